@@ -39,7 +39,7 @@ pub enum RetroError {
     #[error("Insufficient voting credits")]
     InsufficientCredits,
 
-    #[error("Vote credits can only be increased, not decreased")]
+    #[error("Vote credits can only be increased here; use RetractVote to take them back")]
     CannotDecreaseVotes,
 
     #[error("Too many categories specified")]
@@ -113,6 +113,174 @@ pub enum RetroError {
 
     #[error("Account discriminator does not match expected type")]
     InvalidAccountDiscriminator,
+
+    #[error("Board still has action items pending verification")]
+    ActionItemsPending,
+
+    #[error("Board still has memberships or action items that must be reclaimed first")]
+    BoardHasOpenAccounts,
+
+    #[error("Account does not belong to this board")]
+    AccountBoardMismatch,
+
+    #[error("Rent recipient does not match the account's payer")]
+    InvalidRentRecipient,
+
+    #[error("Only the facilitator or the rent payer can reclaim this account")]
+    UnauthorizedReclaim,
+
+    #[error("Author commitment must be provided exactly when the board hides note authors")]
+    InvalidAuthorCommitment,
+
+    #[error("Salt does not open the note's author commitment")]
+    AuthorCommitmentMismatch,
+
+    #[error("Note does not hide its author")]
+    NoteAuthorNotHidden,
+
+    #[error("Only the note's author can modify it")]
+    NotNoteAuthor,
+
+    #[error("Participant is already on the allowlist")]
+    AlreadyOnAllowlist,
+
+    #[error("Participant owns or verifies an action item")]
+    ParticipantHasActionItems,
+
+    #[error("Predecessor must be a closed board from the same team or facilitator")]
+    InvalidPredecessor,
+
+    #[error("Board does not follow up on the given board")]
+    PredecessorMismatch,
+
+    #[error("Due date must be in the future")]
+    InvalidDueDate,
+
+    #[error("Action item is past its due date")]
+    ActionItemPastDue,
+
+    #[error("Action item has no due date or it has not passed yet")]
+    ActionItemNotDue,
+
+    #[error("Verification vote already has this value")]
+    VerificationVoteUnchanged,
+
+    #[error("Team name must be 1-32 bytes")]
+    InvalidTeamName,
+
+    #[error("Maximum number of team members reached")]
+    TeamFull,
+
+    #[error("Not a member of this team")]
+    NotTeamMember,
+
+    #[error("A team must keep at least one facilitator")]
+    LastTeamFacilitator,
+
+    #[error("Signer is not the proposed facilitator")]
+    NotProposedFacilitator,
+
+    #[error("Stage pipeline must start with Setup, keep stage order, only vote after grouping and fit the voting mode")]
+    InvalidStagePipeline,
+
+    #[error("Stage durations must have one entry per pipeline stage")]
+    InvalidStageDurations,
+
+    #[error("Current stage has no timebox or it has not elapsed")]
+    StageTimeboxNotElapsed,
+
+    #[error("Cannot revert out of Discuss once action items exist")]
+    ActionItemsExist,
+
+    #[error("Retracted votes must be between 1 and the votes cast on the group")]
+    InvalidRetraction,
+
+    #[error("Instruction does not apply to this board's voting mode")]
+    InvalidVotingMode,
+
+    #[error("Ballot must rank 1-10 distinct groups of this board")]
+    InvalidBallot,
+
+    #[error("Tally batches take the next groups in id order, then current ballots in participant order, each followed by the groups it ranks")]
+    InvalidTallyAccounts,
+
+    #[error("No vote commitment for the current vote round")]
+    NoVoteCommitment,
+
+    #[error("Votes for this commitment were already revealed")]
+    VotesAlreadyRevealed,
+
+    #[error("Allocations and salt do not open the vote commitment")]
+    VoteCommitmentMismatch,
+
+    #[error("Allocations must name distinct groups with at least one vote each")]
+    InvalidVoteAllocation,
+
+    #[error("Credit budgets must have one entry per allowlist member")]
+    InvalidCreditBudgets,
+
+    #[error("Note is not in the expected group")]
+    NoteNotInGroup,
+
+    #[error("Source and destination must be two different groups")]
+    SameGroup,
+
+    #[error("At least one note must be selected")]
+    NoNotesSelected,
+
+    #[error("Every note of the merged group must be passed")]
+    GroupNotesMissing,
+
+    #[error("Cannot vote on a group without notes")]
+    EmptyGroup,
+
+    #[error("Group still has notes")]
+    GroupNotEmpty,
+
+    #[error("Vote record spends more credits than its membership")]
+    VoteRecordMismatch,
+
+    #[error("Group note count does not match its notes")]
+    GroupNoteCountMismatch,
+
+    #[error("Account already uses the current layout")]
+    AccountAlreadyMigrated,
+
+    #[error("Board must be migrated before its accounts")]
+    BoardNotMigrated,
+
+    #[error("Board has no pending action items")]
+    PendingActionItemCountMismatch,
+
+    #[error("Verifier listed more than once")]
+    DuplicateVerifier,
+
+    #[error("Team boards take their facilitators from the team")]
+    TeamBoardFacilitator,
+
+    #[error("Ballots are being tallied and can no longer change")]
+    TallyInProgress,
+
+    #[error("Every ballot must be tallied before leaving Vote")]
+    BallotsNotTallied,
+
+    #[error("Group must be migrated before its notes")]
+    GroupNotMigrated,
+
+    #[error("Only the wallet an account refunds its rent to can migrate it")]
+    MigrationPayerMismatch,
+
+    #[error("Board has no memberships left")]
+    MembershipCountMismatch,
+
+    #[error("Board has no live action items")]
+    LiveActionItemCountMismatch,
+
+    #[error("Every note on the board must be migrated first")]
+    NotesNotMigrated,
+
+    #[error("Board has no legacy notes left")]
+    LegacyNoteCountMismatch,
 }
 
 impl From<RetroError> for ProgramError {
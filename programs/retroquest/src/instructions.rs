@@ -1,7 +1,7 @@
 use borsh::BorshDeserialize;
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-use crate::state::BoardStage;
+use crate::state::{BoardStage, TeamRole, VoteAllocation, VotingMode};
 
 // Instruction discriminators
 pub const INIT_FACILITATOR_REGISTRY: u8 = 0;
@@ -20,6 +20,43 @@ pub const CREATE_SESSION: u8 = 12;
 pub const REVOKE_SESSION: u8 = 13;
 pub const CREATE_IDENTITY: u8 = 14;
 pub const UPDATE_IDENTITY: u8 = 15;
+pub const RECLAIM_BOARD: u8 = 16;
+pub const RECLAIM_MEMBERSHIP: u8 = 17;
+pub const RECLAIM_NOTE: u8 = 18;
+pub const RECLAIM_GROUP: u8 = 19;
+pub const RECLAIM_VOTE_RECORD: u8 = 20;
+pub const RECLAIM_ACTION_ITEM: u8 = 21;
+pub const RECLAIM_VERIFICATION_VOTE: u8 = 22;
+pub const REVEAL_NOTE_AUTHOR: u8 = 23;
+pub const UPDATE_NOTE: u8 = 24;
+pub const DELETE_NOTE: u8 = 25;
+pub const ADD_PARTICIPANT: u8 = 26;
+pub const REMOVE_PARTICIPANT: u8 = 27;
+pub const REVIEW_ACTION_ITEM: u8 = 28;
+pub const EXPIRE_ACTION_ITEM: u8 = 29;
+pub const UPDATE_VERIFICATION_VOTE: u8 = 30;
+pub const REASSIGN_ACTION_ITEM: u8 = 31;
+pub const INIT_REPUTATION: u8 = 32;
+pub const CREATE_TEAM: u8 = 33;
+pub const SET_TEAM_MEMBER: u8 = 34;
+pub const REMOVE_TEAM_MEMBER: u8 = 35;
+pub const PROPOSE_FACILITATOR: u8 = 36;
+pub const ACCEPT_FACILITATOR: u8 = 37;
+pub const CRANK_ADVANCE_STAGE: u8 = 38;
+pub const REVERT_STAGE: u8 = 39;
+pub const RETRACT_VOTE: u8 = 40;
+pub const SUBMIT_BALLOT: u8 = 41;
+pub const RECLAIM_BALLOT: u8 = 42;
+pub const COMMIT_VOTES: u8 = 43;
+pub const REVEAL_VOTES: u8 = 44;
+pub const RECLAIM_VOTE_COMMITMENT: u8 = 45;
+pub const SET_CREDIT_BUDGET: u8 = 46;
+pub const MERGE_GROUPS: u8 = 47;
+pub const SPLIT_GROUP: u8 = 48;
+pub const MOVE_NOTE: u8 = 49;
+pub const DELETE_GROUP: u8 = 50;
+pub const MIGRATE_ACCOUNT: u8 = 51;
+pub const TALLY_BALLOTS: u8 = 52;
 
 #[derive(Debug)]
 pub enum RetroInstruction {
@@ -32,69 +69,82 @@ pub enum RetroInstruction {
 
     /// Create a new retro board
     /// Accounts:
-    /// 0. `[writable]` Facilitator registry PDA
+    /// 0. `[writable]` Facilitator registry PDA, or Team PDA for a team board
     /// 1. `[writable]` Board PDA
     /// 2. `[signer]` Facilitator
     /// 3. `[]` System program
     /// 4+ `[writable]` BoardMembership PDAs for each allowlist member
-    CreateBoard {
-        categories: Vec<String>,
-        allowlist: Vec<Pubkey>,
-        voting_credits_per_participant: Option<u8>,
-    },
+    /// 5. `[]` Predecessor board after the memberships, only when `predecessor` is set
+    CreateBoard { config: BoardConfig },
 
-    /// Advance board to next stage
+    /// Advance board to the next stage in its pipeline
+    /// A ranked board leaves Vote only once TallyBallots has tallied every ballot.
     /// Accounts:
     /// 0. `[writable]` Board PDA
     /// 1. `[signer]` Facilitator
+    /// 2. `[]` Team PDA, only for team boards
     AdvanceStage { new_stage: BoardStage },
 
     /// Close the board
     /// Accounts:
     /// 0. `[writable]` Board PDA
     /// 1. `[signer]` Facilitator
+    /// 2. `[]` Team PDA, only for team boards
     CloseBoard,
 
     /// Create a note (must be on allowlist)
+    /// `author_commitment` is required on boards that hide note authors and rejected otherwise.
     /// Accounts:
     /// 0. `[writable]` Board PDA
     /// 1. `[writable]` Note PDA
-    /// 2. `[signer]` Author
-    /// 3. `[]` System program
-    CreateNote { category_id: u8, content: String },
+    /// 2. `[]` Author's BoardMembership PDA
+    /// 3. `[signer]` Author
+    /// 4. `[]` System program
+    CreateNote {
+        category_id: u8,
+        content: String,
+        author_commitment: Option<[u8; 32]>,
+    },
 
     /// Create a group (must be on allowlist)
     /// Accounts:
     /// 0. `[writable]` Board PDA
     /// 1. `[writable]` Group PDA
-    /// 2. `[signer]` Creator
-    /// 3. `[]` System program
+    /// 2. `[]` Creator's BoardMembership PDA
+    /// 3. `[signer]` Creator
+    /// 4. `[]` System program
     CreateGroup { title: String },
 
     /// Set group title (must be on allowlist)
     /// Accounts:
     /// 0. `[]` Board PDA
     /// 1. `[writable]` Group PDA
-    /// 2. `[signer]` Participant
+    /// 2. `[]` Participant's BoardMembership PDA
+    /// 3. `[signer]` Participant
     SetGroupTitle { group_id: u64, title: String },
 
     /// Assign note to group (must be on allowlist)
     /// Accounts:
     /// 0. `[]` Board PDA
     /// 1. `[writable]` Note PDA
-    /// 2. `[]` Group PDA
-    /// 3. `[signer]` Participant
+    /// 2. `[writable]` Group PDA
+    /// 3. `[]` Participant's BoardMembership PDA
+    /// 4. `[signer]` Participant
     AssignNoteToGroup { note_id: u64, group_id: u64 },
 
     /// Unassign note from group (must be on allowlist)
     /// Accounts:
     /// 0. `[]` Board PDA
     /// 1. `[writable]` Note PDA
-    /// 2. `[signer]` Participant
+    /// 2. `[writable]` Note's current Group PDA
+    /// 3. `[]` Participant's BoardMembership PDA
+    /// 4. `[signer]` Participant
     UnassignNote { note_id: u64 },
 
     /// Cast vote (must be on allowlist)
-    /// Uses BoardMembership to track credits
+    /// Uses BoardMembership to track credits; the membership must already exist
+    /// `credits_delta` is the number of votes to add; on quadratic boards n votes on one
+    /// group cost n² credits in total.
     /// Accounts:
     /// 0. `[]` Board PDA
     /// 1. `[writable]` BoardMembership PDA
@@ -110,21 +160,26 @@ pub enum RetroInstruction {
     /// 1. `[writable]` ActionItem PDA
     /// 2. `[signer]` Facilitator
     /// 3. `[]` System program
+    /// 4. `[]` Team PDA, only for team boards
+    /// 5. `[]` Owner's BoardMembership PDA
+    /// 6. `[]` BoardMembership PDA for each verifier, in order
     CreateActionItem {
         description: String,
         owner: Pubkey,
         verifiers: Vec<Pubkey>,
         threshold: u8,
+        due_at: Option<i64>,
     },
 
     /// Cast a verification vote on an action item (board must be closed)
     /// Accounts:
-    /// 0. `[]` Board PDA
+    /// 0. `[writable]` Board PDA
     /// 1. `[writable]` ActionItem PDA
     /// 2. `[writable]` VerificationVote PDA
     /// 3. `[writable]` Owner's BoardMembership PDA (for score update)
-    /// 4. `[signer]` Verifier
-    /// 5. `[]` System program
+    /// 4. `[writable]` Owner's Reputation PDA (updated only if it exists)
+    /// 5. `[signer]` Verifier
+    /// 6. `[]` System program
     CastVerificationVote { action_item_id: u64, approved: bool },
 
     /// Create a session token for ephemeral signing
@@ -156,16 +211,403 @@ pub enum RetroInstruction {
     /// 0. `[writable]` ParticipantIdentity PDA
     /// 1. `[signer]` Authority (user's wallet)
     UpdateIdentity { username: String },
+
+    /// Reclaim rent from a closed board (facilitator or rent payer).
+    /// All memberships and action items must be reclaimed first.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` Rent recipient (board's rent payer)
+    /// 2. `[signer]` Facilitator or rent payer
+    /// 3. `[]` Team PDA, only for team boards
+    ReclaimBoard,
+
+    /// Reclaim rent from a BoardMembership (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` BoardMembership PDA
+    /// 2. `[writable]` Rent recipient (membership's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards
+    ReclaimMembership,
+
+    /// Reclaim rent from a note (facilitator or rent payer, board closed and settled)
+    /// Notes that still hide their author go back to the author, who opens the commitment with `salt`.
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[writable]` Note PDA
+    /// 2. `[writable]` Rent recipient (note's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards not yet reclaimed
+    ReclaimNote { salt: Option<[u8; 32]> },
+
+    /// Reclaim rent from a group (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[writable]` Group PDA
+    /// 2. `[writable]` Rent recipient (group's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards not yet reclaimed
+    ReclaimGroup,
+
+    /// Reclaim rent from a vote record (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[writable]` VoteRecord PDA
+    /// 2. `[writable]` Rent recipient (vote record's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards not yet reclaimed
+    ReclaimVoteRecord,
+
+    /// Reclaim rent from a settled action item (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` ActionItem PDA
+    /// 2. `[writable]` Rent recipient (action item's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards
+    ReclaimActionItem,
+
+    /// Reclaim rent from a verification vote (facilitator or rent payer, action item settled)
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[]` ActionItem PDA (may already be reclaimed)
+    /// 2. `[writable]` VerificationVote PDA
+    /// 3. `[writable]` Rent recipient (vote's rent payer)
+    /// 4. `[signer]` Facilitator or rent payer
+    /// 5. `[]` Team PDA, only for team boards whose action item is not yet reclaimed
+    ReclaimVerificationVote,
+
+    /// Prove authorship of a note with a hidden author by opening its commitment
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Note PDA
+    /// 2. `[signer]` Author
+    RevealNoteAuthor { salt: [u8; 32] },
+
+    /// Edit own note (WriteNotes stage only)
+    /// `salt` opens the author commitment on notes that hide their author.
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Note PDA
+    /// 2. `[signer]` Author
+    UpdateNote {
+        note_id: u64,
+        category_id: u8,
+        content: String,
+        salt: Option<[u8; 32]>,
+    },
+
+    /// Delete own note and refund its rent (WriteNotes stage only)
+    /// The note id is not reused, leaving a gap in the board's note ids.
+    /// Grouped notes must be unassigned first.
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Note PDA
+    /// 2. `[writable]` Rent recipient (note's rent payer)
+    /// 3. `[signer]` Author
+    DeleteNote { note_id: u64, salt: Option<[u8; 32]> },
+
+    /// Add a participant to the allowlist (facilitator only)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` BoardMembership PDA for the participant
+    /// 2. `[signer]` Facilitator
+    /// 3. `[]` System program
+    /// 4. `[]` Team PDA, only for team boards
+    AddParticipant { participant: Pubkey },
+
+    /// Remove a participant from the allowlist (facilitator only, before Vote)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` BoardMembership PDA for the participant
+    /// 2. `[writable]` Rent recipient (membership's rent payer)
+    /// 3. `[signer]` Facilitator
+    /// 4. `[]` Team PDA, only for team boards
+    RemoveParticipant { participant: Pubkey },
+
+    /// Verify a predecessor board's action item (follow-up board in ReviewPreviousActions stage)
+    /// Accounts:
+    /// 0. `[]` Follow-up board PDA
+    /// 1. `[writable]` Predecessor board PDA
+    /// 2. `[writable]` ActionItem PDA
+    /// 3. `[writable]` VerificationVote PDA
+    /// 4. `[writable]` Owner's BoardMembership PDA on the predecessor board
+    /// 5. `[writable]` Owner's Reputation PDA (updated only if it exists)
+    /// 6. `[signer]` Verifier
+    /// 7. `[]` System program
+    ReviewActionItem { action_item_id: u64, approved: bool },
+
+    /// Mark a pending action item as expired once its due date has passed (anyone can call)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` ActionItem PDA
+    /// 2. `[writable]` Owner's Reputation PDA (updated only if it exists)
+    ExpireActionItem,
+
+    /// Change an existing verification vote while the action item is still pending
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` ActionItem PDA
+    /// 2. `[writable]` VerificationVote PDA
+    /// 3. `[writable]` Owner's BoardMembership PDA (for score update)
+    /// 4. `[writable]` Owner's Reputation PDA (updated only if it exists)
+    /// 5. `[signer]` Verifier
+    UpdateVerificationVote { approved: bool },
+
+    /// Change a pending action item's owner, verifiers and threshold (facilitator only)
+    /// Votes from verifiers who are dropped are closed and refunded to whoever paid for them.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` ActionItem PDA
+    /// 2. `[signer]` Facilitator
+    /// 3. `[]` Team PDA, only for team boards
+    /// 4. `[writable]` New owner's BoardMembership PDA
+    /// 5. `[writable]` New owner's Reputation PDA (updated only if it exists)
+    /// 6. `[]` BoardMembership PDA for each new verifier, in order
+    /// 7. `[writable]` VerificationVote PDA and its rent payer, one pair per dropped verifier
+    ReassignActionItem {
+        owner: Pubkey,
+        verifiers: Vec<Pubkey>,
+        threshold: u8,
+    },
+
+    /// Create the caller's cross-board reputation (requires a participant identity)
+    /// Accounts:
+    /// 0. `[writable]` Reputation PDA
+    /// 1. `[]` ParticipantIdentity PDA
+    /// 2. `[signer]` Authority (user's wallet)
+    /// 3. `[]` System program
+    InitReputation,
+
+    /// Create a team; the creator becomes its first facilitator
+    /// Accounts:
+    /// 0. `[writable]` Team PDA
+    /// 1. `[signer]` Creator
+    /// 2. `[]` System program
+    CreateTeam { name: String },
+
+    /// Add a team member or change their role (team facilitator only)
+    /// Accounts:
+    /// 0. `[writable]` Team PDA
+    /// 1. `[signer]` Facilitator
+    SetTeamMember { member: Pubkey, role: TeamRole },
+
+    /// Remove a team member (team facilitator only)
+    /// Accounts:
+    /// 0. `[writable]` Team PDA
+    /// 1. `[signer]` Facilitator
+    RemoveTeamMember { member: Pubkey },
+
+    /// Offer the facilitator role on a live board to an allowlisted participant (facilitator only)
+    /// Not available on team boards, which are run by the team's facilitators.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[]` Proposed facilitator's BoardMembership PDA
+    /// 2. `[signer]` Facilitator
+    /// 3. `[]` Team PDA, only for team boards
+    ProposeFacilitator { new_facilitator: Pubkey },
+
+    /// Accept a pending facilitator proposal
+    /// Rent stays with whoever paid for the board, its memberships and action items.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[]` Proposed facilitator's BoardMembership PDA
+    /// 2. `[signer]` Proposed facilitator
+    AcceptFacilitator,
+
+    /// Advance a board whose current stage has run past its timebox (anyone can call)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    CrankAdvanceStage,
+
+    /// Move the board back to an earlier stage in its pipeline (facilitator override)
+    /// Going back before Vote (before RevealVotes on sealed and ranked boards) bumps the board's
+    /// vote round. Memberships, groups and vote records from an older round count as
+    /// zero and are reset the next time an instruction touches them.
+    /// Cannot leave Discuss once action items exist.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[signer]` Facilitator
+    /// 2. `[]` Team PDA, only for team boards
+    RevertStage { target_stage: BoardStage },
+
+    /// Take votes back from a group during the Vote stage, refunding what they cost
+    /// The vote record is closed and its rent returned to whoever paid it once it reaches zero.
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` BoardMembership PDA
+    /// 2. `[writable]` Group PDA
+    /// 3. `[writable]` Vote record PDA
+    /// 4. `[writable]` Rent recipient (vote record's rent payer)
+    /// 5. `[signer]` Voter
+    RetractVote { group_id: u64, votes: u8 },
+
+    /// Submit or replace a ranking of groups (ranked boards, Vote stage)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[]` BoardMembership PDA
+    /// 2. `[writable]` Ballot PDA
+    /// 3. `[signer]` Voter
+    /// 4. `[]` System program
+    /// 5. `[]` Group PDAs after the optional session, in `ranking` order
+    SubmitBallot { ranking: Vec<u64> },
+
+    /// Reclaim rent from a ballot (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[writable]` Ballot PDA
+    /// 2. `[writable]` Rent recipient (ballot's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards not yet reclaimed
+    ReclaimBallot,
+
+    /// Commit to a hidden vote allocation (sealed boards, Vote stage)
+    /// Committing again replaces the previous commitment.
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[]` BoardMembership PDA
+    /// 2. `[writable]` VoteCommitment PDA
+    /// 3. `[signer]` Voter
+    /// 4. `[]` System program
+    CommitVotes { commitment: [u8; 32] },
+
+    /// Open a vote commitment and add its votes to the group tallies (RevealVotes stage)
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` BoardMembership PDA
+    /// 2. `[writable]` VoteCommitment PDA
+    /// 3. `[signer]` Voter
+    /// 4. `[writable]` Group PDAs after the optional session, in allocation order
+    RevealVotes {
+        allocations: Vec<VoteAllocation>,
+        salt: [u8; 32],
+    },
+
+    /// Reclaim rent from a vote commitment (facilitator or rent payer, board closed and settled)
+    /// Accounts:
+    /// 0. `[]` Board PDA (may already be reclaimed)
+    /// 1. `[writable]` VoteCommitment PDA
+    /// 2. `[writable]` Rent recipient (commitment's rent payer)
+    /// 3. `[signer]` Facilitator or rent payer
+    /// 4. `[]` Team PDA, only for team boards not yet reclaimed
+    ReclaimVoteCommitment,
+
+    /// Set a participant's voting budget (facilitator only, before Vote)
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Participant's BoardMembership PDA
+    /// 2. `[signer]` Facilitator
+    /// 3. `[]` Team PDA, only for team boards
+    SetCreditBudget { participant: Pubkey, credits: u8 },
+
+    /// Move every note of the source group into the target group and close the source
+    /// (source group's creator or facilitator, must be on allowlist, GroupDuplicates stage).
+    /// All of the source's notes must be passed.
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Target group PDA
+    /// 2. `[writable]` Source group PDA
+    /// 3. `[writable]` Rent recipient (source group's rent payer)
+    /// 4. `[]` Participant's BoardMembership PDA
+    /// 5. `[signer]` Participant
+    /// 6. `[]` Team PDA, only for team boards
+    /// 7. `[writable]` Note PDAs after the optional session, in `note_ids` order
+    MergeGroups {
+        target_group_id: u64,
+        source_group_id: u64,
+        note_ids: Vec<u64>,
+    },
+
+    /// Move some notes of a group into a new group (must be on allowlist, GroupDuplicates stage)
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[writable]` Existing group PDA
+    /// 2. `[writable]` New group PDA
+    /// 3. `[]` Participant's BoardMembership PDA
+    /// 4. `[signer]` Participant
+    /// 5. `[]` System program
+    /// 6. `[writable]` Note PDAs after the optional session, in `note_ids` order
+    SplitGroup {
+        group_id: u64,
+        title: String,
+        note_ids: Vec<u64>,
+    },
+
+    /// Move a grouped note straight into another group (must be on allowlist, GroupDuplicates stage)
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Note PDA
+    /// 2. `[writable]` Current group PDA
+    /// 3. `[writable]` New group PDA
+    /// 4. `[]` Participant's BoardMembership PDA
+    /// 5. `[signer]` Participant
+    MoveNote {
+        note_id: u64,
+        from_group_id: u64,
+        to_group_id: u64,
+    },
+
+    /// Delete a group without notes and refund its rent (creator or facilitator, GroupDuplicates stage)
+    /// Accounts:
+    /// 0. `[]` Board PDA
+    /// 1. `[writable]` Group PDA
+    /// 2. `[writable]` Rent recipient (group's rent payer)
+    /// 3. `[signer]` Group creator or facilitator
+    /// 4. `[]` Team PDA, only for team boards
+    DeleteGroup { group_id: u64 },
+
+    /// Rewrite an account created before boards had stage pipelines, voting modes and rent payers
+    /// in its current layout. The payer must be the wallet the account refunds its rent to,
+    /// and covers any extra rent.
+    /// Migrate a board before its memberships, groups, notes and action items, and a group before its notes.
+    /// A board can't take votes or lose groups until all of its notes are migrated.
+    /// Accounts:
+    /// 0. `[writable]` Account to migrate
+    /// 1. `[writable, signer]` Payer
+    /// 2. `[]` System program
+    /// 3. For a board: `[writable]` BoardMembership PDA per allowlist entry, in allowlist order
+    ///    (missing ones are created).
+    ///    For a membership or group: `[]` Board PDA.
+    ///    For an action item: `[writable]` Board PDA.
+    ///    For a note: `[writable]` Board PDA, then `[writable]` Group PDA if the note is in a group.
+    MigrateAccount,
+
+    /// Tally a batch of a ranked board's ballots into Borda points on its groups (Vote stage)
+    /// The first batch closes the round to new and changed ballots, so only the facilitator
+    /// can start tallying before the Vote timebox runs out; after that anyone can continue.
+    /// Groups are scanned in id order to count the candidates, then ballots are added in
+    /// participant order. Progress is kept on the board, so a batch can stop anywhere.
+    /// Accounts:
+    /// 0. `[writable]` Board PDA
+    /// 1. `[signer]` Facilitator, or anyone once tallying has started or Vote has timed out
+    /// 2. `[]` Team PDA, only for team boards
+    /// 3. `[writable]` Next groups to scan in id order (reclaimed ones as their empty PDA),
+    ///    until every group has been scanned
+    /// 4. `[]` Then each next ballot of the current round, followed by `[writable]` the groups
+    ///    it ranks, in ranking order
+    TallyBallots,
 }
 
-// Instruction data payloads for Borsh deserialization
-#[derive(BorshDeserialize)]
-struct CreateBoardPayload {
-    categories: Vec<String>,
-    allowlist: Vec<Pubkey>,
-    voting_credits_per_participant: Option<u8>,
+/// Settings chosen when a board is created (CreateBoard payload)
+#[derive(BorshDeserialize, Debug)]
+pub struct BoardConfig {
+    pub categories: Vec<String>,
+    /// Up to MAX_INITIAL_PARTICIPANTS; AddParticipant grows the board to MAX_PARTICIPANTS
+    pub allowlist: Vec<Pubkey>,
+    pub voting_credits_per_participant: Option<u8>,
+    pub hide_note_authors: bool,
+    pub predecessor: Option<Pubkey>,
+    /// Ordered stage pipeline; None enables every stage that applies
+    pub stages: Option<Vec<BoardStage>>,
+    /// Timebox in slots for each pipeline stage (0 = untimed); None leaves every stage untimed
+    pub stage_duration_slots: Option<Vec<u64>>,
+    pub voting_mode: VotingMode,
+    /// Voting budget for each allowlist entry; None gives everyone voting_credits_per_participant
+    pub credit_budgets: Option<Vec<u8>>,
 }
 
+// Instruction data payloads for Borsh deserialization
+
 #[derive(BorshDeserialize)]
 struct AdvanceStagePayload {
     new_stage: u8,
@@ -175,6 +617,7 @@ struct AdvanceStagePayload {
 struct CreateNotePayload {
     category_id: u8,
     content: String,
+    author_commitment: Option<[u8; 32]>,
 }
 
 #[derive(BorshDeserialize)]
@@ -211,6 +654,7 @@ struct CreateActionItemPayload {
     owner: Pubkey,
     verifiers: Vec<Pubkey>,
     threshold: u8,
+    due_at: Option<i64>,
 }
 
 #[derive(BorshDeserialize)]
@@ -219,6 +663,93 @@ struct CastVerificationVotePayload {
     approved: bool,
 }
 
+#[derive(BorshDeserialize)]
+struct UpdateVerificationVotePayload {
+    approved: bool,
+}
+
+#[derive(BorshDeserialize)]
+struct ReassignActionItemPayload {
+    owner: Pubkey,
+    verifiers: Vec<Pubkey>,
+    threshold: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct CreateTeamPayload {
+    name: String,
+}
+
+#[derive(BorshDeserialize)]
+struct SetTeamMemberPayload {
+    member: Pubkey,
+    role: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct TeamMemberPayload {
+    member: Pubkey,
+}
+
+#[derive(BorshDeserialize)]
+struct ProposeFacilitatorPayload {
+    new_facilitator: Pubkey,
+}
+
+#[derive(BorshDeserialize)]
+struct RetractVotePayload {
+    group_id: u64,
+    votes: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct SubmitBallotPayload {
+    ranking: Vec<u64>,
+}
+
+#[derive(BorshDeserialize)]
+struct CommitVotesPayload {
+    commitment: [u8; 32],
+}
+
+#[derive(BorshDeserialize)]
+struct RevealVotesPayload {
+    allocations: Vec<VoteAllocation>,
+    salt: [u8; 32],
+}
+
+#[derive(BorshDeserialize)]
+struct SetCreditBudgetPayload {
+    participant: Pubkey,
+    credits: u8,
+}
+
+#[derive(BorshDeserialize)]
+struct MergeGroupsPayload {
+    target_group_id: u64,
+    source_group_id: u64,
+    note_ids: Vec<u64>,
+}
+
+#[derive(BorshDeserialize)]
+struct SplitGroupPayload {
+    group_id: u64,
+    title: String,
+    note_ids: Vec<u64>,
+}
+
+#[derive(BorshDeserialize)]
+struct MoveNotePayload {
+    note_id: u64,
+    from_group_id: u64,
+    to_group_id: u64,
+}
+
+#[derive(BorshDeserialize)]
+struct DeleteGroupPayload {
+    group_id: u64,
+}
+
 #[derive(BorshDeserialize)]
 struct CreateSessionPayload {
     valid_until: i64,
@@ -230,6 +761,35 @@ struct IdentityPayload {
     username: String,
 }
 
+#[derive(BorshDeserialize)]
+struct ReclaimNotePayload {
+    salt: Option<[u8; 32]>,
+}
+
+#[derive(BorshDeserialize)]
+struct RevealNoteAuthorPayload {
+    salt: [u8; 32],
+}
+
+#[derive(BorshDeserialize)]
+struct UpdateNotePayload {
+    note_id: u64,
+    category_id: u8,
+    content: String,
+    salt: Option<[u8; 32]>,
+}
+
+#[derive(BorshDeserialize)]
+struct ParticipantPayload {
+    participant: Pubkey,
+}
+
+#[derive(BorshDeserialize)]
+struct DeleteNotePayload {
+    note_id: u64,
+    salt: Option<[u8; 32]>,
+}
+
 impl RetroInstruction {
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
         let (&variant, rest) = input
@@ -240,13 +800,9 @@ impl RetroInstruction {
             0 => Self::InitFacilitatorRegistry,
 
             1 => {
-                let payload = CreateBoardPayload::try_from_slice(rest)
+                let config = BoardConfig::try_from_slice(rest)
                     .map_err(|_| ProgramError::InvalidInstructionData)?;
-                Self::CreateBoard {
-                    categories: payload.categories,
-                    allowlist: payload.allowlist,
-                    voting_credits_per_participant: payload.voting_credits_per_participant,
-                }
+                Self::CreateBoard { config }
             }
 
             2 => {
@@ -258,6 +814,8 @@ impl RetroInstruction {
                     2 => BoardStage::GroupDuplicates,
                     3 => BoardStage::Vote,
                     4 => BoardStage::Discuss,
+                    5 => BoardStage::ReviewPreviousActions,
+                    6 => BoardStage::RevealVotes,
                     _ => return Err(ProgramError::InvalidInstructionData),
                 };
                 Self::AdvanceStage { new_stage }
@@ -271,6 +829,7 @@ impl RetroInstruction {
                 Self::CreateNote {
                     category_id: payload.category_id,
                     content: payload.content,
+                    author_commitment: payload.author_commitment,
                 }
             }
 
@@ -325,6 +884,7 @@ impl RetroInstruction {
                     owner: payload.owner,
                     verifiers: payload.verifiers,
                     threshold: payload.threshold,
+                    due_at: payload.due_at,
                 }
             }
 
@@ -364,6 +924,236 @@ impl RetroInstruction {
                 }
             }
 
+            16 => Self::ReclaimBoard,
+            17 => Self::ReclaimMembership,
+            18 => {
+                let payload = ReclaimNotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::ReclaimNote { salt: payload.salt }
+            }
+            19 => Self::ReclaimGroup,
+            20 => Self::ReclaimVoteRecord,
+            21 => Self::ReclaimActionItem,
+            22 => Self::ReclaimVerificationVote,
+
+            23 => {
+                let payload = RevealNoteAuthorPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RevealNoteAuthor { salt: payload.salt }
+            }
+
+            24 => {
+                let payload = UpdateNotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::UpdateNote {
+                    note_id: payload.note_id,
+                    category_id: payload.category_id,
+                    content: payload.content,
+                    salt: payload.salt,
+                }
+            }
+
+            25 => {
+                let payload = DeleteNotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::DeleteNote {
+                    note_id: payload.note_id,
+                    salt: payload.salt,
+                }
+            }
+
+            26 => {
+                let payload = ParticipantPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::AddParticipant {
+                    participant: payload.participant,
+                }
+            }
+
+            27 => {
+                let payload = ParticipantPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RemoveParticipant {
+                    participant: payload.participant,
+                }
+            }
+
+            28 => {
+                let payload = CastVerificationVotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::ReviewActionItem {
+                    action_item_id: payload.action_item_id,
+                    approved: payload.approved,
+                }
+            }
+
+            29 => Self::ExpireActionItem,
+
+            30 => {
+                let payload = UpdateVerificationVotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::UpdateVerificationVote {
+                    approved: payload.approved,
+                }
+            }
+
+            31 => {
+                let payload = ReassignActionItemPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::ReassignActionItem {
+                    owner: payload.owner,
+                    verifiers: payload.verifiers,
+                    threshold: payload.threshold,
+                }
+            }
+
+            32 => Self::InitReputation,
+
+            33 => {
+                let payload = CreateTeamPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::CreateTeam { name: payload.name }
+            }
+
+            34 => {
+                let payload = SetTeamMemberPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let role = match payload.role {
+                    0 => TeamRole::Member,
+                    1 => TeamRole::Facilitator,
+                    _ => return Err(ProgramError::InvalidInstructionData),
+                };
+                Self::SetTeamMember {
+                    member: payload.member,
+                    role,
+                }
+            }
+
+            35 => {
+                let payload = TeamMemberPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RemoveTeamMember {
+                    member: payload.member,
+                }
+            }
+
+            36 => {
+                let payload = ProposeFacilitatorPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::ProposeFacilitator {
+                    new_facilitator: payload.new_facilitator,
+                }
+            }
+
+            37 => Self::AcceptFacilitator,
+
+            38 => Self::CrankAdvanceStage,
+
+            39 => {
+                let payload = AdvanceStagePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                let target_stage = match payload.new_stage {
+                    0 => BoardStage::Setup,
+                    1 => BoardStage::WriteNotes,
+                    2 => BoardStage::GroupDuplicates,
+                    3 => BoardStage::Vote,
+                    4 => BoardStage::Discuss,
+                    5 => BoardStage::ReviewPreviousActions,
+                    6 => BoardStage::RevealVotes,
+                    _ => return Err(ProgramError::InvalidInstructionData),
+                };
+                Self::RevertStage { target_stage }
+            }
+
+            40 => {
+                let payload = RetractVotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RetractVote {
+                    group_id: payload.group_id,
+                    votes: payload.votes,
+                }
+            }
+
+            41 => {
+                let payload = SubmitBallotPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::SubmitBallot {
+                    ranking: payload.ranking,
+                }
+            }
+
+            42 => Self::ReclaimBallot,
+
+            43 => {
+                let payload = CommitVotesPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::CommitVotes {
+                    commitment: payload.commitment,
+                }
+            }
+
+            44 => {
+                let payload = RevealVotesPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::RevealVotes {
+                    allocations: payload.allocations,
+                    salt: payload.salt,
+                }
+            }
+
+            45 => Self::ReclaimVoteCommitment,
+
+            46 => {
+                let payload = SetCreditBudgetPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::SetCreditBudget {
+                    participant: payload.participant,
+                    credits: payload.credits,
+                }
+            }
+
+            47 => {
+                let payload = MergeGroupsPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::MergeGroups {
+                    target_group_id: payload.target_group_id,
+                    source_group_id: payload.source_group_id,
+                    note_ids: payload.note_ids,
+                }
+            }
+
+            48 => {
+                let payload = SplitGroupPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::SplitGroup {
+                    group_id: payload.group_id,
+                    title: payload.title,
+                    note_ids: payload.note_ids,
+                }
+            }
+
+            49 => {
+                let payload = MoveNotePayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::MoveNote {
+                    note_id: payload.note_id,
+                    from_group_id: payload.from_group_id,
+                    to_group_id: payload.to_group_id,
+                }
+            }
+
+            50 => {
+                let payload = DeleteGroupPayload::try_from_slice(rest)
+                    .map_err(|_| ProgramError::InvalidInstructionData)?;
+                Self::DeleteGroup {
+                    group_id: payload.group_id,
+                }
+            }
+
+            51 => Self::MigrateAccount,
+
+            52 => Self::TallyBallots,
+
             _ => return Err(ProgramError::InvalidInstructionData),
         })
     }
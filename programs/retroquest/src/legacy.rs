@@ -0,0 +1,156 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{
+    ActionItemStatus, BoardStage, MAX_ACTION_DESCRIPTION_CHARS, MAX_CATEGORIES, MAX_CATEGORY_NAME_LEN,
+    MAX_GROUP_TITLE_CHARS, MAX_NOTE_CHARS,
+};
+
+// Account layouts from before boards had stage pipelines, voting modes and rent payers.
+// Every account was allocated at a fixed size that differs from its current one,
+// so MigrateAccount tells the two apart by data length.
+
+pub const LEGACY_MAX_PARTICIPANTS: usize = 8;
+pub const LEGACY_MAX_VERIFIERS: usize = 7;
+
+/// RetroBoard kept its allowlist inline instead of as BoardMembership accounts
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyRetroBoard {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub facilitator: Pubkey,
+    pub board_index: u64,
+    pub stage: BoardStage,
+    pub closed: bool,
+    pub categories: Vec<String>,
+    pub allowlist: Vec<Pubkey>,
+    pub voting_credits_per_participant: u8,
+    pub note_count: u64,
+    pub group_count: u64,
+    pub action_item_count: u64,
+    pub created_at_slot: u64,
+    pub stage_changed_at_slot: u64,
+    pub bump: u8,
+}
+
+impl LegacyRetroBoard {
+    // discriminator(1) + is_initialized(1) + facilitator(32) + board_index(8) +
+    // stage(1) + closed(1) + voting_credits(1) +
+    // note_count(8) + group_count(8) + action_item_count(8) + created_at_slot(8) + stage_changed_at_slot(8) + bump(1) +
+    // categories(4 + MAX_CATEGORIES * (4 + MAX_CATEGORY_NAME_LEN)) + allowlist(4 + LEGACY_MAX_PARTICIPANTS * 32)
+    pub const LEN: usize = 1 + 1 + 32 + 8 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1
+        + (4 + MAX_CATEGORIES * (4 + MAX_CATEGORY_NAME_LEN))
+        + (4 + LEGACY_MAX_PARTICIPANTS * 32);
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyBoardMembership {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub participant: Pubkey,
+    pub credits_spent: u8,
+    pub total_score: u64,
+    pub bump: u8,
+}
+
+impl LegacyBoardMembership {
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + credits_spent(1) + total_score(8) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8 + 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyNote {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub note_id: u64,
+    pub author: Pubkey,
+    pub category_id: u8,
+    pub content: String,
+    pub created_at_slot: u64,
+    pub group_id: Option<u64>,
+    pub bump: u8,
+}
+
+impl LegacyNote {
+    // discriminator(1) + is_initialized(1) + board(32) + note_id(8) + author(32) + category_id(1) + content(4 + MAX) + created_at_slot(8) + group_id(9) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 8 + 32 + 1 + (4 + MAX_NOTE_CHARS) + 8 + 9 + 1;
+}
+
+/// Group had no note count; migration counts the notes that point at it
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyGroup {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub group_id: u64,
+    pub title: String,
+    pub created_by: Pubkey,
+    pub vote_tally: u64,
+    pub bump: u8,
+}
+
+impl LegacyGroup {
+    // discriminator(1) + is_initialized(1) + board(32) + group_id(8) + title(4 + MAX) + created_by(32) + vote_tally(8) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 8 + (4 + MAX_GROUP_TITLE_CHARS) + 32 + 8 + 1;
+}
+
+/// Votes were always linear, so `credits_spent` is also the number of votes
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyVoteRecord {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub participant: Pubkey,
+    pub group_id: u64,
+    pub credits_spent: u8,
+    pub bump: u8,
+}
+
+impl LegacyVoteRecord {
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + group_id(8) + credits_spent(1) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 1 + 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyActionItem {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub action_item_id: u64,
+    pub description: String,
+    pub owner: Pubkey,
+    pub verifiers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub approvals: u8,
+    pub status: ActionItemStatus,
+    pub created_at_slot: u64,
+    pub verified_at_slot: Option<u64>,
+    pub bump: u8,
+}
+
+impl LegacyActionItem {
+    // discriminator(1) + is_initialized(1) + board(32) + action_item_id(8) +
+    // description(4 + MAX_ACTION_DESCRIPTION_CHARS) + owner(32) +
+    // verifiers(4 + LEGACY_MAX_VERIFIERS * 32) + threshold(1) + approvals(1) +
+    // status(1) + created_at_slot(8) + verified_at_slot(1 + 8) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 8 + (4 + MAX_ACTION_DESCRIPTION_CHARS) + 32
+        + (4 + LEGACY_MAX_VERIFIERS * 32) + 1 + 1 + 1 + 8 + 9 + 1;
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct LegacyVerificationVote {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub action_item: Pubkey,
+    pub verifier: Pubkey,
+    pub approved: bool,
+    pub voted_at_slot: u64,
+    pub bump: u8,
+}
+
+impl LegacyVerificationVote {
+    // discriminator(1) + is_initialized(1) + action_item(32) + verifier(32) + approved(1) + voted_at_slot(8) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8 + 1;
+}
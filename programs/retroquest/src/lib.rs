@@ -1,6 +1,7 @@
 pub mod entrypoint;
 pub mod error;
 pub mod instructions;
+pub mod legacy;
 pub mod processor;
 pub mod state;
 
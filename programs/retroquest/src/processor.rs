@@ -13,7 +13,8 @@ use solana_program::{
 
 use crate::{
     error::RetroError,
-    instructions::RetroInstruction,
+    instructions::{BoardConfig, RetroInstruction},
+    legacy::*,
     state::*,
 };
 use session_keys::{validate_signer_or_session, SessionToken, SESSION_TOKEN_SEED};
@@ -29,26 +30,20 @@ pub fn process_instruction(
         RetroInstruction::InitFacilitatorRegistry => {
             process_init_facilitator_registry(program_id, accounts)
         }
-        RetroInstruction::CreateBoard {
-            categories,
-            allowlist,
-            voting_credits_per_participant,
-        } => process_create_board(
-            program_id,
-            accounts,
-            categories,
-            allowlist,
-            voting_credits_per_participant,
-        ),
+        RetroInstruction::CreateBoard { config } => {
+            process_create_board(program_id, accounts, config)
+        }
         RetroInstruction::AdvanceStage { new_stage } => {
             process_advance_stage(program_id, accounts, new_stage)
         }
         RetroInstruction::CloseBoard => {
             process_close_board(program_id, accounts)
         }
-        RetroInstruction::CreateNote { category_id, content } => {
-            process_create_note(program_id, accounts, category_id, content)
-        }
+        RetroInstruction::CreateNote {
+            category_id,
+            content,
+            author_commitment,
+        } => process_create_note(program_id, accounts, category_id, content, author_commitment),
         RetroInstruction::CreateGroup { title } => {
             process_create_group(program_id, accounts, title)
         }
@@ -69,7 +64,16 @@ pub fn process_instruction(
             owner,
             verifiers,
             threshold,
-        } => process_create_action_item(program_id, accounts, description, owner, verifiers, threshold),
+            due_at,
+        } => process_create_action_item(
+            program_id,
+            accounts,
+            description,
+            owner,
+            verifiers,
+            threshold,
+            due_at,
+        ),
         RetroInstruction::CastVerificationVote {
             action_item_id,
             approved,
@@ -85,6 +89,101 @@ pub fn process_instruction(
         RetroInstruction::UpdateIdentity { username } => {
             process_update_identity(program_id, accounts, username)
         }
+        RetroInstruction::ReclaimBoard => process_reclaim_board(program_id, accounts),
+        RetroInstruction::ReclaimMembership => process_reclaim_membership(program_id, accounts),
+        RetroInstruction::ReclaimNote { salt } => process_reclaim_note(program_id, accounts, salt),
+        RetroInstruction::ReclaimGroup => process_reclaim_group(program_id, accounts),
+        RetroInstruction::ReclaimVoteRecord => process_reclaim_vote_record(program_id, accounts),
+        RetroInstruction::ReclaimActionItem => process_reclaim_action_item(program_id, accounts),
+        RetroInstruction::ReclaimVerificationVote => {
+            process_reclaim_verification_vote(program_id, accounts)
+        }
+        RetroInstruction::RevealNoteAuthor { salt } => {
+            process_reveal_note_author(program_id, accounts, salt)
+        }
+        RetroInstruction::UpdateNote {
+            note_id,
+            category_id,
+            content,
+            salt,
+        } => process_update_note(program_id, accounts, note_id, category_id, content, salt),
+        RetroInstruction::DeleteNote { note_id, salt } => {
+            process_delete_note(program_id, accounts, note_id, salt)
+        }
+        RetroInstruction::AddParticipant { participant } => {
+            process_add_participant(program_id, accounts, participant)
+        }
+        RetroInstruction::RemoveParticipant { participant } => {
+            process_remove_participant(program_id, accounts, participant)
+        }
+        RetroInstruction::ReviewActionItem {
+            action_item_id,
+            approved,
+        } => process_review_action_item(program_id, accounts, action_item_id, approved),
+        RetroInstruction::ExpireActionItem => process_expire_action_item(program_id, accounts),
+        RetroInstruction::UpdateVerificationVote { approved } => {
+            process_update_verification_vote(program_id, accounts, approved)
+        }
+        RetroInstruction::ReassignActionItem {
+            owner,
+            verifiers,
+            threshold,
+        } => process_reassign_action_item(program_id, accounts, owner, verifiers, threshold),
+        RetroInstruction::InitReputation => process_init_reputation(program_id, accounts),
+        RetroInstruction::CreateTeam { name } => process_create_team(program_id, accounts, name),
+        RetroInstruction::SetTeamMember { member, role } => {
+            process_set_team_member(program_id, accounts, member, role)
+        }
+        RetroInstruction::RemoveTeamMember { member } => {
+            process_remove_team_member(program_id, accounts, member)
+        }
+        RetroInstruction::ProposeFacilitator { new_facilitator } => {
+            process_propose_facilitator(program_id, accounts, new_facilitator)
+        }
+        RetroInstruction::AcceptFacilitator => process_accept_facilitator(program_id, accounts),
+        RetroInstruction::CrankAdvanceStage => process_crank_advance_stage(program_id, accounts),
+        RetroInstruction::RevertStage { target_stage } => {
+            process_revert_stage(program_id, accounts, target_stage)
+        }
+        RetroInstruction::RetractVote { group_id, votes } => {
+            process_retract_vote(program_id, accounts, group_id, votes)
+        }
+        RetroInstruction::SubmitBallot { ranking } => {
+            process_submit_ballot(program_id, accounts, ranking)
+        }
+        RetroInstruction::ReclaimBallot => process_reclaim_ballot(program_id, accounts),
+        RetroInstruction::CommitVotes { commitment } => {
+            process_commit_votes(program_id, accounts, commitment)
+        }
+        RetroInstruction::RevealVotes { allocations, salt } => {
+            process_reveal_votes(program_id, accounts, allocations, salt)
+        }
+        RetroInstruction::ReclaimVoteCommitment => {
+            process_reclaim_vote_commitment(program_id, accounts)
+        }
+        RetroInstruction::SetCreditBudget { participant, credits } => {
+            process_set_credit_budget(program_id, accounts, participant, credits)
+        }
+        RetroInstruction::MergeGroups {
+            target_group_id,
+            source_group_id,
+            note_ids,
+        } => process_merge_groups(program_id, accounts, target_group_id, source_group_id, note_ids),
+        RetroInstruction::SplitGroup {
+            group_id,
+            title,
+            note_ids,
+        } => process_split_group(program_id, accounts, group_id, title, note_ids),
+        RetroInstruction::MoveNote {
+            note_id,
+            from_group_id,
+            to_group_id,
+        } => process_move_note(program_id, accounts, note_id, from_group_id, to_group_id),
+        RetroInstruction::DeleteGroup { group_id } => {
+            process_delete_group(program_id, accounts, group_id)
+        }
+        RetroInstruction::MigrateAccount => process_migrate_account(program_id, accounts),
+        RetroInstruction::TallyBallots => process_tally_ballots(program_id, accounts),
     }
 }
 
@@ -148,17 +247,27 @@ fn process_init_facilitator_registry(
 fn process_create_board(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    categories: Vec<String>,
-    allowlist: Vec<Pubkey>,
-    voting_credits_per_participant: Option<u8>,
+    config: BoardConfig,
 ) -> ProgramResult {
     msg!("Instruction: CreateBoard");
+    let BoardConfig {
+        categories,
+        allowlist,
+        voting_credits_per_participant,
+        hide_note_authors,
+        predecessor,
+        stages,
+        stage_duration_slots,
+        voting_mode,
+        credit_budgets,
+    } = config;
 
     // Determine if session token is present based on account count
-    // Without session: registry, board, signer, system, memberships... (4 + allowlist.len())
-    // With session: registry, board, signer, system, session_token, memberships... (5 + allowlist.len())
+    // Without session: registry, board, signer, system, memberships..., [predecessor] (4 + allowlist.len() + 1?)
+    // With session: registry, board, signer, system, session_token, memberships..., [predecessor] (5 + allowlist.len() + 1?)
     let num_base_accounts = 4;
-    let expected_without_session = num_base_accounts + allowlist.len();
+    let expected_without_session =
+        num_base_accounts + allowlist.len() + usize::from(predecessor.is_some());
     let expected_with_session = expected_without_session + 1;
 
     let has_session_token = if accounts.len() == expected_with_session {
@@ -219,28 +328,75 @@ fn process_create_board(
         }
     }
 
-    // Validate allowlist
-    if allowlist.len() > MAX_PARTICIPANTS {
+    // Validate allowlist; each entry costs its key in the payload plus its membership account,
+    // so larger boards add the rest with AddParticipant
+    if allowlist.len() > MAX_INITIAL_PARTICIPANTS {
         return Err(RetroError::MaxParticipantsReached.into());
     }
 
-    // Deserialize and validate facilitator registry
-    let mut registry = FacilitatorRegistry::deserialize(&mut &registry_info.data.borrow()[..])?;
-    if registry.discriminator != DISCRIMINATOR_FACILITATOR_REGISTRY {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !registry.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
-    if registry.facilitator != facilitator {
-        return Err(RetroError::UnauthorizedFacilitator.into());
-    }
+    // Validate stage pipeline: Setup first, canonical order, review only on follow-up boards,
+    // and voting only after grouping since votes go to groups
+    let stages = stages.unwrap_or_else(|| {
+        BoardStage::default_pipeline(predecessor.is_some(), voting_mode == VotingMode::Sealed)
+    });
+    if stages.first() != Some(&BoardStage::Setup)
+        || stages.len() < 2
+        || stages.windows(2).any(|w| w[0].rank() >= w[1].rank())
+        || stages.contains(&BoardStage::ReviewPreviousActions) != predecessor.is_some()
+        || (stages.contains(&BoardStage::Vote) && !stages.contains(&BoardStage::GroupDuplicates))
+        || (voting_mode == VotingMode::Ranked
+            && stages.contains(&BoardStage::Vote)
+            && !stages.contains(&BoardStage::Discuss))
+        || stages.contains(&BoardStage::RevealVotes)
+            != (voting_mode == VotingMode::Sealed && stages.contains(&BoardStage::Vote))
+    {
+        return Err(RetroError::InvalidStagePipeline.into());
+    }
+
+    let credit_budgets = credit_budgets.unwrap_or_default();
+    if !credit_budgets.is_empty() && credit_budgets.len() != allowlist.len() {
+        return Err(RetroError::InvalidCreditBudgets.into());
+    }
+
+    let stage_duration_slots = stage_duration_slots.unwrap_or_default();
+    if !stage_duration_slots.is_empty() && stage_duration_slots.len() != stages.len() {
+        return Err(RetroError::InvalidStageDurations.into());
+    }
+
+    // Boards are numbered by the facilitator's registry, or by the team for team boards
+    let team = if registry_info.owner == program_id
+        && registry_info.data.borrow().first() == Some(&DISCRIMINATOR_TEAM)
+    {
+        Some(*registry_info.key)
+    } else {
+        None
+    };
+
+    let (board_authority, board_index) = if team.is_some() {
+        let team_state = load_team(program_id, registry_info)?;
+        if !team_state.is_facilitator(&facilitator) {
+            return Err(RetroError::UnauthorizedFacilitator.into());
+        }
+        (*registry_info.key, team_state.board_count)
+    } else {
+        // Deserialize and validate facilitator registry
+        let registry = FacilitatorRegistry::deserialize(&mut &registry_info.data.borrow()[..])?;
+        if registry.discriminator != DISCRIMINATOR_FACILITATOR_REGISTRY {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        if !registry.is_initialized {
+            return Err(RetroError::AccountNotInitialized.into());
+        }
+        if registry.facilitator != facilitator {
+            return Err(RetroError::UnauthorizedFacilitator.into());
+        }
+        (facilitator, registry.board_count)
+    };
 
-    let board_index = registry.board_count;
     let (pda, bump) = Pubkey::find_program_address(
         &[
             BOARD_SEED,
-            facilitator.as_ref(),
+            board_authority.as_ref(),
             &board_index.to_le_bytes(),
         ],
         program_id,
@@ -269,7 +425,7 @@ fn process_create_board(
         ],
         &[&[
             BOARD_SEED,
-            facilitator.as_ref(),
+            board_authority.as_ref(),
             &board_index.to_le_bytes(),
             &[bump],
         ]],
@@ -280,24 +436,41 @@ fn process_create_board(
         discriminator: DISCRIMINATOR_RETRO_BOARD,
         is_initialized: true,
         facilitator,
+        pending_facilitator: None,
         board_index,
         stage: BoardStage::Setup,
+        stages,
+        stage_duration_slots,
         closed: false,
         categories,
-        allowlist,
         voting_credits_per_participant: voting_credits_per_participant.unwrap_or(VOTING_CREDITS_DEFAULT),
+        voting_mode,
+        vote_round: 0,
+        ballot_count: 0,
+        tallied_group_count: 0,
+        candidate_count: 0,
+        tallied_ballot_count: 0,
+        last_tallied_participant: None,
+        hide_note_authors,
         note_count: 0,
         group_count: 0,
         action_item_count: 0,
+        pending_action_item_count: 0,
+        live_action_item_count: 0,
+        legacy_note_count: 0,
+        membership_count: allowlist.len() as u32,
+        team,
+        predecessor,
         created_at_slot: clock.slot,
         stage_changed_at_slot: clock.slot,
+        rent_payer: *signer_info.key,
         bump,
     };
 
     board.serialize(&mut *board_info.data.borrow_mut())?;
 
-    // Create BoardMembership for each allowlist member (enables board discovery)
-    for participant_pubkey in &board.allowlist {
+    // Create BoardMembership for each allowlist member (grants access and enables board discovery)
+    for (i, participant_pubkey) in allowlist.iter().enumerate() {
         let membership_info = next_account_info(account_info_iter)?;
 
         let (pda, membership_bump) = Pubkey::find_program_address(
@@ -333,16 +506,67 @@ fn process_create_board(
             is_initialized: true,
             board: *board_info.key,
             participant: *participant_pubkey,
+            voting_credits: credit_budgets
+                .get(i)
+                .copied()
+                .unwrap_or(board.voting_credits_per_participant),
             credits_spent: 0,
             total_score: 0,
+            rejected_count: 0,
+            vote_round: 0,
+            rent_payer: *signer_info.key,
             bump: membership_bump,
         };
         membership.serialize(&mut *membership_info.data.borrow_mut())?;
     }
 
-    // Update facilitator registry
-    registry.board_count += 1;
-    registry.serialize(&mut *registry_info.data.borrow_mut())?;
+    // A follow-up board must point at a closed board from the same team, or one the facilitator
+    // created or was handed over
+    if let Some(predecessor_key) = predecessor {
+        let predecessor_info = next_account_info(account_info_iter)?;
+        if *predecessor_info.key != predecessor_key {
+            return Err(RetroError::InvalidPredecessor.into());
+        }
+        if predecessor_info.owner != program_id {
+            return Err(RetroError::InvalidAccountOwner.into());
+        }
+
+        let predecessor_board = RetroBoard::deserialize(&mut &predecessor_info.data.borrow()[..])?;
+        if predecessor_board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        let same_series = match team {
+            Some(_) => predecessor_board.team == team,
+            None => {
+                // The board address keeps the facilitator it was created under after a handover
+                let created_by_facilitator = Pubkey::create_program_address(
+                    &[
+                        BOARD_SEED,
+                        facilitator.as_ref(),
+                        &predecessor_board.board_index.to_le_bytes(),
+                        &[predecessor_board.bump],
+                    ],
+                    program_id,
+                ) == Ok(predecessor_key);
+                predecessor_board.team.is_none()
+                    && (predecessor_board.facilitator == facilitator || created_by_facilitator)
+            }
+        };
+        if !same_series || !predecessor_board.closed {
+            return Err(RetroError::InvalidPredecessor.into());
+        }
+    }
+
+    // Update the board counter on the facilitator registry or team
+    if team.is_some() {
+        let mut team_state = Team::deserialize(&mut &registry_info.data.borrow()[..])?;
+        team_state.board_count += 1;
+        team_state.serialize(&mut *registry_info.data.borrow_mut())?;
+    } else {
+        let mut registry = FacilitatorRegistry::deserialize(&mut &registry_info.data.borrow()[..])?;
+        registry.board_count += 1;
+        registry.serialize(&mut *registry_info.data.borrow_mut())?;
+    }
 
     Ok(())
 }
@@ -357,6 +581,21 @@ fn process_advance_stage(
 
     let board_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
     // Optional session token for session-based signing
     let session_token_info = next_account_info(account_info_iter).ok();
 
@@ -377,26 +616,15 @@ fn process_advance_stage(
         program_id,
     )?;
 
-    if board_info.owner != program_id {
-        return Err(RetroError::InvalidAccountOwner.into());
-    }
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
 
-    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
-    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !board.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
-    if board.facilitator != facilitator {
-        return Err(RetroError::UnauthorizedFacilitator.into());
-    }
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if !board.stage.can_advance_to(new_stage) {
+    if board.next_stage() != Some(new_stage) {
         return Err(RetroError::InvalidStageTransition.into());
     }
+    check_ballots_tallied(&board)?;
 
     let clock = Clock::get()?;
     board.stage = new_stage;
@@ -416,6 +644,21 @@ fn process_close_board(
 
     let board_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
     // Optional session token for session-based signing
     let session_token_info = next_account_info(account_info_iter).ok();
 
@@ -436,24 +679,12 @@ fn process_close_board(
         program_id,
     )?;
 
-    if board_info.owner != program_id {
-        return Err(RetroError::InvalidAccountOwner.into());
-    }
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
 
-    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
-    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !board.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
-    if board.facilitator != facilitator {
-        return Err(RetroError::UnauthorizedFacilitator.into());
-    }
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::Discuss {
+    if !board.is_final_stage() {
         return Err(RetroError::InvalidStage.into());
     }
 
@@ -468,12 +699,14 @@ fn process_create_note(
     accounts: &[AccountInfo],
     category_id: u8,
     content: String,
+    author_commitment: Option<[u8; 32]>,
 ) -> ProgramResult {
     msg!("Instruction: CreateNote");
     let account_info_iter = &mut accounts.iter();
 
     let board_info = next_account_info(account_info_iter)?;
     let note_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     let system_program_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
@@ -512,13 +745,12 @@ fn process_create_note(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::WriteNotes {
+    if !board.stage.capabilities().notes {
         return Err(RetroError::InvalidStage.into());
     }
 
-    if !board.allowlist.contains(&author) {
-        return Err(RetroError::NotOnAllowlist.into());
-    }
+    // The allowlist is checked against the real author even when the stored note hides it
+    load_membership(program_id, board_info.key, &author, membership_info)?;
 
     if content.len() > MAX_NOTE_CHARS {
         return Err(RetroError::NoteTooLong.into());
@@ -526,6 +758,9 @@ fn process_create_note(
     if category_id as usize >= board.categories.len() {
         return Err(RetroError::InvalidCategoryId.into());
     }
+    if author_commitment.is_some() != board.hide_note_authors {
+        return Err(RetroError::InvalidAuthorCommitment.into());
+    }
 
     let note_id = board.note_count;
     let (pda, bump) = Pubkey::find_program_address(
@@ -563,11 +798,14 @@ fn process_create_note(
         is_initialized: true,
         board: *board_info.key,
         note_id,
-        author,
+        author: if author_commitment.is_some() { Pubkey::default() } else { author },
+        author_commitment,
         category_id,
         content,
         created_at_slot: clock.slot,
         group_id: None,
+        // The payer would give the author away; the rent goes back through the commitment instead
+        rent_payer: if author_commitment.is_some() { Pubkey::default() } else { *signer_info.key },
         bump,
     };
 
@@ -589,6 +827,7 @@ fn process_create_group(
 
     let board_info = next_account_info(account_info_iter)?;
     let group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     let system_program_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
@@ -625,14 +864,12 @@ fn process_create_group(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::GroupDuplicates {
+    if !board.stage.capabilities().grouping {
         return Err(RetroError::InvalidStage.into());
     }
 
     // Check allowlist
-    if !board.allowlist.contains(&creator) {
-        return Err(RetroError::NotOnAllowlist.into());
-    }
+    load_membership(program_id, board_info.key, &creator, membership_info)?;
 
     if title.len() > MAX_GROUP_TITLE_CHARS {
         return Err(RetroError::GroupTitleTooLong.into());
@@ -676,6 +913,9 @@ fn process_create_group(
         title,
         created_by: creator,
         vote_tally: 0,
+        vote_round: board.vote_round,
+        note_count: 0,
+        rent_payer: *signer_info.key,
         bump,
     };
 
@@ -698,6 +938,7 @@ fn process_set_group_title(
 
     let board_info = next_account_info(account_info_iter)?;
     let group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
     let session_token_info = next_account_info(account_info_iter).ok();
@@ -733,14 +974,12 @@ fn process_set_group_title(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::GroupDuplicates {
+    if !board.stage.capabilities().grouping {
         return Err(RetroError::InvalidStage.into());
     }
 
     // Check allowlist
-    if !board.allowlist.contains(&participant) {
-        return Err(RetroError::NotOnAllowlist.into());
-    }
+    load_membership(program_id, board_info.key, &participant, membership_info)?;
 
     if title.len() > MAX_GROUP_TITLE_CHARS {
         return Err(RetroError::GroupTitleTooLong.into());
@@ -763,7 +1002,7 @@ fn process_set_group_title(
 fn process_assign_note_to_group(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    _note_id: u64,
+    note_id: u64,
     group_id: u64,
 ) -> ProgramResult {
     msg!("Instruction: AssignNoteToGroup");
@@ -772,6 +1011,7 @@ fn process_assign_note_to_group(
     let board_info = next_account_info(account_info_iter)?;
     let note_info = next_account_info(account_info_iter)?;
     let group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
     let session_token_info = next_account_info(account_info_iter).ok();
@@ -807,30 +1047,15 @@ fn process_assign_note_to_group(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::GroupDuplicates {
+    if !board.stage.capabilities().grouping {
         return Err(RetroError::InvalidStage.into());
     }
 
     // Check allowlist
-    if !board.allowlist.contains(&participant) {
-        return Err(RetroError::NotOnAllowlist.into());
-    }
-
-    let group = Group::deserialize(&mut &group_info.data.borrow()[..])?;
-    if group.discriminator != DISCRIMINATOR_GROUP {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !group.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
+    load_membership(program_id, board_info.key, &participant, membership_info)?;
 
-    let mut note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
-    if note.discriminator != DISCRIMINATOR_NOTE {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !note.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
+    let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+    let mut note = load_note(program_id, board_info.key, note_id, note_info)?;
     if note.group_id.is_some() {
         return Err(RetroError::NoteAlreadyGrouped.into());
     }
@@ -838,19 +1063,24 @@ fn process_assign_note_to_group(
     note.group_id = Some(group_id);
     note.serialize(&mut *note_info.data.borrow_mut())?;
 
+    group.note_count += 1;
+    group.serialize(&mut *group_info.data.borrow_mut())?;
+
     Ok(())
 }
 
 fn process_unassign_note(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    _note_id: u64,
+    note_id: u64,
 ) -> ProgramResult {
     msg!("Instruction: UnassignNote");
     let account_info_iter = &mut accounts.iter();
 
     let board_info = next_account_info(account_info_iter)?;
     let note_info = next_account_info(account_info_iter)?;
+    let group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
     let session_token_info = next_account_info(account_info_iter).ok();
@@ -886,29 +1116,26 @@ fn process_unassign_note(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::GroupDuplicates {
+    if !board.stage.capabilities().grouping {
         return Err(RetroError::InvalidStage.into());
     }
 
     // Check allowlist
-    if !board.allowlist.contains(&participant) {
-        return Err(RetroError::NotOnAllowlist.into());
-    }
+    load_membership(program_id, board_info.key, &participant, membership_info)?;
 
-    let mut note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
-    if note.discriminator != DISCRIMINATOR_NOTE {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !note.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
-    }
-    if note.group_id.is_none() {
-        return Err(RetroError::NoteNotGrouped.into());
-    }
+    let mut note = load_note(program_id, board_info.key, note_id, note_info)?;
+    let group_id = note.group_id.ok_or(RetroError::NoteNotGrouped)?;
+    let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
 
     note.group_id = None;
     note.serialize(&mut *note_info.data.borrow_mut())?;
 
+    group.note_count = group
+        .note_count
+        .checked_sub(1)
+        .ok_or(RetroError::GroupNoteCountMismatch)?;
+    group.serialize(&mut *group_info.data.borrow_mut())?;
+
     Ok(())
 }
 
@@ -961,82 +1188,26 @@ fn process_cast_vote(
     if board.closed {
         return Err(RetroError::BoardClosed.into());
     }
-    if board.stage != BoardStage::Vote {
+    if !board.stage.capabilities().voting {
         return Err(RetroError::InvalidStage.into());
     }
-
-    // Check allowlist
-    if !board.allowlist.contains(&voter) {
-        return Err(RetroError::NotOnAllowlist.into());
+    check_notes_migrated(&board)?;
+    if !board.voting_mode.has_live_tally() {
+        return Err(RetroError::InvalidVotingMode.into());
     }
 
+    // Check allowlist (uses voter/authority, not session signer)
+    let mut membership = load_membership(program_id, board_info.key, &voter, membership_info)?;
+    membership.sync_vote_round(board.vote_round);
+
     if credits_delta == 0 {
         return Err(RetroError::CannotDecreaseVotes.into());
     }
 
-    // Verify BoardMembership PDA (uses voter/authority, not session signer)
-    let (membership_pda, membership_bump) = Pubkey::find_program_address(
-        &[MEMBERSHIP_SEED, board_info.key.as_ref(), voter.as_ref()],
-        program_id,
-    );
-
-    if membership_pda != *membership_info.key {
-        return Err(RetroError::InvalidPDA.into());
-    }
-
-    // Create or load BoardMembership (lazy creation on first vote for backward compatibility)
-    let mut membership = if membership_info.data_is_empty() {
-        let rent = Rent::get()?;
-        let space = BoardMembership::LEN;
-        let lamports = rent.minimum_balance(space);
-
-        invoke_signed(
-            &system_instruction::create_account(
-                signer_info.key,
-                membership_info.key,
-                lamports,
-                space as u64,
-                program_id,
-            ),
-            &[
-                signer_info.clone(),
-                membership_info.clone(),
-                system_program_info.clone(),
-            ],
-            &[&[MEMBERSHIP_SEED, board_info.key.as_ref(), voter.as_ref(), &[membership_bump]]],
-        )?;
-
-        BoardMembership {
-            discriminator: DISCRIMINATOR_BOARD_MEMBERSHIP,
-            is_initialized: true,
-            board: *board_info.key,
-            participant: voter,
-            credits_spent: 0,
-            total_score: 0,
-            bump: membership_bump,
-        }
-    } else {
-        let m = BoardMembership::deserialize(&mut &membership_info.data.borrow()[..])?;
-        if m.discriminator != DISCRIMINATOR_BOARD_MEMBERSHIP {
-            return Err(RetroError::InvalidAccountDiscriminator.into());
-        }
-        m
-    };
-
-    let total_credits_after = membership.credits_spent
-        .checked_add(credits_delta)
-        .ok_or(RetroError::InsufficientCredits)?;
-
-    if total_credits_after > board.voting_credits_per_participant {
-        return Err(RetroError::InsufficientCredits.into());
-    }
-
-    let mut group = Group::deserialize(&mut &group_info.data.borrow()[..])?;
-    if group.discriminator != DISCRIMINATOR_GROUP {
-        return Err(RetroError::InvalidAccountDiscriminator.into());
-    }
-    if !group.is_initialized {
-        return Err(RetroError::AccountNotInitialized.into());
+    let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+    group.sync_vote_round(board.vote_round);
+    if group.note_count == 0 {
+        return Err(RetroError::EmptyGroup.into());
     }
 
     // Verify VoteRecord PDA (uses voter/authority, not session signer)
@@ -1077,23 +1248,40 @@ fn process_cast_vote(
             board: *board_info.key,
             participant: voter,
             group_id,
+            votes: 0,
             credits_spent: 0,
+            vote_round: board.vote_round,
+            rent_payer: *signer_info.key,
             bump: vote_bump,
         }
     } else {
-        let v = VoteRecord::deserialize(&mut &vote_record_info.data.borrow()[..])?;
+        let mut v = VoteRecord::deserialize(&mut &vote_record_info.data.borrow()[..])?;
         if v.discriminator != DISCRIMINATOR_VOTE_RECORD {
             return Err(RetroError::InvalidAccountDiscriminator.into());
         }
+        v.sync_vote_round(board.vote_round);
         v
     };
 
-    vote_record.credits_spent = vote_record.credits_spent
+    // Charge the difference between what the group's votes cost before and after
+    let votes_after = vote_record.votes
         .checked_add(credits_delta)
         .ok_or(RetroError::InsufficientCredits)?;
+    let record_credits_after = board.voting_mode.cost(votes_after);
+    let total_credits_after = (membership.credits_spent as u64)
+        .checked_sub(vote_record.credits_spent as u64)
+        .ok_or(RetroError::VoteRecordMismatch)?
+        + record_credits_after;
+
+    if total_credits_after > membership.voting_credits as u64 {
+        return Err(RetroError::InsufficientCredits.into());
+    }
+
+    vote_record.votes = votes_after;
+    vote_record.credits_spent = record_credits_after as u8;
     vote_record.serialize(&mut *vote_record_info.data.borrow_mut())?;
 
-    membership.credits_spent = total_credits_after;
+    membership.credits_spent = total_credits_after as u8;
     membership.serialize(&mut *membership_info.data.borrow_mut())?;
 
     group.vote_tally = group.vote_tally
@@ -1111,34 +1299,11 @@ fn process_create_action_item(
     owner: Pubkey,
     verifiers: Vec<Pubkey>,
     threshold: u8,
+    due_at: Option<i64>,
 ) -> ProgramResult {
     msg!("Instruction: CreateActionItem");
-    let account_info_iter = &mut accounts.iter();
-
-    let board_info = next_account_info(account_info_iter)?;
-    let action_item_info = next_account_info(account_info_iter)?;
-    let signer_info = next_account_info(account_info_iter)?;
-    let system_program_info = next_account_info(account_info_iter)?;
-    // Optional session token for session-based signing
-    let session_token_info = next_account_info(account_info_iter).ok();
-
-    // Determine the facilitator (authority) - either from session token or direct signer
-    let facilitator = if let Some(session_info) = session_token_info {
-        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
-        session.authority
-    } else {
-        *signer_info.key
-    };
-
-    // Validate signer or session
-    validate_signer_or_session(
-        signer_info,
-        &facilitator,
-        session_token_info,
-        program_id,
-        program_id,
-    )?;
 
+    let board_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
     if board_info.owner != program_id {
         return Err(RetroError::InvalidAccountOwner.into());
     }
@@ -1150,19 +1315,70 @@ fn process_create_action_item(
     if !board.is_initialized {
         return Err(RetroError::AccountNotInitialized.into());
     }
-    if board.closed {
-        return Err(RetroError::BoardClosed.into());
-    }
-    if board.stage != BoardStage::Discuss {
-        return Err(RetroError::InvalidStage.into());
-    }
-    if board.facilitator != facilitator {
-        return Err(RetroError::UnauthorizedFacilitator.into());
-    }
 
-    // Validate description length
-    if description.len() > MAX_ACTION_DESCRIPTION_CHARS {
-        return Err(RetroError::ActionDescriptionTooLong.into());
+    // Determine if session token is present based on account count
+    // Without session: board, action_item, signer, system, [team], memberships... (4 + team + 1 + verifiers.len())
+    // With session: board, action_item, signer, system, [team], session_token, memberships... (5 + team + 1 + verifiers.len())
+    let num_base_accounts = 4 + usize::from(board.team.is_some());
+    let expected_without_session = num_base_accounts + 1 + verifiers.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter().skip(1);
+
+    let action_item_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    let team_info = next_team_account(&board, account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().action_items {
+        return Err(RetroError::InvalidStage.into());
+    }
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    // Validate description length
+    if description.len() > MAX_ACTION_DESCRIPTION_CHARS {
+        return Err(RetroError::ActionDescriptionTooLong.into());
     }
 
     // Validate verifiers count
@@ -1177,17 +1393,23 @@ fn process_create_action_item(
     if threshold as usize > verifiers.len() {
         return Err(RetroError::ThresholdTooHigh.into());
     }
+    if has_duplicate_verifiers(&verifiers) {
+        return Err(RetroError::DuplicateVerifier.into());
+    }
 
-    // Validate owner is on allowlist
-    if !board.allowlist.contains(&owner) {
-        return Err(RetroError::NotOnAllowlist.into());
+    let clock = Clock::get()?;
+    if due_at.is_some_and(|due_at| due_at <= clock.unix_timestamp) {
+        return Err(RetroError::InvalidDueDate.into());
     }
 
+    // Validate owner is on allowlist
+    let owner_membership_info = next_account_info(account_info_iter)?;
+    load_membership(program_id, board_info.key, &owner, owner_membership_info)?;
+
     // Validate all verifiers are on allowlist and owner is not a verifier
     for verifier in &verifiers {
-        if !board.allowlist.contains(verifier) {
-            return Err(RetroError::NotOnAllowlist.into());
-        }
+        let verifier_membership_info = next_account_info(account_info_iter)?;
+        load_membership(program_id, board_info.key, verifier, verifier_membership_info)?;
         if *verifier == owner {
             return Err(RetroError::OwnerCannotVerify.into());
         }
@@ -1223,7 +1445,6 @@ fn process_create_action_item(
         &[&[ACTION_ITEM_SEED, board_info.key.as_ref(), &action_item_id.to_le_bytes(), &[bump]]],
     )?;
 
-    let clock = Clock::get()?;
     let action_item = ActionItem {
         discriminator: DISCRIMINATOR_ACTION_ITEM,
         is_initialized: true,
@@ -1234,15 +1455,20 @@ fn process_create_action_item(
         verifiers,
         threshold,
         approvals: 0,
+        rejections: 0,
         status: ActionItemStatus::Pending,
         created_at_slot: clock.slot,
         verified_at_slot: None,
+        due_at,
+        rent_payer: *signer_info.key,
         bump,
     };
 
     action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
 
     board.action_item_count += 1;
+    board.pending_action_item_count += 1;
+    board.live_action_item_count += 1;
     board.serialize(&mut *board_info.data.borrow_mut())?;
 
     Ok(())
@@ -1251,16 +1477,38 @@ fn process_create_action_item(
 fn process_cast_verification_vote(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    _action_item_id: u64,
+    action_item_id: u64,
     approved: bool,
 ) -> ProgramResult {
     msg!("Instruction: CastVerificationVote");
     let account_info_iter = &mut accounts.iter();
 
     let board_info = next_account_info(account_info_iter)?;
+
+    record_verification_vote(program_id, board_info, account_info_iter, action_item_id, approved)
+}
+
+// A verifier listed twice would pass the threshold with a single vote
+fn has_duplicate_verifiers(verifiers: &[Pubkey]) -> bool {
+    verifiers
+        .iter()
+        .enumerate()
+        .any(|(i, verifier)| verifiers[..i].contains(verifier))
+}
+
+// Verification helper shared by CastVerificationVote and ReviewActionItem.
+// Consumes action item, vote, owner membership, signer, system program and optional session.
+fn record_verification_vote<'a, 'b>(
+    program_id: &Pubkey,
+    board_info: &'a AccountInfo<'b>,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+    action_item_id: u64,
+    approved: bool,
+) -> ProgramResult {
     let action_item_info = next_account_info(account_info_iter)?;
     let vote_info = next_account_info(account_info_iter)?;
     let owner_membership_info = next_account_info(account_info_iter)?;
+    let owner_reputation_info = next_account_info(account_info_iter)?;
     let signer_info = next_account_info(account_info_iter)?;
     let system_program_info = next_account_info(account_info_iter)?;
     // Optional session token for session-based signing
@@ -1287,7 +1535,7 @@ fn process_cast_verification_vote(
         return Err(RetroError::InvalidAccountOwner.into());
     }
 
-    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
     if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
         return Err(RetroError::InvalidAccountDiscriminator.into());
     }
@@ -1299,6 +1547,15 @@ fn process_cast_verification_vote(
         return Err(RetroError::BoardNotClosed.into());
     }
 
+    // Verify ActionItem PDA
+    let (action_item_pda, _) = Pubkey::find_program_address(
+        &[ACTION_ITEM_SEED, board_info.key.as_ref(), &action_item_id.to_le_bytes()],
+        program_id,
+    );
+
+    if action_item_pda != *action_item_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
     if action_item_info.owner != program_id {
         return Err(RetroError::InvalidAccountOwner.into());
     }
@@ -1310,10 +1567,18 @@ fn process_cast_verification_vote(
     if !action_item.is_initialized {
         return Err(RetroError::AccountNotInitialized.into());
     }
+    if action_item.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
     if action_item.status != ActionItemStatus::Pending {
         return Err(RetroError::ActionItemNotPending.into());
     }
 
+    let clock = Clock::get()?;
+    if action_item.is_past_due(clock.unix_timestamp) {
+        return Err(RetroError::ActionItemPastDue.into());
+    }
+
     // Validate verifier is in the action item's verifiers list
     if !action_item.verifiers.contains(&verifier) {
         return Err(RetroError::NotAVerifier.into());
@@ -1365,7 +1630,6 @@ fn process_cast_verification_vote(
         &[&[VERIFICATION_VOTE_SEED, action_item_info.key.as_ref(), verifier.as_ref(), &[vote_bump]]],
     )?;
 
-    let clock = Clock::get()?;
     let vote = VerificationVote {
         discriminator: DISCRIMINATOR_VERIFICATION_VOTE,
         is_initialized: true,
@@ -1373,31 +1637,109 @@ fn process_cast_verification_vote(
         verifier,
         approved,
         voted_at_slot: clock.slot,
+        rent_payer: *signer_info.key,
         bump: vote_bump,
     };
 
     vote.serialize(&mut *vote_info.data.borrow_mut())?;
 
-    // Update action item if approved
     if approved {
         action_item.approvals += 1;
+    } else {
+        action_item.rejections += 1;
+    }
+
+    settle_action_item(
+        program_id,
+        &mut action_item,
+        owner_membership_info,
+        owner_reputation_info,
+        &mut board,
+        board_info,
+        clock.slot,
+    )?;
 
-        // Check if threshold is met
-        if action_item.approvals >= action_item.threshold {
-            action_item.status = ActionItemStatus::Completed;
-            action_item.verified_at_slot = Some(clock.slot);
+    action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
 
-            // Increment owner's score
-            let mut owner_membership = BoardMembership::deserialize(&mut &owner_membership_info.data.borrow()[..])?;
-            if owner_membership.discriminator != DISCRIMINATOR_BOARD_MEMBERSHIP {
-                return Err(RetroError::InvalidAccountDiscriminator.into());
-            }
-            owner_membership.total_score += 1;
-            owner_membership.serialize(&mut *owner_membership_info.data.borrow_mut())?;
-        }
+    Ok(())
+}
+
+// Settlement helper: completes or rejects the item once the threshold is met or can no longer be met.
+// Leaves the item Pending otherwise; the caller serializes the action item.
+fn settle_action_item(
+    program_id: &Pubkey,
+    action_item: &mut ActionItem,
+    owner_membership_info: &AccountInfo,
+    owner_reputation_info: &AccountInfo,
+    board: &mut RetroBoard,
+    board_info: &AccountInfo,
+    slot: u64,
+) -> ProgramResult {
+    let completed = action_item.approvals >= action_item.threshold;
+    if !completed && action_item.can_reach_threshold() {
+        return Ok(());
     }
 
-    action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
+    let mut owner_membership = BoardMembership::deserialize(&mut &owner_membership_info.data.borrow()[..])?;
+    if owner_membership.discriminator != DISCRIMINATOR_BOARD_MEMBERSHIP {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+
+    if completed {
+        action_item.status = ActionItemStatus::Completed;
+        owner_membership.total_score += 1;
+    } else {
+        action_item.status = ActionItemStatus::Rejected;
+        owner_membership.rejected_count += 1;
+    }
+    action_item.verified_at_slot = Some(slot);
+    owner_membership.serialize(&mut *owner_membership_info.data.borrow_mut())?;
+
+    record_reputation(program_id, &action_item.owner, owner_reputation_info, action_item.status)?;
+
+    board.pending_action_item_count = board
+        .pending_action_item_count
+        .checked_sub(1)
+        .ok_or(RetroError::PendingActionItemCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+// Reputation helper: records a settled outcome on the owner's Reputation PDA.
+// Owners who have not created one yet are skipped; the PDA is still checked.
+fn record_reputation(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    reputation_info: &AccountInfo,
+    status: ActionItemStatus,
+) -> ProgramResult {
+    let (identity_pda, _) = Pubkey::find_program_address(
+        &[PARTICIPANT_IDENTITY_SEED, owner.as_ref()],
+        program_id,
+    );
+    let (reputation_pda, _) = Pubkey::find_program_address(
+        &[REPUTATION_SEED, identity_pda.as_ref()],
+        program_id,
+    );
+
+    if reputation_pda != *reputation_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if reputation_info.data_is_empty() {
+        return Ok(());
+    }
+    if reputation_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut reputation = Reputation::deserialize(&mut &reputation_info.data.borrow()[..])?;
+    if reputation.discriminator != DISCRIMINATOR_REPUTATION {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+
+    reputation.record(status);
+    reputation.serialize(&mut *reputation_info.data.borrow_mut())?;
 
     Ok(())
 }
@@ -1553,135 +1895,3976 @@ fn process_revoke_session(
     Ok(())
 }
 
-// Username validation helper
-fn validate_username(username: &str) -> ProgramResult {
-    if username.len() < 3 {
-        return Err(RetroError::UsernameTooShort.into());
-    }
-    if username.len() > MAX_USERNAME_CHARS {
-        return Err(RetroError::UsernameTooLong.into());
-    }
-    if !username.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
-        return Err(RetroError::InvalidUsernameCharacters.into());
-    }
-    Ok(())
-}
-
-fn process_create_identity(
+fn process_reveal_note_author(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    username: String,
+    salt: [u8; 32],
 ) -> ProgramResult {
-    msg!("Instruction: CreateIdentity");
+    msg!("Instruction: RevealNoteAuthor");
     let account_info_iter = &mut accounts.iter();
 
-    let identity_info = next_account_info(account_info_iter)?;
-    let authority_info = next_account_info(account_info_iter)?;
-    let system_program_info = next_account_info(account_info_iter)?;
-
-    // Authority must sign
-    if !authority_info.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let board_info = next_account_info(account_info_iter)?;
+    let note_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
 
-    // Validate username
-    validate_username(&username)?;
+    // Determine the author (authority) based on signing method
+    let author = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
 
-    // Derive and verify PDA
-    let (pda, bump) = Pubkey::find_program_address(
-        &[PARTICIPANT_IDENTITY_SEED, authority_info.key.as_ref()],
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &author,
+        session_token_info,
         program_id,
-    );
+        program_id,
+    )?;
 
-    if pda != *identity_info.key {
-        return Err(RetroError::InvalidPDA.into());
+    if note_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
     }
 
-    // Check if identity already exists
-    if !identity_info.data_is_empty() {
-        return Err(RetroError::AccountAlreadyInitialized.into());
+    let mut note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+    if note.discriminator != DISCRIMINATOR_NOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !note.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if note.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
     }
 
-    // Create account
-    let rent = Rent::get()?;
-    let space = ParticipantIdentity::MAX_LEN;
-    let lamports = rent.minimum_balance(space);
-
-    invoke_signed(
-        &system_instruction::create_account(
-            authority_info.key,
-            identity_info.key,
-            lamports,
-            space as u64,
-            program_id,
-        ),
-        &[
-            authority_info.clone(),
-            identity_info.clone(),
-            system_program_info.clone(),
-        ],
-        &[&[PARTICIPANT_IDENTITY_SEED, authority_info.key.as_ref(), &[bump]]],
-    )?;
+    let commitment = note.author_commitment.ok_or(RetroError::NoteAuthorNotHidden)?;
+    if Note::author_commitment(&author, board_info.key, &salt) != commitment {
+        return Err(RetroError::AuthorCommitmentMismatch.into());
+    }
 
-    // Initialize identity
-    let identity = ParticipantIdentity {
-        discriminator: DISCRIMINATOR_PARTICIPANT_IDENTITY,
-        is_initialized: true,
-        authority: *authority_info.key,
-        username,
-        bump,
-    };
+    // The rent held back for whoever opened the commitment now belongs to the revealed author
+    note.author = author;
+    note.rent_payer = author;
+    note.serialize(&mut *note_info.data.borrow_mut())?;
 
-    identity.serialize(&mut *identity_info.data.borrow_mut())?;
+    msg!("Note {} revealed as written by {}", note.note_id, author);
 
-    msg!("Identity created for authority: {}", authority_info.key);
+    Ok(())
+}
 
+// Check that `author` wrote the note, opening the commitment with `salt` on notes that hide their author
+fn check_note_author(note: &Note, author: &Pubkey, salt: Option<[u8; 32]>) -> ProgramResult {
+    if note.is_author_hidden() {
+        let commitment = note.author_commitment.ok_or(RetroError::NoteAuthorNotHidden)?;
+        let salt = salt.ok_or(RetroError::NotNoteAuthor)?;
+        if Note::author_commitment(author, &note.board, &salt) != commitment {
+            return Err(RetroError::AuthorCommitmentMismatch.into());
+        }
+    } else if note.author != *author {
+        return Err(RetroError::NotNoteAuthor.into());
+    }
     Ok(())
 }
 
-fn process_update_identity(
+fn process_update_note(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    username: String,
+    note_id: u64,
+    category_id: u8,
+    content: String,
+    salt: Option<[u8; 32]>,
 ) -> ProgramResult {
-    msg!("Instruction: UpdateIdentity");
+    msg!("Instruction: UpdateNote");
     let account_info_iter = &mut accounts.iter();
 
-    let identity_info = next_account_info(account_info_iter)?;
-    let authority_info = next_account_info(account_info_iter)?;
-
-    // Authority must sign
-    if !authority_info.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    let board_info = next_account_info(account_info_iter)?;
+    let note_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
 
-    // Validate username
-    validate_username(&username)?;
+    // Determine the author (authority) based on signing method
+    let author = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
 
-    // Verify account ownership
-    if identity_info.owner != program_id {
-        return Err(RetroError::InvalidAccountOwner.into());
-    }
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &author,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().notes {
+        return Err(RetroError::InvalidStage.into());
+    }
+
+    if content.len() > MAX_NOTE_CHARS {
+        return Err(RetroError::NoteTooLong.into());
+    }
+    if category_id as usize >= board.categories.len() {
+        return Err(RetroError::InvalidCategoryId.into());
+    }
+
+    // Verify Note PDA
+    let (note_pda, _) = Pubkey::find_program_address(
+        &[NOTE_SEED, board_info.key.as_ref(), &note_id.to_le_bytes()],
+        program_id,
+    );
+
+    if note_pda != *note_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if note_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+    if note.discriminator != DISCRIMINATOR_NOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !note.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if note.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+
+    check_note_author(&note, &author, salt)?;
+
+    note.category_id = category_id;
+    note.content = content;
+    note.serialize(&mut *note_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_delete_note(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    note_id: u64,
+    salt: Option<[u8; 32]>,
+) -> ProgramResult {
+    msg!("Instruction: DeleteNote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let note_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the author (authority) based on signing method
+    let author = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &author,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().notes {
+        return Err(RetroError::InvalidStage.into());
+    }
+
+    // Verify Note PDA
+    let (note_pda, _) = Pubkey::find_program_address(
+        &[NOTE_SEED, board_info.key.as_ref(), &note_id.to_le_bytes()],
+        program_id,
+    );
+
+    if note_pda != *note_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if note_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+    if note.discriminator != DISCRIMINATOR_NOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !note.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if note.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+
+    check_note_author(&note, &author, salt)?;
+
+    // Keep group note counts right; a board reverted to WriteNotes can still have grouped notes
+    if note.group_id.is_some() {
+        return Err(RetroError::NoteAlreadyGrouped.into());
+    }
+
+    // A note that still hides its author refunds the author who opened the commitment
+    let rent_recipient = if note.is_author_hidden() { author } else { note.rent_payer };
+    if *recipient_info.key != rent_recipient {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(note_info, recipient_info)?;
+
+    Ok(())
+}
+
+// Membership validation helper: a participant is on the allowlist iff their BoardMembership exists
+fn load_membership(
+    program_id: &Pubkey,
+    board_key: &Pubkey,
+    participant: &Pubkey,
+    membership_info: &AccountInfo,
+) -> Result<BoardMembership, ProgramError> {
+    let (pda, _) = Pubkey::find_program_address(
+        &[MEMBERSHIP_SEED, board_key.as_ref(), participant.as_ref()],
+        program_id,
+    );
+
+    if pda != *membership_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if membership_info.data_is_empty() || membership_info.lamports() == 0 {
+        return Err(RetroError::NotOnAllowlist.into());
+    }
+    if membership_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let membership = BoardMembership::deserialize(&mut &membership_info.data.borrow()[..])?;
+    if membership.discriminator != DISCRIMINATOR_BOARD_MEMBERSHIP {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !membership.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    Ok(membership)
+}
+
+fn load_group(
+    program_id: &Pubkey,
+    board_key: &Pubkey,
+    group_id: u64,
+    group_info: &AccountInfo,
+) -> Result<Group, ProgramError> {
+    let (pda, _) = Pubkey::find_program_address(
+        &[GROUP_SEED, board_key.as_ref(), &group_id.to_le_bytes()],
+        program_id,
+    );
+
+    if pda != *group_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if group_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let group = Group::deserialize(&mut &group_info.data.borrow()[..])?;
+    if group.discriminator != DISCRIMINATOR_GROUP {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !group.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if group.board != *board_key || group.group_id != group_id {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+
+    Ok(group)
+}
+
+fn load_note(
+    program_id: &Pubkey,
+    board_key: &Pubkey,
+    note_id: u64,
+    note_info: &AccountInfo,
+) -> Result<Note, ProgramError> {
+    let (pda, _) = Pubkey::find_program_address(
+        &[NOTE_SEED, board_key.as_ref(), &note_id.to_le_bytes()],
+        program_id,
+    );
+
+    if pda != *note_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if note_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+    if note.discriminator != DISCRIMINATOR_NOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !note.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if note.board != *board_key || note.note_id != note_id {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+
+    Ok(note)
+}
+
+/// Reassign the listed notes from group `from` to group `to`
+fn move_notes(
+    program_id: &Pubkey,
+    board_key: &Pubkey,
+    note_infos: &[AccountInfo],
+    note_ids: &[u64],
+    from: u64,
+    to: u64,
+) -> ProgramResult {
+    for (note_info, note_id) in note_infos.iter().zip(note_ids) {
+        if note_info.owner != program_id {
+            return Err(RetroError::InvalidAccountOwner.into());
+        }
+        let mut note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+        if note.discriminator != DISCRIMINATOR_NOTE {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        if note.board != *board_key || note.note_id != *note_id {
+            return Err(RetroError::AccountBoardMismatch.into());
+        }
+        // A note passed twice is already in `to` the second time around
+        if note.group_id != Some(from) {
+            return Err(RetroError::NoteNotInGroup.into());
+        }
+
+        note.group_id = Some(to);
+        note.serialize(&mut *note_info.data.borrow_mut())?;
+    }
+
+    Ok(())
+}
+
+// Team helper: loads and validates a Team account
+fn load_team(program_id: &Pubkey, team_info: &AccountInfo) -> Result<Team, ProgramError> {
+    if team_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let team = Team::deserialize(&mut &team_info.data.borrow()[..])?;
+    if team.discriminator != DISCRIMINATOR_TEAM {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !team.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    Ok(team)
+}
+
+// Team boards take their team account after the instruction's fixed accounts; other boards take none
+fn next_team_account<'a, 'b, I>(
+    board: &RetroBoard,
+    account_info_iter: &mut I,
+) -> Result<Option<&'a AccountInfo<'b>>, ProgramError>
+where
+    I: Iterator<Item = &'a AccountInfo<'b>>,
+{
+    if board.team.is_some() {
+        Ok(Some(next_account_info(account_info_iter)?))
+    } else {
+        Ok(None)
+    }
+}
+
+// Facilitator check: the board's facilitator, or any facilitator of the owning team
+fn authorize_facilitator(
+    program_id: &Pubkey,
+    board: &RetroBoard,
+    team_info: Option<&AccountInfo>,
+    authority: &Pubkey,
+) -> ProgramResult {
+    let Some(team_key) = board.team else {
+        if board.facilitator != *authority {
+            return Err(RetroError::UnauthorizedFacilitator.into());
+        }
+        return Ok(());
+    };
+
+    let team_info = team_info.ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if *team_info.key != team_key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+
+    let team = load_team(program_id, team_info)?;
+    if !team.is_facilitator(authority) {
+        return Err(RetroError::UnauthorizedFacilitator.into());
+    }
+
+    Ok(())
+}
+
+// A ranked board keeps its Borda results on the groups, so it can't leave Vote half tallied
+fn check_ballots_tallied(board: &RetroBoard) -> ProgramResult {
+    if board.stage == BoardStage::Vote
+        && board.voting_mode == VotingMode::Ranked
+        && !board.ballots_tallied()
+    {
+        return Err(RetroError::BallotsNotTallied.into());
+    }
+    Ok(())
+}
+
+// Groups don't count legacy notes until those migrate, so a group that looks empty may not be
+fn check_notes_migrated(board: &RetroBoard) -> ProgramResult {
+    if board.legacy_note_count > 0 {
+        return Err(RetroError::NotesNotMigrated.into());
+    }
+    Ok(())
+}
+
+// Username validation helper
+fn validate_username(username: &str) -> ProgramResult {
+    if username.len() < 3 {
+        return Err(RetroError::UsernameTooShort.into());
+    }
+    if username.len() > MAX_USERNAME_CHARS {
+        return Err(RetroError::UsernameTooLong.into());
+    }
+    if !username.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(RetroError::InvalidUsernameCharacters.into());
+    }
+    Ok(())
+}
+
+fn process_create_identity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    username: String,
+) -> ProgramResult {
+    msg!("Instruction: CreateIdentity");
+    let account_info_iter = &mut accounts.iter();
+
+    let identity_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Authority must sign
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate username
+    validate_username(&username)?;
+
+    // Derive and verify PDA
+    let (pda, bump) = Pubkey::find_program_address(
+        &[PARTICIPANT_IDENTITY_SEED, authority_info.key.as_ref()],
+        program_id,
+    );
+
+    if pda != *identity_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    // Check if identity already exists
+    if !identity_info.data_is_empty() {
+        return Err(RetroError::AccountAlreadyInitialized.into());
+    }
+
+    // Create account
+    let rent = Rent::get()?;
+    let space = ParticipantIdentity::MAX_LEN;
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority_info.key,
+            identity_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[
+            authority_info.clone(),
+            identity_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[PARTICIPANT_IDENTITY_SEED, authority_info.key.as_ref(), &[bump]]],
+    )?;
+
+    // Initialize identity
+    let identity = ParticipantIdentity {
+        discriminator: DISCRIMINATOR_PARTICIPANT_IDENTITY,
+        is_initialized: true,
+        authority: *authority_info.key,
+        username,
+        bump,
+    };
+
+    identity.serialize(&mut *identity_info.data.borrow_mut())?;
+
+    msg!("Identity created for authority: {}", authority_info.key);
+
+    Ok(())
+}
+
+fn process_update_identity(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    username: String,
+) -> ProgramResult {
+    msg!("Instruction: UpdateIdentity");
+    let account_info_iter = &mut accounts.iter();
+
+    let identity_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+
+    // Authority must sign
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Validate username
+    validate_username(&username)?;
+
+    // Verify account ownership
+    if identity_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    // Deserialize and validate
+    let mut identity = ParticipantIdentity::deserialize(&mut &identity_info.data.borrow()[..])?;
+    if identity.discriminator != DISCRIMINATOR_PARTICIPANT_IDENTITY {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !identity.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Verify authority matches
+    if identity.authority != *authority_info.key {
+        return Err(RetroError::UnauthorizedIdentityUpdate.into());
+    }
+
+    // Update username
+    identity.username = username;
+    identity.serialize(&mut *identity_info.data.borrow_mut())?;
+
+    msg!("Identity updated for authority: {}", authority_info.key);
+
+    Ok(())
+}
+
+/// Move all lamports from `account_info` to `destination_info` and zero its data.
+fn close_account(account_info: &AccountInfo, destination_info: &AccountInfo) -> ProgramResult {
+    let dest_lamports = destination_info.lamports();
+    **destination_info.lamports.borrow_mut() = dest_lamports
+        .checked_add(account_info.lamports())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account_info.lamports.borrow_mut() = 0;
+
+    account_info.data.borrow_mut().fill(0);
+
+    Ok(())
+}
+
+/// Whether an account the program created has since been closed. Anyone can send lamports
+/// to a closed address, so the runtime handing it back to the system program counts too.
+fn is_reclaimed(program_id: &Pubkey, account_info: &AccountInfo) -> bool {
+    account_info.lamports() == 0
+        || account_info.owner != program_id
+        || account_info.data_is_empty()
+}
+
+/// Load the board a reclaimable account belongs to and check that it is closed
+/// with every action item settled. Returns `None` if the board was already reclaimed,
+/// in which case only the rent payer can close what is left behind.
+fn load_settled_board(
+    program_id: &Pubkey,
+    board_info: &AccountInfo,
+    expected_board: &Pubkey,
+) -> Result<Option<RetroBoard>, ProgramError> {
+    if board_info.key != expected_board {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+    if is_reclaimed(program_id, board_info) {
+        return Ok(None);
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if !board.closed {
+        return Err(RetroError::BoardNotClosed.into());
+    }
+    if board.pending_action_item_count > 0 {
+        return Err(RetroError::ActionItemsPending.into());
+    }
+
+    Ok(Some(board))
+}
+
+// Reclaim is allowed for whoever paid the rent, or a facilitator while the board exists
+fn authorize_reclaim(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    rent_payer: &Pubkey,
+    board: Option<&RetroBoard>,
+    team_info: Option<&AccountInfo>,
+) -> ProgramResult {
+    if *authority == *rent_payer {
+        return Ok(());
+    }
+    let board = board.ok_or(RetroError::UnauthorizedReclaim)?;
+    authorize_facilitator(program_id, board, team_info, authority)
+        .map_err(|_| RetroError::UnauthorizedReclaim.into())
+}
+
+fn process_reclaim_board(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimBoard");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    let board = load_settled_board(program_id, board_info, board_info.key)?
+        .ok_or(RetroError::AccountNotInitialized)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &board.rent_payer, Some(&board), team_info)?;
+
+    // Memberships and action items update the board's counts when reclaimed, so they go first
+    if board.membership_count > 0 || board.live_action_item_count > 0 {
+        return Err(RetroError::BoardHasOpenAccounts.into());
+    }
+
+    if *recipient_info.key != board.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(board_info, recipient_info)?;
+
+    msg!("Board reclaimed: {}", board_info.key);
+
+    Ok(())
+}
+
+fn process_reclaim_membership(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimMembership");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if membership_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let membership = BoardMembership::deserialize(&mut &membership_info.data.borrow()[..])?;
+    if membership.discriminator != DISCRIMINATOR_BOARD_MEMBERSHIP {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !membership.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let mut board = load_settled_board(program_id, board_info, &membership.board)?
+        .ok_or(RetroError::AccountNotInitialized)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &membership.rent_payer, Some(&board), team_info)?;
+
+    if *recipient_info.key != membership.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(membership_info, recipient_info)?;
+
+    board.membership_count = board
+        .membership_count
+        .checked_sub(1)
+        .ok_or(RetroError::MembershipCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reclaim_note(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    salt: Option<[u8; 32]>,
+) -> ProgramResult {
+    msg!("Instruction: ReclaimNote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let note_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if note_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let note = Note::deserialize(&mut &note_info.data.borrow()[..])?;
+    if note.discriminator != DISCRIMINATOR_NOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !note.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let board = load_settled_board(program_id, board_info, &note.board)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    // A note that still hides its author has no recorded payer; only its author can reclaim it
+    let rent_recipient = if note.is_author_hidden() {
+        check_note_author(&note, &authority, salt)?;
+        authority
+    } else {
+        authorize_reclaim(program_id, &authority, &note.rent_payer, board.as_ref(), team_info)?;
+        note.rent_payer
+    };
+
+    if *recipient_info.key != rent_recipient {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(note_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_reclaim_group(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimGroup");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let group_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if group_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let group = Group::deserialize(&mut &group_info.data.borrow()[..])?;
+    if group.discriminator != DISCRIMINATOR_GROUP {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !group.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let board = load_settled_board(program_id, board_info, &group.board)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &group.rent_payer, board.as_ref(), team_info)?;
+
+    if *recipient_info.key != group.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(group_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_reclaim_vote_record(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimVoteRecord");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let vote_record_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if vote_record_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let vote_record = VoteRecord::deserialize(&mut &vote_record_info.data.borrow()[..])?;
+    if vote_record.discriminator != DISCRIMINATOR_VOTE_RECORD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !vote_record.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let board = load_settled_board(program_id, board_info, &vote_record.board)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &vote_record.rent_payer, board.as_ref(), team_info)?;
+
+    if *recipient_info.key != vote_record.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(vote_record_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_reclaim_action_item(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimActionItem");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let action_item_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if action_item_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let action_item = ActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+    if action_item.discriminator != DISCRIMINATOR_ACTION_ITEM {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !action_item.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let mut board = load_settled_board(program_id, board_info, &action_item.board)?
+        .ok_or(RetroError::AccountNotInitialized)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &action_item.rent_payer, Some(&board), team_info)?;
+
+    if *recipient_info.key != action_item.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(action_item_info, recipient_info)?;
+
+    board.live_action_item_count = board
+        .live_action_item_count
+        .checked_sub(1)
+        .ok_or(RetroError::LiveActionItemCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reclaim_verification_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimVerificationVote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let action_item_info = next_account_info(account_info_iter)?;
+    let vote_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if vote_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let vote = VerificationVote::deserialize(&mut &vote_info.data.borrow()[..])?;
+    if vote.discriminator != DISCRIMINATOR_VERIFICATION_VOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !vote.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if vote.action_item != *action_item_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    // An action item can only be reclaimed once settled, so a missing one needs no further checks
+    let board = if is_reclaimed(program_id, action_item_info) {
+        None
+    } else {
+        let action_item = ActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+        if action_item.discriminator != DISCRIMINATOR_ACTION_ITEM {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        if action_item.status == ActionItemStatus::Pending {
+            return Err(RetroError::ActionItemsPending.into());
+        }
+
+        load_settled_board(program_id, board_info, &action_item.board)?
+    };
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &vote.rent_payer, board.as_ref(), team_info)?;
+
+    if *recipient_info.key != vote.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(vote_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_add_participant(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    participant: Pubkey,
+) -> ProgramResult {
+    msg!("Instruction: AddParticipant");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+
+    if board.membership_count as usize >= MAX_PARTICIPANTS {
+        return Err(RetroError::MaxParticipantsReached.into());
+    }
+
+    let (pda, membership_bump) = Pubkey::find_program_address(
+        &[MEMBERSHIP_SEED, board_info.key.as_ref(), participant.as_ref()],
+        program_id,
+    );
+
+    if pda != *membership_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    // An existing membership is what puts a participant on the allowlist
+    if !membership_info.data_is_empty() {
+        return Err(RetroError::AlreadyOnAllowlist.into());
+    }
+
+    let rent = Rent::get()?;
+    let space = BoardMembership::LEN;
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            signer_info.key,
+            membership_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[
+            signer_info.clone(),
+            membership_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[MEMBERSHIP_SEED, board_info.key.as_ref(), participant.as_ref(), &[membership_bump]]],
+    )?;
+
+    let membership = BoardMembership {
+        discriminator: DISCRIMINATOR_BOARD_MEMBERSHIP,
+        is_initialized: true,
+        board: *board_info.key,
+        participant,
+        voting_credits: board.voting_credits_per_participant,
+        credits_spent: 0,
+        total_score: 0,
+        rejected_count: 0,
+        vote_round: board.vote_round,
+        rent_payer: *signer_info.key,
+        bump: membership_bump,
+    };
+    membership.serialize(&mut *membership_info.data.borrow_mut())?;
+
+    board.membership_count += 1;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    msg!("Participant added: {}", participant);
+
+    Ok(())
+}
+
+fn process_remove_participant(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    participant: Pubkey,
+) -> ProgramResult {
+    msg!("Instruction: RemoveParticipant");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    // Once voting starts a membership carries spent credits, and a re-added one would start fresh.
+    // Action items only exist from Discuss on, so none can name the participant before then.
+    if !board.stage.capabilities().manage_members {
+        return Err(RetroError::InvalidStage.into());
+    }
+
+    let membership = load_membership(program_id, board_info.key, &participant, membership_info)?;
+
+    if *recipient_info.key != membership.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(membership_info, recipient_info)?;
+    board.membership_count = board
+        .membership_count
+        .checked_sub(1)
+        .ok_or(RetroError::MembershipCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    msg!("Participant removed: {}", participant);
+
+    Ok(())
+}
+
+fn process_review_action_item(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    action_item_id: u64,
+    approved: bool,
+) -> ProgramResult {
+    msg!("Instruction: ReviewActionItem");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let predecessor_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().review {
+        return Err(RetroError::InvalidStage.into());
+    }
+    if board.predecessor != Some(*predecessor_info.key) {
+        return Err(RetroError::PredecessorMismatch.into());
+    }
+
+    // The vote lands on the predecessor board, exactly as CastVerificationVote would
+    record_verification_vote(
+        program_id,
+        predecessor_info,
+        account_info_iter,
+        action_item_id,
+        approved,
+    )
+}
+
+fn process_expire_action_item(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ExpireActionItem");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let action_item_info = next_account_info(account_info_iter)?;
+    let owner_reputation_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id || action_item_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let mut action_item = ActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+    if action_item.discriminator != DISCRIMINATOR_ACTION_ITEM {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !action_item.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if action_item.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+    if action_item.status != ActionItemStatus::Pending {
+        return Err(RetroError::ActionItemNotPending.into());
+    }
+
+    // No signer needed: once the due date has passed the outcome is fixed
+    let clock = Clock::get()?;
+    if !action_item.is_past_due(clock.unix_timestamp) {
+        return Err(RetroError::ActionItemNotDue.into());
+    }
+
+    action_item.status = ActionItemStatus::Expired;
+    action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
+
+    record_reputation(program_id, &action_item.owner, owner_reputation_info, action_item.status)?;
+
+    board.pending_action_item_count = board
+        .pending_action_item_count
+        .checked_sub(1)
+        .ok_or(RetroError::PendingActionItemCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    msg!("Action item {} expired", action_item.action_item_id);
+
+    Ok(())
+}
+
+fn process_update_verification_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    approved: bool,
+) -> ProgramResult {
+    msg!("Instruction: UpdateVerificationVote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let action_item_info = next_account_info(account_info_iter)?;
+    let vote_info = next_account_info(account_info_iter)?;
+    let owner_membership_info = next_account_info(account_info_iter)?;
+    let owner_reputation_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the verifier (authority) based on signing method
+    let verifier = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &verifier,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id
+        || action_item_info.owner != program_id
+        || vote_info.owner != program_id
+    {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let mut action_item = ActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+    if action_item.discriminator != DISCRIMINATOR_ACTION_ITEM {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !action_item.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if action_item.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+    // Votes are final once the item is settled
+    if action_item.status != ActionItemStatus::Pending {
+        return Err(RetroError::ActionItemNotPending.into());
+    }
+
+    let clock = Clock::get()?;
+    if action_item.is_past_due(clock.unix_timestamp) {
+        return Err(RetroError::ActionItemPastDue.into());
+    }
+
+    // Verify vote PDA (uses verifier/authority, not session signer)
+    let (vote_pda, _) = Pubkey::find_program_address(
+        &[VERIFICATION_VOTE_SEED, action_item_info.key.as_ref(), verifier.as_ref()],
+        program_id,
+    );
+
+    if vote_pda != *vote_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    let mut vote = VerificationVote::deserialize(&mut &vote_info.data.borrow()[..])?;
+    if vote.discriminator != DISCRIMINATOR_VERIFICATION_VOTE {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !vote.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if vote.approved == approved {
+        return Err(RetroError::VerificationVoteUnchanged.into());
+    }
+
+    // Verify owner membership PDA
+    let (owner_membership_pda, _) = Pubkey::find_program_address(
+        &[MEMBERSHIP_SEED, board_info.key.as_ref(), action_item.owner.as_ref()],
+        program_id,
+    );
+
+    if owner_membership_pda != *owner_membership_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    // Move the vote from one tally to the other
+    if approved {
+        action_item.rejections -= 1;
+        action_item.approvals += 1;
+    } else {
+        action_item.approvals -= 1;
+        action_item.rejections += 1;
+    }
+
+    vote.approved = approved;
+    vote.voted_at_slot = clock.slot;
+    vote.serialize(&mut *vote_info.data.borrow_mut())?;
+
+    settle_action_item(
+        program_id,
+        &mut action_item,
+        owner_membership_info,
+        owner_reputation_info,
+        &mut board,
+        board_info,
+        clock.slot,
+    )?;
+
+    action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reassign_action_item(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    owner: Pubkey,
+    verifiers: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    msg!("Instruction: ReassignActionItem");
+
+    let board_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let action_item_info = accounts.get(1).ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if action_item_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut action_item = ActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+    if action_item.discriminator != DISCRIMINATOR_ACTION_ITEM {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !action_item.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Verifiers dropped by the reassignment lose their votes
+    let removed_verifiers: Vec<Pubkey> = action_item
+        .verifiers
+        .iter()
+        .filter(|v| !verifiers.contains(v))
+        .copied()
+        .collect();
+
+    // Determine if session token is present based on account count
+    // Without session: board, action_item, signer, [team], owner membership, owner reputation,
+    // verifier memberships..., (vote, recipient) pairs... (3 + team + 2 + verifiers.len() + 2 * removed);
+    // the session token adds one after the team account
+    let num_base_accounts = 3 + usize::from(board.team.is_some());
+    let expected_without_session =
+        num_base_accounts + 2 + verifiers.len() + 2 * removed_verifiers.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter().skip(2);
+
+    let signer_info = next_account_info(account_info_iter)?;
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if action_item.board != *board_info.key {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+    if action_item.status != ActionItemStatus::Pending {
+        return Err(RetroError::ActionItemNotPending.into());
+    }
+
+    let clock = Clock::get()?;
+    if action_item.is_past_due(clock.unix_timestamp) {
+        return Err(RetroError::ActionItemPastDue.into());
+    }
+
+    // Same invariants as CreateActionItem
+    if verifiers.len() > MAX_VERIFIERS {
+        return Err(RetroError::TooManyVerifiers.into());
+    }
+    if threshold == 0 {
+        return Err(RetroError::ThresholdTooLow.into());
+    }
+    if threshold as usize > verifiers.len() {
+        return Err(RetroError::ThresholdTooHigh.into());
+    }
+    if has_duplicate_verifiers(&verifiers) {
+        return Err(RetroError::DuplicateVerifier.into());
+    }
+
+    let owner_membership_info = next_account_info(account_info_iter)?;
+    load_membership(program_id, board_info.key, &owner, owner_membership_info)?;
+    let owner_reputation_info = next_account_info(account_info_iter)?;
+
+    for verifier in &verifiers {
+        let verifier_membership_info = next_account_info(account_info_iter)?;
+        load_membership(program_id, board_info.key, verifier, verifier_membership_info)?;
+        if *verifier == owner {
+            return Err(RetroError::OwnerCannotVerify.into());
+        }
+    }
+
+    // Close the removed verifiers' votes, refunding their rent payers, and take the votes out of the tallies
+    for verifier in &removed_verifiers {
+        let vote_info = next_account_info(account_info_iter)?;
+        let recipient_info = next_account_info(account_info_iter)?;
+
+        let (vote_pda, _) = Pubkey::find_program_address(
+            &[VERIFICATION_VOTE_SEED, action_item_info.key.as_ref(), verifier.as_ref()],
+            program_id,
+        );
+
+        if vote_pda != *vote_info.key {
+            return Err(RetroError::InvalidPDA.into());
+        }
+        if vote_info.data_is_empty() {
+            continue;
+        }
+        if vote_info.owner != program_id {
+            return Err(RetroError::InvalidAccountOwner.into());
+        }
+
+        let vote = VerificationVote::deserialize(&mut &vote_info.data.borrow()[..])?;
+        if vote.discriminator != DISCRIMINATOR_VERIFICATION_VOTE {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        if *recipient_info.key != vote.rent_payer {
+            return Err(RetroError::InvalidRentRecipient.into());
+        }
+        if vote.approved {
+            action_item.approvals -= 1;
+        } else {
+            action_item.rejections -= 1;
+        }
+
+        close_account(vote_info, recipient_info)?;
+    }
+
+    action_item.owner = owner;
+    action_item.verifiers = verifiers;
+    action_item.threshold = threshold;
+
+    // Remaining votes may already meet the new threshold, or be unable to
+    settle_action_item(
+        program_id,
+        &mut action_item,
+        owner_membership_info,
+        owner_reputation_info,
+        &mut board,
+        board_info,
+        clock.slot,
+    )?;
+
+    action_item.serialize(&mut *action_item_info.data.borrow_mut())?;
+
+    msg!("Action item {} reassigned", action_item.action_item_id);
+
+    Ok(())
+}
+
+fn process_init_reputation(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: InitReputation");
+    let account_info_iter = &mut accounts.iter();
+
+    let reputation_info = next_account_info(account_info_iter)?;
+    let identity_info = next_account_info(account_info_iter)?;
+    let authority_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Authority must sign
+    if !authority_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Reputation hangs off an existing identity
+    let (identity_pda, _) = Pubkey::find_program_address(
+        &[PARTICIPANT_IDENTITY_SEED, authority_info.key.as_ref()],
+        program_id,
+    );
+
+    if identity_pda != *identity_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if identity_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let identity = ParticipantIdentity::deserialize(&mut &identity_info.data.borrow()[..])?;
+    if identity.discriminator != DISCRIMINATOR_PARTICIPANT_IDENTITY {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !identity.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Derive and verify PDA
+    let (pda, bump) = Pubkey::find_program_address(
+        &[REPUTATION_SEED, identity_info.key.as_ref()],
+        program_id,
+    );
+
+    if pda != *reputation_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if !reputation_info.data_is_empty() {
+        return Err(RetroError::AccountAlreadyInitialized.into());
+    }
+
+    let rent = Rent::get()?;
+    let space = Reputation::LEN;
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            authority_info.key,
+            reputation_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[
+            authority_info.clone(),
+            reputation_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[REPUTATION_SEED, identity_info.key.as_ref(), &[bump]]],
+    )?;
+
+    let reputation = Reputation {
+        discriminator: DISCRIMINATOR_REPUTATION,
+        is_initialized: true,
+        authority: *authority_info.key,
+        identity: *identity_info.key,
+        completed: 0,
+        rejected: 0,
+        expired: 0,
+        current_streak: 0,
+        best_streak: 0,
+        bump,
+    };
+
+    reputation.serialize(&mut *reputation_info.data.borrow_mut())?;
+
+    msg!("Reputation created for authority: {}", authority_info.key);
+
+    Ok(())
+}
+
+fn process_create_team(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    name: String,
+) -> ProgramResult {
+    msg!("Instruction: CreateTeam");
+    let account_info_iter = &mut accounts.iter();
+
+    let team_info = next_account_info(account_info_iter)?;
+    let creator_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !creator_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if name.is_empty() || name.len() > MAX_TEAM_NAME_LEN {
+        return Err(RetroError::InvalidTeamName.into());
+    }
+
+    let (pda, bump) = Pubkey::find_program_address(
+        &[TEAM_SEED, creator_info.key.as_ref(), name.as_bytes()],
+        program_id,
+    );
+
+    if pda != *team_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if !team_info.data_is_empty() {
+        return Err(RetroError::AccountAlreadyInitialized.into());
+    }
+
+    let rent = Rent::get()?;
+    let space = Team::MAX_LEN;
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            creator_info.key,
+            team_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[
+            creator_info.clone(),
+            team_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[TEAM_SEED, creator_info.key.as_ref(), name.as_bytes(), &[bump]]],
+    )?;
+
+    // The creator is the team's first facilitator
+    let team = Team {
+        discriminator: DISCRIMINATOR_TEAM,
+        is_initialized: true,
+        creator: *creator_info.key,
+        name,
+        members: vec![TeamMember {
+            member: *creator_info.key,
+            role: TeamRole::Facilitator,
+        }],
+        board_count: 0,
+        bump,
+    };
+
+    team.serialize(&mut *team_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_set_team_member(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    member: Pubkey,
+    role: TeamRole,
+) -> ProgramResult {
+    msg!("Instruction: SetTeamMember");
+    let account_info_iter = &mut accounts.iter();
+
+    let team_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    let mut team = load_team(program_id, team_info)?;
+    if !team.is_facilitator(&facilitator) {
+        return Err(RetroError::UnauthorizedFacilitator.into());
+    }
+
+    if let Some(entry) = team.members.iter_mut().find(|m| m.member == member) {
+        entry.role = role;
+    } else {
+        if team.members.len() >= MAX_TEAM_MEMBERS {
+            return Err(RetroError::TeamFull.into());
+        }
+        team.members.push(TeamMember { member, role });
+    }
+
+    if team.facilitator_count() == 0 {
+        return Err(RetroError::LastTeamFacilitator.into());
+    }
+
+    team.serialize(&mut *team_info.data.borrow_mut())?;
+
+    msg!("Team member set: {}", member);
+
+    Ok(())
+}
+
+fn process_remove_team_member(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    member: Pubkey,
+) -> ProgramResult {
+    msg!("Instruction: RemoveTeamMember");
+    let account_info_iter = &mut accounts.iter();
+
+    let team_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    let mut team = load_team(program_id, team_info)?;
+    if !team.is_facilitator(&facilitator) {
+        return Err(RetroError::UnauthorizedFacilitator.into());
+    }
+
+    let position = team
+        .members
+        .iter()
+        .position(|m| m.member == member)
+        .ok_or(RetroError::NotTeamMember)?;
+    team.members.remove(position);
+
+    if team.facilitator_count() == 0 {
+        return Err(RetroError::LastTeamFacilitator.into());
+    }
+
+    team.serialize(&mut *team_info.data.borrow_mut())?;
+
+    msg!("Team member removed: {}", member);
+
+    Ok(())
+}
+
+fn process_propose_facilitator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_facilitator: Pubkey,
+) -> ProgramResult {
+    msg!("Instruction: ProposeFacilitator");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the facilitator (authority) - either from session token or direct signer
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    // Team boards are run by the team's facilitators; the role moves through team membership
+    if board.team.is_some() {
+        return Err(RetroError::TeamBoardFacilitator.into());
+    }
+
+    // The new facilitator must be on the allowlist
+    load_membership(program_id, board_info.key, &new_facilitator, membership_info)?;
+
+    // Proposing again replaces any earlier proposal
+    board.pending_facilitator = Some(new_facilitator);
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    msg!("Facilitator proposed: {}", new_facilitator);
+
+    Ok(())
+}
+
+fn process_accept_facilitator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: AcceptFacilitator");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the new facilitator (authority) - either from session token or direct signer
+    let new_facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signer or session
+    validate_signer_or_session(
+        signer_info,
+        &new_facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if board.team.is_some() {
+        return Err(RetroError::TeamBoardFacilitator.into());
+    }
+    if board.pending_facilitator != Some(new_facilitator) {
+        return Err(RetroError::NotProposedFacilitator.into());
+    }
+
+    // They may have been removed from the allowlist since the proposal
+    load_membership(program_id, board_info.key, &new_facilitator, membership_info)?;
+
+    board.facilitator = new_facilitator;
+    board.pending_facilitator = None;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    msg!("Facilitator accepted: {}", new_facilitator);
+
+    Ok(())
+}
+
+fn process_crank_advance_stage(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: CrankAdvanceStage");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+
+    let next_stage = board.next_stage().ok_or(RetroError::InvalidStageTransition)?;
+
+    // No signer needed: the facilitator already agreed to the timebox at creation
+    let clock = Clock::get()?;
+    match board.stage_deadline_slot() {
+        Some(deadline) if clock.slot >= deadline => {}
+        _ => return Err(RetroError::StageTimeboxNotElapsed.into()),
+    }
+
+    check_ballots_tallied(&board)?;
+
+    board.stage = next_stage;
+    board.stage_changed_at_slot = clock.slot;
+
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_revert_stage(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    target_stage: BoardStage,
+) -> ProgramResult {
+    msg!("Instruction: RevertStage");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+
+    // Target must be an earlier stage of this board's pipeline
+    if !board.stages.contains(&target_stage) || target_stage.rank() >= board.stage.rank() {
+        return Err(RetroError::InvalidStageTransition.into());
+    }
+
+    // Action items are created from the vote results, so those must stand once items exist
+    if board.stage.capabilities().action_items && board.action_item_count > 0 {
+        return Err(RetroError::ActionItemsExist.into());
+    }
+
+    // Going back before voting discards the round; memberships, groups and
+    // vote records reset themselves the next time they are touched.
+    // Sealed votes are public once revealed and ranked results once tallied,
+    // so going back to Vote discards them too.
+    let first_kept_rank = if matches!(board.voting_mode, VotingMode::Sealed | VotingMode::Ranked) {
+        BoardStage::RevealVotes.rank()
+    } else {
+        BoardStage::Vote.rank()
+    };
+    if board.stage.rank() >= BoardStage::Vote.rank() && target_stage.rank() < first_kept_rank {
+        board.vote_round += 1;
+        board.ballot_count = 0;
+        board.tallied_group_count = 0;
+        board.candidate_count = 0;
+        board.tallied_ballot_count = 0;
+        board.last_tallied_participant = None;
+    }
+
+    let clock = Clock::get()?;
+    board.stage = target_stage;
+    board.stage_changed_at_slot = clock.slot;
+
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_retract_vote(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    group_id: u64,
+    votes: u8,
+) -> ProgramResult {
+    msg!("Instruction: RetractVote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let group_info = next_account_info(account_info_iter)?;
+    let vote_record_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the voter (authority) based on signing method
+    let voter = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &voter,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().voting {
+        return Err(RetroError::InvalidStage.into());
+    }
+    if !board.voting_mode.has_live_tally() {
+        return Err(RetroError::InvalidVotingMode.into());
+    }
+
+    let mut membership = load_membership(program_id, board_info.key, &voter, membership_info)?;
+    membership.sync_vote_round(board.vote_round);
+
+    let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+    group.sync_vote_round(board.vote_round);
+
+    // Verify VoteRecord PDA (uses voter/authority, not session signer)
+    let (vote_pda, _) = Pubkey::find_program_address(
+        &[VOTE_SEED, board_info.key.as_ref(), voter.as_ref(), &group_id.to_le_bytes()],
+        program_id,
+    );
+    if vote_pda != *vote_record_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if vote_record_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut vote_record = VoteRecord::deserialize(&mut &vote_record_info.data.borrow()[..])?;
+    if vote_record.discriminator != DISCRIMINATOR_VOTE_RECORD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    vote_record.sync_vote_round(board.vote_round);
+
+    if votes == 0 || votes > vote_record.votes {
+        return Err(RetroError::InvalidRetraction.into());
+    }
+
+    // Refund the difference between what the group's votes cost before and after
+    vote_record.votes -= votes;
+    let record_credits_after = board.voting_mode.cost(vote_record.votes) as u8;
+    let refund = vote_record
+        .credits_spent
+        .checked_sub(record_credits_after)
+        .ok_or(RetroError::VoteRecordMismatch)?;
+    vote_record.credits_spent = record_credits_after;
+    membership.credits_spent = membership
+        .credits_spent
+        .checked_sub(refund)
+        .ok_or(RetroError::InvalidRetraction)?;
+    group.vote_tally = group
+        .vote_tally
+        .checked_sub(votes as u64)
+        .ok_or(RetroError::InvalidRetraction)?;
+
+    membership.serialize(&mut *membership_info.data.borrow_mut())?;
+    group.serialize(&mut *group_info.data.borrow_mut())?;
+
+    // No votes left on the group: return the vote record's rent to whoever paid it
+    if vote_record.votes == 0 {
+        if *recipient_info.key != vote_record.rent_payer {
+            return Err(RetroError::InvalidRentRecipient.into());
+        }
+        close_account(vote_record_info, recipient_info)?;
+    } else {
+        vote_record.serialize(&mut *vote_record_info.data.borrow_mut())?;
+    }
+
+    Ok(())
+}
+
+fn process_submit_ballot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    ranking: Vec<u64>,
+) -> ProgramResult {
+    msg!("Instruction: SubmitBallot");
+
+    // Determine if session token is present based on account count
+    // Without session: board, membership, ballot, signer, system, groups... (5 + ranking.len())
+    // With session: board, membership, ballot, signer, system, session_token, groups... (6 + ranking.len())
+    let num_base_accounts = 5;
+    let expected_without_session = num_base_accounts + ranking.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let ballot_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the voter (authority) based on signing method
+    let voter = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &voter,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().voting {
+        return Err(RetroError::InvalidStage.into());
+    }
+    check_notes_migrated(&board)?;
+    if board.voting_mode != VotingMode::Ranked {
+        return Err(RetroError::InvalidVotingMode.into());
+    }
+    if board.is_tallying() {
+        return Err(RetroError::TallyInProgress.into());
+    }
+
+    // Check allowlist (uses voter/authority, not session signer)
+    load_membership(program_id, board_info.key, &voter, membership_info)?;
+
+    if ranking.is_empty()
+        || ranking.len() > MAX_BALLOT_RANKS
+        || ranking.iter().any(|id| *id >= board.group_count)
+        || ranking.iter().enumerate().any(|(i, id)| ranking[..i].contains(id))
+    {
+        return Err(RetroError::InvalidBallot.into());
+    }
+
+    // Merged and deleted groups are gone, and empty ones can't be voted on
+    for group_id in &ranking {
+        let group_info = next_account_info(account_info_iter)?;
+        if group_info.data_is_empty() {
+            return Err(RetroError::InvalidBallot.into());
+        }
+        let group = load_group(program_id, board_info.key, *group_id, group_info)?;
+        if group.note_count == 0 {
+            return Err(RetroError::EmptyGroup.into());
+        }
+    }
+
+    let (ballot_pda, ballot_bump) = Pubkey::find_program_address(
+        &[BALLOT_SEED, board_info.key.as_ref(), voter.as_ref()],
+        program_id,
+    );
+    if ballot_pda != *ballot_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    let mut ballot = if ballot_info.data_is_empty() {
+        let rent = Rent::get()?;
+        let space = Ballot::MAX_LEN;
+        let lamports = rent.minimum_balance(space);
+
+        invoke_signed(
+            &system_instruction::create_account(
+                signer_info.key,
+                ballot_info.key,
+                lamports,
+                space as u64,
+                program_id,
+            ),
+            &[
+                signer_info.clone(),
+                ballot_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[BALLOT_SEED, board_info.key.as_ref(), voter.as_ref(), &[ballot_bump]]],
+        )?;
+
+        board.ballot_count += 1;
+
+        Ballot {
+            discriminator: DISCRIMINATOR_BALLOT,
+            is_initialized: true,
+            board: *board_info.key,
+            participant: voter,
+            ranking: Vec::new(),
+            vote_round: board.vote_round,
+            rent_payer: *signer_info.key,
+            bump: ballot_bump,
+        }
+    } else {
+        let b = Ballot::deserialize(&mut &ballot_info.data.borrow()[..])?;
+        if b.discriminator != DISCRIMINATOR_BALLOT {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        // A ballot from a discarded round counts again once resubmitted
+        if b.vote_round != board.vote_round {
+            board.ballot_count += 1;
+        }
+        b
+    };
+
+    ballot.ranking = ranking;
+    ballot.vote_round = board.vote_round;
+    ballot.serialize(&mut *ballot_info.data.borrow_mut())?;
+
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_tally_ballots(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: TallyBallots");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if !signer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().voting {
+        return Err(RetroError::InvalidStage.into());
+    }
+    if board.voting_mode != VotingMode::Ranked {
+        return Err(RetroError::InvalidVotingMode.into());
+    }
+
+    // The first batch closes the round to ballots, which is the facilitator's call
+    // until the Vote timebox runs out
+    let clock = Clock::get()?;
+    let timed_out = board
+        .stage_deadline_slot()
+        .is_some_and(|deadline| clock.slot >= deadline);
+    if !board.is_tallying() && !timed_out {
+        authorize_facilitator(program_id, &board, team_info, signer_info.key)?;
+    }
+
+    if account_info_iter.as_slice().is_empty() {
+        return Err(RetroError::InvalidTallyAccounts.into());
+    }
+
+    // Scan groups in id order first: only groups a ballot could rank are candidates,
+    // so merged, deleted and empty ones don't raise the points
+    while board.tallied_group_count < board.group_count {
+        let Some(group_info) = account_info_iter.next() else {
+            break;
+        };
+        let group_id = board.tallied_group_count;
+        if group_info.data_is_empty() {
+            let (group_pda, _) = Pubkey::find_program_address(
+                &[GROUP_SEED, board_info.key.as_ref(), &group_id.to_le_bytes()],
+                program_id,
+            );
+            if group_pda != *group_info.key {
+                return Err(RetroError::InvalidPDA.into());
+            }
+        } else {
+            let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+            if group.note_count > 0 {
+                board.candidate_count += 1;
+            }
+            group.vote_tally = 0;
+            group.vote_round = board.vote_round;
+            // Ballots later in the batch may write this group again, so write through
+            // a fresh slice rather than advancing the account's own
+            group.serialize(&mut &mut group_info.data.borrow_mut()[..])?;
+        }
+        board.tallied_group_count += 1;
+    }
+
+    // Then ballots, strictly in participant order so none is counted twice
+    while let Some(ballot_info) = account_info_iter.next() {
+        if ballot_info.owner != program_id {
+            return Err(RetroError::InvalidAccountOwner.into());
+        }
+        let ballot = Ballot::deserialize(&mut &ballot_info.data.borrow()[..])?;
+        if ballot.discriminator != DISCRIMINATOR_BALLOT {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        if ballot.board != *board_info.key {
+            return Err(RetroError::AccountBoardMismatch.into());
+        }
+        if ballot.vote_round != board.vote_round
+            || board
+                .last_tallied_participant
+                .is_some_and(|p| p >= ballot.participant)
+        {
+            return Err(RetroError::InvalidTallyAccounts.into());
+        }
+
+        for (group_id, points) in ballot.borda_points(board.candidate_count) {
+            let group_info = next_account_info(account_info_iter)?;
+            let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+            group.vote_tally += points;
+            group.serialize(&mut &mut group_info.data.borrow_mut()[..])?;
+        }
+
+        board.tallied_ballot_count += 1;
+        board.last_tallied_participant = Some(ballot.participant);
+    }
+
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reclaim_ballot(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimBallot");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let ballot_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if ballot_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let ballot = Ballot::deserialize(&mut &ballot_info.data.borrow()[..])?;
+    if ballot.discriminator != DISCRIMINATOR_BALLOT {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !ballot.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let board = load_settled_board(program_id, board_info, &ballot.board)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &ballot.rent_payer, board.as_ref(), team_info)?;
+
+    if *recipient_info.key != ballot.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(ballot_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_commit_votes(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    commitment: [u8; 32],
+) -> ProgramResult {
+    msg!("Instruction: CommitVotes");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let commitment_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the voter (authority) based on signing method
+    let voter = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &voter,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().voting {
+        return Err(RetroError::InvalidStage.into());
+    }
+    if board.voting_mode != VotingMode::Sealed {
+        return Err(RetroError::InvalidVotingMode.into());
+    }
+
+    // Check allowlist (uses voter/authority, not session signer)
+    load_membership(program_id, board_info.key, &voter, membership_info)?;
+
+    let (commitment_pda, commitment_bump) = Pubkey::find_program_address(
+        &[VOTE_COMMITMENT_SEED, board_info.key.as_ref(), voter.as_ref()],
+        program_id,
+    );
+    if commitment_pda != *commitment_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    let mut vote_commitment = if commitment_info.data_is_empty() {
+        let rent = Rent::get()?;
+        let space = VoteCommitment::LEN;
+        let lamports = rent.minimum_balance(space);
+
+        invoke_signed(
+            &system_instruction::create_account(
+                signer_info.key,
+                commitment_info.key,
+                lamports,
+                space as u64,
+                program_id,
+            ),
+            &[
+                signer_info.clone(),
+                commitment_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[VOTE_COMMITMENT_SEED, board_info.key.as_ref(), voter.as_ref(), &[commitment_bump]]],
+        )?;
+
+        VoteCommitment {
+            discriminator: DISCRIMINATOR_VOTE_COMMITMENT,
+            is_initialized: true,
+            board: *board_info.key,
+            participant: voter,
+            commitment,
+            revealed: false,
+            vote_round: board.vote_round,
+            rent_payer: *signer_info.key,
+            bump: commitment_bump,
+        }
+    } else {
+        let c = VoteCommitment::deserialize(&mut &commitment_info.data.borrow()[..])?;
+        if c.discriminator != DISCRIMINATOR_VOTE_COMMITMENT {
+            return Err(RetroError::InvalidAccountDiscriminator.into());
+        }
+        c
+    };
+
+    // Reveals only happen after Vote, so a commitment being replaced is never revealed in this round
+    vote_commitment.commitment = commitment;
+    vote_commitment.revealed = false;
+    vote_commitment.vote_round = board.vote_round;
+    vote_commitment.serialize(&mut *commitment_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reveal_votes(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    allocations: Vec<VoteAllocation>,
+    salt: [u8; 32],
+) -> ProgramResult {
+    msg!("Instruction: RevealVotes");
+
+    // Determine if session token is present based on account count
+    // Without session: board, membership, commitment, signer, groups... (4 + allocations.len())
+    // With session: board, membership, commitment, signer, session_token, groups... (5 + allocations.len())
+    let num_base_accounts = 4;
+    let expected_without_session = num_base_accounts + allocations.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let commitment_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the voter (authority) based on signing method
+    let voter = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &voter,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().reveal {
+        return Err(RetroError::InvalidStage.into());
+    }
+    check_notes_migrated(&board)?;
+
+    let mut membership = load_membership(program_id, board_info.key, &voter, membership_info)?;
+    membership.sync_vote_round(board.vote_round);
+
+    let (commitment_pda, _) = Pubkey::find_program_address(
+        &[VOTE_COMMITMENT_SEED, board_info.key.as_ref(), voter.as_ref()],
+        program_id,
+    );
+    if commitment_pda != *commitment_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+    if commitment_info.data_is_empty() {
+        return Err(RetroError::NoVoteCommitment.into());
+    }
+
+    let mut vote_commitment = VoteCommitment::deserialize(&mut &commitment_info.data.borrow()[..])?;
+    if vote_commitment.discriminator != DISCRIMINATOR_VOTE_COMMITMENT {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if vote_commitment.vote_round != board.vote_round {
+        return Err(RetroError::NoVoteCommitment.into());
+    }
+    if vote_commitment.revealed {
+        return Err(RetroError::VotesAlreadyRevealed.into());
+    }
+
+    let expected = VoteCommitment::commitment(&voter, board_info.key, &allocations, &salt)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    if expected != vote_commitment.commitment {
+        return Err(RetroError::VoteCommitmentMismatch.into());
+    }
+
+    if allocations.iter().enumerate().any(|(i, a)| {
+        a.votes == 0 || allocations[..i].iter().any(|b| b.group_id == a.group_id)
+    }) {
+        return Err(RetroError::InvalidVoteAllocation.into());
+    }
+
+    let total_credits: u64 = allocations.iter().map(|a| board.voting_mode.cost(a.votes)).sum();
+    if total_credits > membership.voting_credits as u64 {
+        return Err(RetroError::InsufficientCredits.into());
+    }
+
+    for allocation in &allocations {
+        let group_info = next_account_info(account_info_iter)?;
+        let mut group = load_group(program_id, board_info.key, allocation.group_id, group_info)?;
+        if group.note_count == 0 {
+            return Err(RetroError::EmptyGroup.into());
+        }
+        group.sync_vote_round(board.vote_round);
+        group.vote_tally = group
+            .vote_tally
+            .checked_add(allocation.votes as u64)
+            .ok_or(RetroError::InsufficientCredits)?;
+        group.serialize(&mut *group_info.data.borrow_mut())?;
+    }
+
+    membership.credits_spent = total_credits as u8;
+    membership.serialize(&mut *membership_info.data.borrow_mut())?;
+
+    vote_commitment.revealed = true;
+    vote_commitment.serialize(&mut *commitment_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_reclaim_vote_commitment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Instruction: ReclaimVoteCommitment");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let commitment_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if commitment_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let vote_commitment = VoteCommitment::deserialize(&mut &commitment_info.data.borrow()[..])?;
+    if vote_commitment.discriminator != DISCRIMINATOR_VOTE_COMMITMENT {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !vote_commitment.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    let board = load_settled_board(program_id, board_info, &vote_commitment.board)?;
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = match &board {
+        Some(board) => next_team_account(board, account_info_iter)?,
+        None => None,
+    };
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_reclaim(program_id, &authority, &vote_commitment.rent_payer, board.as_ref(), team_info)?;
+
+    if *recipient_info.key != vote_commitment.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(commitment_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_set_credit_budget(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    participant: Pubkey,
+    credits: u8,
+) -> ProgramResult {
+    msg!("Instruction: SetCreditBudget");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    let facilitator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    validate_signer_or_session(
+        signer_info,
+        &facilitator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    authorize_facilitator(program_id, &board, team_info, &facilitator)?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    // Budgets are fixed once voting starts so nobody ends up over budget mid-vote
+    if !board.stage.capabilities().manage_members {
+        return Err(RetroError::InvalidStage.into());
+    }
+
+    let mut membership = load_membership(program_id, board_info.key, &participant, membership_info)?;
+    membership.voting_credits = credits;
+    membership.serialize(&mut *membership_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_merge_groups(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    target_group_id: u64,
+    source_group_id: u64,
+    note_ids: Vec<u64>,
+) -> ProgramResult {
+    msg!("Instruction: MergeGroups");
+
+    let board_info = accounts.first().ok_or(ProgramError::NotEnoughAccountKeys)?;
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Determine if session token is present based on account count
+    // Without session: board, target, source, recipient, membership, signer, [team], notes... (6 + team + note_ids.len())
+    // With session: board, target, source, recipient, membership, signer, [team], session_token, notes...
+    let num_base_accounts = 6 + usize::from(board.team.is_some());
+    let expected_without_session = num_base_accounts + note_ids.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter();
+
+    next_account_info(account_info_iter)?; // board, loaded above
+    let target_info = next_account_info(account_info_iter)?;
+    let source_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the participant (authority) based on signing method
+    let participant = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &participant,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().grouping {
+        return Err(RetroError::InvalidStage.into());
+    }
+    check_notes_migrated(&board)?;
+
+    // Check allowlist
+    load_membership(program_id, board_info.key, &participant, membership_info)?;
+
+    if target_group_id == source_group_id {
+        return Err(RetroError::SameGroup.into());
+    }
+    let mut target = load_group(program_id, board_info.key, target_group_id, target_info)?;
+    let source = load_group(program_id, board_info.key, source_group_id, source_info)?;
+
+    // Closing a group is up to whoever created it, as in DeleteGroup, or a facilitator
+    if source.created_by != participant {
+        authorize_facilitator(program_id, &board, team_info, &participant)?;
+    }
+
+    // move_notes rejects repeats and notes outside the source, so this many ids is all of them
+    if note_ids.len() != source.note_count as usize {
+        return Err(RetroError::GroupNotesMissing.into());
+    }
+
+    move_notes(
+        program_id,
+        board_info.key,
+        account_info_iter.as_slice(),
+        &note_ids,
+        source_group_id,
+        target_group_id,
+    )?;
+
+    target.note_count += source.note_count;
+    target.serialize(&mut *target_info.data.borrow_mut())?;
+
+    if *recipient_info.key != source.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+    close_account(source_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_split_group(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    group_id: u64,
+    title: String,
+    note_ids: Vec<u64>,
+) -> ProgramResult {
+    msg!("Instruction: SplitGroup");
+
+    // Determine if session token is present based on account count
+    // Without session: board, group, new group, membership, signer, system, notes... (6 + note_ids.len())
+    // With session: board, group, new group, membership, signer, system, session_token, notes... (7 + note_ids.len())
+    let num_base_accounts = 6;
+    let expected_without_session = num_base_accounts + note_ids.len();
+    let expected_with_session = expected_without_session + 1;
+
+    let has_session_token = if accounts.len() == expected_with_session {
+        true
+    } else if accounts.len() == expected_without_session {
+        false
+    } else {
+        msg!(
+            "Invalid account count: got {}, expected {} or {}",
+            accounts.len(),
+            expected_without_session,
+            expected_with_session
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let group_info = next_account_info(account_info_iter)?;
+    let new_group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    // Optional session token - only consume if present
+    let session_token_info = if has_session_token {
+        Some(next_account_info(account_info_iter)?)
+    } else {
+        None
+    };
+
+    // Determine the creator (authority) based on signing method
+    let creator = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &creator,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let mut board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().grouping {
+        return Err(RetroError::InvalidStage.into());
+    }
+
+    // Check allowlist
+    load_membership(program_id, board_info.key, &creator, membership_info)?;
+
+    if title.len() > MAX_GROUP_TITLE_CHARS {
+        return Err(RetroError::GroupTitleTooLong.into());
+    }
+    if note_ids.is_empty() {
+        return Err(RetroError::NoNotesSelected.into());
+    }
+
+    let mut group = load_group(program_id, board_info.key, group_id, group_info)?;
+
+    let new_group_id = board.group_count;
+    let (pda, bump) = Pubkey::find_program_address(
+        &[GROUP_SEED, board_info.key.as_ref(), &new_group_id.to_le_bytes()],
+        program_id,
+    );
+
+    if pda != *new_group_info.key {
+        return Err(RetroError::InvalidPDA.into());
+    }
+
+    let rent = Rent::get()?;
+    let space = Group::MAX_LEN;
+    let lamports = rent.minimum_balance(space);
+
+    invoke_signed(
+        &system_instruction::create_account(
+            signer_info.key,
+            new_group_info.key,
+            lamports,
+            space as u64,
+            program_id,
+        ),
+        &[
+            signer_info.clone(),
+            new_group_info.clone(),
+            system_program_info.clone(),
+        ],
+        &[&[GROUP_SEED, board_info.key.as_ref(), &new_group_id.to_le_bytes(), &[bump]]],
+    )?;
+
+    let new_group = Group {
+        discriminator: DISCRIMINATOR_GROUP,
+        is_initialized: true,
+        board: *board_info.key,
+        group_id: new_group_id,
+        title,
+        created_by: creator,
+        vote_tally: 0,
+        vote_round: board.vote_round,
+        note_count: note_ids.len() as u32,
+        rent_payer: *signer_info.key,
+        bump,
+    };
+
+    move_notes(
+        program_id,
+        board_info.key,
+        account_info_iter.as_slice(),
+        &note_ids,
+        group_id,
+        new_group_id,
+    )?;
+
+    group.note_count = group
+        .note_count
+        .checked_sub(new_group.note_count)
+        .ok_or(RetroError::GroupNoteCountMismatch)?;
+    group.serialize(&mut *group_info.data.borrow_mut())?;
+    new_group.serialize(&mut *new_group_info.data.borrow_mut())?;
+
+    board.group_count += 1;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    Ok(())
+}
 
-    // Deserialize and validate
-    let mut identity = ParticipantIdentity::deserialize(&mut &identity_info.data.borrow()[..])?;
-    if identity.discriminator != DISCRIMINATOR_PARTICIPANT_IDENTITY {
+fn process_move_note(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    note_id: u64,
+    from_group_id: u64,
+    to_group_id: u64,
+) -> ProgramResult {
+    msg!("Instruction: MoveNote");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let note_info = next_account_info(account_info_iter)?;
+    let from_group_info = next_account_info(account_info_iter)?;
+    let to_group_info = next_account_info(account_info_iter)?;
+    let membership_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the participant (authority) based on signing method
+    let participant = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &participant,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
         return Err(RetroError::InvalidAccountDiscriminator.into());
     }
-    if !identity.is_initialized {
+    if !board.is_initialized {
         return Err(RetroError::AccountNotInitialized.into());
     }
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().grouping {
+        return Err(RetroError::InvalidStage.into());
+    }
 
-    // Verify authority matches
-    if identity.authority != *authority_info.key {
-        return Err(RetroError::UnauthorizedIdentityUpdate.into());
+    // Check allowlist
+    load_membership(program_id, board_info.key, &participant, membership_info)?;
+
+    if from_group_id == to_group_id {
+        return Err(RetroError::SameGroup.into());
     }
+    let mut from_group = load_group(program_id, board_info.key, from_group_id, from_group_info)?;
+    let mut to_group = load_group(program_id, board_info.key, to_group_id, to_group_info)?;
 
-    // Update username
-    identity.username = username;
-    identity.serialize(&mut *identity_info.data.borrow_mut())?;
+    move_notes(
+        program_id,
+        board_info.key,
+        std::slice::from_ref(note_info),
+        &[note_id],
+        from_group_id,
+        to_group_id,
+    )?;
 
-    msg!("Identity updated for authority: {}", authority_info.key);
+    from_group.note_count = from_group
+        .note_count
+        .checked_sub(1)
+        .ok_or(RetroError::GroupNoteCountMismatch)?;
+    from_group.serialize(&mut *from_group_info.data.borrow_mut())?;
+    to_group.note_count += 1;
+    to_group.serialize(&mut *to_group_info.data.borrow_mut())?;
+
+    Ok(())
+}
+
+fn process_delete_group(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    group_id: u64,
+) -> ProgramResult {
+    msg!("Instruction: DeleteGroup");
+    let account_info_iter = &mut accounts.iter();
+
+    let board_info = next_account_info(account_info_iter)?;
+    let group_info = next_account_info(account_info_iter)?;
+    let recipient_info = next_account_info(account_info_iter)?;
+    let signer_info = next_account_info(account_info_iter)?;
+
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    if !board.is_initialized {
+        return Err(RetroError::AccountNotInitialized.into());
+    }
+
+    // Team boards pass the team account right after the fixed accounts
+    let team_info = next_team_account(&board, account_info_iter)?;
+    // Optional session token for session-based signing
+    let session_token_info = next_account_info(account_info_iter).ok();
+
+    // Determine the authority based on signing method
+    let authority = if let Some(session_info) = session_token_info {
+        let session = SessionToken::deserialize(&mut &session_info.data.borrow()[..])?;
+        session.authority
+    } else {
+        *signer_info.key
+    };
+
+    // Validate signature (either direct or session-based)
+    validate_signer_or_session(
+        signer_info,
+        &authority,
+        session_token_info,
+        program_id,
+        program_id,
+    )?;
+
+    if board.closed {
+        return Err(RetroError::BoardClosed.into());
+    }
+    if !board.stage.capabilities().grouping {
+        return Err(RetroError::InvalidStage.into());
+    }
+    check_notes_migrated(&board)?;
+
+    let group = load_group(program_id, board_info.key, group_id, group_info)?;
+    if authority != group.created_by {
+        authorize_facilitator(program_id, &board, team_info, &authority)?;
+    }
+    if group.note_count > 0 {
+        return Err(RetroError::GroupNotEmpty.into());
+    }
+
+    // Rent goes back to whoever signed for the group, which may be a session key
+    if *recipient_info.key != group.rent_payer {
+        return Err(RetroError::InvalidRentRecipient.into());
+    }
+
+    close_account(group_info, recipient_info)?;
+
+    Ok(())
+}
+
+fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Instruction: MigrateAccount");
+    let account_info_iter = &mut accounts.iter();
+
+    let account_info = next_account_info(account_info_iter)?;
+    let payer_info = next_account_info(account_info_iter)?;
+    let system_program_info = next_account_info(account_info_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if account_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+
+    let discriminator = account_info
+        .data
+        .borrow()
+        .first()
+        .copied()
+        .ok_or(RetroError::AccountNotInitialized)?;
+
+    // Everything a legacy account lacks is either counted from the accounts that follow,
+    // or starts from the state a new account would have
+    let (data, space, rent_payer) = match discriminator {
+        DISCRIMINATOR_RETRO_BOARD => migrate_board(
+            program_id,
+            account_info,
+            payer_info,
+            system_program_info,
+            account_info_iter,
+        )?,
+        DISCRIMINATOR_BOARD_MEMBERSHIP => migrate_membership(program_id, account_info, account_info_iter)?,
+        DISCRIMINATOR_NOTE => migrate_note(program_id, account_info, account_info_iter)?,
+        DISCRIMINATOR_GROUP => migrate_group(program_id, account_info, account_info_iter)?,
+        DISCRIMINATOR_VOTE_RECORD => migrate_vote_record(account_info)?,
+        DISCRIMINATOR_ACTION_ITEM => migrate_action_item(program_id, account_info, account_info_iter)?,
+        DISCRIMINATOR_VERIFICATION_VOTE => migrate_verification_vote(account_info)?,
+        _ => return Err(RetroError::AccountAlreadyMigrated.into()),
+    };
 
+    // Whoever the account refunds its rent to tops it up, so the refund is all theirs
+    if *payer_info.key != rent_payer {
+        return Err(RetroError::MigrationPayerMismatch.into());
+    }
+
+    // The payer covers any rent the new layout needs on top of what the account holds
+    let required = Rent::get()?.minimum_balance(space);
+    let top_up = required.saturating_sub(account_info.lamports());
+    if top_up > 0 {
+        invoke_signed(
+            &system_instruction::transfer(payer_info.key, account_info.key, top_up),
+            &[
+                payer_info.clone(),
+                account_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[],
+        )?;
+    }
+
+    account_info.resize(space)?;
+    let mut account_data = account_info.data.borrow_mut();
+    account_data.fill(0);
+    account_data[..data.len()].copy_from_slice(&data);
+
+    Ok(())
+}
+
+// Legacy accounts were allocated at a fixed size no current layout shares
+fn check_legacy_len(account_info: &AccountInfo, legacy_len: usize) -> ProgramResult {
+    if account_info.data_len() != legacy_len {
+        return Err(RetroError::AccountAlreadyMigrated.into());
+    }
     Ok(())
 }
+
+// Board an account is migrated against; it has to be in the current layout already
+fn load_migrated_board(
+    program_id: &Pubkey,
+    board_info: &AccountInfo,
+    expected_board: &Pubkey,
+) -> Result<RetroBoard, ProgramError> {
+    if board_info.key != expected_board {
+        return Err(RetroError::AccountBoardMismatch.into());
+    }
+    if board_info.owner != program_id {
+        return Err(RetroError::InvalidAccountOwner.into());
+    }
+    if board_info.data_len() == LegacyRetroBoard::LEN {
+        return Err(RetroError::BoardNotMigrated.into());
+    }
+
+    let board = RetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+    if board.discriminator != DISCRIMINATOR_RETRO_BOARD {
+        return Err(RetroError::InvalidAccountDiscriminator.into());
+    }
+    Ok(board)
+}
+
+/// Accounts after the fixed ones:
+/// 1. `[writable]` BoardMembership PDA per allowlist entry, in allowlist order; missing ones are created
+fn migrate_board<'a, 'b>(
+    program_id: &Pubkey,
+    board_info: &'a AccountInfo<'b>,
+    payer_info: &'a AccountInfo<'b>,
+    system_program_info: &'a AccountInfo<'b>,
+    account_info_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(board_info, LegacyRetroBoard::LEN)?;
+    let legacy = LegacyRetroBoard::deserialize(&mut &board_info.data.borrow()[..])?;
+
+    // The allowlist becomes one membership per participant. Memberships created lazily
+    // on a first vote may be missing for participants who never voted.
+    let rent = Rent::get()?;
+    for participant in &legacy.allowlist {
+        let membership_info = next_account_info(account_info_iter)?;
+
+        let (pda, membership_bump) = Pubkey::find_program_address(
+            &[MEMBERSHIP_SEED, board_info.key.as_ref(), participant.as_ref()],
+            program_id,
+        );
+
+        if pda != *membership_info.key {
+            return Err(RetroError::InvalidPDA.into());
+        }
+        if !membership_info.data_is_empty() {
+            continue;
+        }
+
+        let space = BoardMembership::LEN;
+        invoke_signed(
+            &system_instruction::create_account(
+                payer_info.key,
+                membership_info.key,
+                rent.minimum_balance(space),
+                space as u64,
+                program_id,
+            ),
+            &[
+                payer_info.clone(),
+                membership_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[&[MEMBERSHIP_SEED, board_info.key.as_ref(), participant.as_ref(), &[membership_bump]]],
+        )?;
+
+        let membership = BoardMembership {
+            discriminator: DISCRIMINATOR_BOARD_MEMBERSHIP,
+            is_initialized: true,
+            board: *board_info.key,
+            participant: *participant,
+            voting_credits: legacy.voting_credits_per_participant,
+            credits_spent: 0,
+            total_score: 0,
+            rejected_count: 0,
+            vote_round: 0,
+            rent_payer: *payer_info.key,
+            bump: membership_bump,
+        };
+        membership.serialize(&mut *membership_info.data.borrow_mut())?;
+    }
+
+    let board = RetroBoard {
+        discriminator: DISCRIMINATOR_RETRO_BOARD,
+        is_initialized: legacy.is_initialized,
+        facilitator: legacy.facilitator,
+        pending_facilitator: None,
+        board_index: legacy.board_index,
+        stage: legacy.stage,
+        stages: BoardStage::default_pipeline(false, false),
+        stage_duration_slots: Vec::new(),
+        closed: legacy.closed,
+        categories: legacy.categories,
+        voting_credits_per_participant: legacy.voting_credits_per_participant,
+        voting_mode: VotingMode::Linear,
+        vote_round: 0,
+        ballot_count: 0,
+        tallied_group_count: 0,
+        candidate_count: 0,
+        tallied_ballot_count: 0,
+        last_tallied_participant: None,
+        hide_note_authors: false,
+        note_count: legacy.note_count,
+        group_count: legacy.group_count,
+        action_item_count: legacy.action_item_count,
+        // Every action item counts as pending until its own migration says otherwise,
+        // so the board can't look settled while legacy items remain
+        pending_action_item_count: legacy.action_item_count,
+        // Action items could not be reclaimed yet
+        live_action_item_count: legacy.action_item_count,
+        // Legacy notes were never deleted, so each id up to note_count has one to migrate
+        legacy_note_count: legacy.note_count,
+        membership_count: legacy.allowlist.len() as u32,
+        team: None,
+        predecessor: None,
+        created_at_slot: legacy.created_at_slot,
+        stage_changed_at_slot: legacy.stage_changed_at_slot,
+        // Legacy boards were always created and paid for by their facilitator
+        rent_payer: legacy.facilitator,
+        bump: legacy.bump,
+    };
+
+    Ok((board.try_to_vec()?, RetroBoard::MAX_LEN, board.rent_payer))
+}
+
+/// Accounts after the fixed ones:
+/// 1. `[]` Board PDA, already migrated
+fn migrate_membership(
+    program_id: &Pubkey,
+    membership_info: &AccountInfo,
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(membership_info, LegacyBoardMembership::LEN)?;
+    let legacy = LegacyBoardMembership::deserialize(&mut &membership_info.data.borrow()[..])?;
+
+    let board_info = next_account_info(account_info_iter)?;
+    let board = load_migrated_board(program_id, board_info, &legacy.board)?;
+
+    let membership = BoardMembership {
+        discriminator: DISCRIMINATOR_BOARD_MEMBERSHIP,
+        is_initialized: legacy.is_initialized,
+        board: legacy.board,
+        participant: legacy.participant,
+        voting_credits: board.voting_credits_per_participant,
+        credits_spent: legacy.credits_spent,
+        total_score: legacy.total_score,
+        rejected_count: 0,
+        // Legacy credits were spent before any revert, in the board's first round
+        vote_round: 0,
+        // Most memberships were created with the board; there is no record of the rest
+        rent_payer: board.rent_payer,
+        bump: legacy.bump,
+    };
+
+    Ok((membership.try_to_vec()?, BoardMembership::LEN, membership.rent_payer))
+}
+
+/// Accounts after the fixed ones:
+/// 1. `[writable]` Board PDA, already migrated
+/// 2. `[writable]` Group PDA, already migrated, only for a note in a group; the note counts itself into it
+fn migrate_note(
+    program_id: &Pubkey,
+    note_info: &AccountInfo,
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(note_info, LegacyNote::LEN)?;
+    let legacy = LegacyNote::deserialize(&mut &note_info.data.borrow()[..])?;
+
+    // Voting and group deletion wait for the board's last legacy note
+    let board_info = next_account_info(account_info_iter)?;
+    let mut board = load_migrated_board(program_id, board_info, &legacy.board)?;
+    board.legacy_note_count = board
+        .legacy_note_count
+        .checked_sub(1)
+        .ok_or(RetroError::LegacyNoteCountMismatch)?;
+    board.serialize(&mut *board_info.data.borrow_mut())?;
+
+    let mut group_id = legacy.group_id;
+    if let Some(id) = group_id {
+        let group_info = next_account_info(account_info_iter)?;
+        if group_info.data_is_empty() {
+            // The group was deleted or reclaimed since; the note leaves it
+            let (pda, _) = Pubkey::find_program_address(
+                &[GROUP_SEED, legacy.board.as_ref(), &id.to_le_bytes()],
+                program_id,
+            );
+            if pda != *group_info.key {
+                return Err(RetroError::InvalidPDA.into());
+            }
+            group_id = None;
+        } else {
+            if group_info.data_len() == LegacyGroup::LEN {
+                return Err(RetroError::GroupNotMigrated.into());
+            }
+            let mut group = load_group(program_id, &legacy.board, id, group_info)?;
+            group.note_count += 1;
+            group.serialize(&mut *group_info.data.borrow_mut())?;
+        }
+    }
+
+    let note = Note {
+        discriminator: DISCRIMINATOR_NOTE,
+        is_initialized: legacy.is_initialized,
+        board: legacy.board,
+        note_id: legacy.note_id,
+        author: legacy.author,
+        author_commitment: None,
+        category_id: legacy.category_id,
+        content: legacy.content,
+        created_at_slot: legacy.created_at_slot,
+        group_id,
+        // Legacy notes were paid for by the author or a session key the author funds
+        rent_payer: legacy.author,
+        bump: legacy.bump,
+    };
+
+    Ok((note.try_to_vec()?, Note::MAX_LEN, note.rent_payer))
+}
+
+/// Accounts after the fixed ones:
+/// 1. `[]` Board PDA, already migrated
+fn migrate_group(
+    program_id: &Pubkey,
+    group_info: &AccountInfo,
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(group_info, LegacyGroup::LEN)?;
+    let legacy = LegacyGroup::deserialize(&mut &group_info.data.borrow()[..])?;
+
+    let board_info = next_account_info(account_info_iter)?;
+    load_migrated_board(program_id, board_info, &legacy.board)?;
+
+    let group = Group {
+        discriminator: DISCRIMINATOR_GROUP,
+        is_initialized: legacy.is_initialized,
+        board: legacy.board,
+        group_id: legacy.group_id,
+        title: legacy.title,
+        created_by: legacy.created_by,
+        vote_tally: legacy.vote_tally,
+        vote_round: 0,
+        // Each note adds itself when it is migrated
+        note_count: 0,
+        rent_payer: legacy.created_by,
+        bump: legacy.bump,
+    };
+
+    Ok((group.try_to_vec()?, Group::MAX_LEN, group.rent_payer))
+}
+
+fn migrate_vote_record(vote_record_info: &AccountInfo) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(vote_record_info, LegacyVoteRecord::LEN)?;
+    let legacy = LegacyVoteRecord::deserialize(&mut &vote_record_info.data.borrow()[..])?;
+
+    let vote_record = VoteRecord {
+        discriminator: DISCRIMINATOR_VOTE_RECORD,
+        is_initialized: legacy.is_initialized,
+        board: legacy.board,
+        participant: legacy.participant,
+        group_id: legacy.group_id,
+        votes: legacy.credits_spent,
+        credits_spent: legacy.credits_spent,
+        vote_round: 0,
+        rent_payer: legacy.participant,
+        bump: legacy.bump,
+    };
+
+    Ok((vote_record.try_to_vec()?, VoteRecord::LEN, vote_record.rent_payer))
+}
+
+/// Accounts after the fixed ones:
+/// 1. `[writable]` Board PDA, already migrated
+fn migrate_action_item(
+    program_id: &Pubkey,
+    action_item_info: &AccountInfo,
+    account_info_iter: &mut std::slice::Iter<AccountInfo>,
+) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(action_item_info, LegacyActionItem::LEN)?;
+    let legacy = LegacyActionItem::deserialize(&mut &action_item_info.data.borrow()[..])?;
+
+    let board_info = next_account_info(account_info_iter)?;
+    let mut board = load_migrated_board(program_id, board_info, &legacy.board)?;
+
+    // The board counted every legacy item as pending; settled ones come off as they migrate
+    if legacy.status != ActionItemStatus::Pending {
+        board.pending_action_item_count = board
+            .pending_action_item_count
+            .checked_sub(1)
+            .ok_or(RetroError::PendingActionItemCountMismatch)?;
+        board.serialize(&mut *board_info.data.borrow_mut())?;
+    }
+
+    let action_item = ActionItem {
+        discriminator: DISCRIMINATOR_ACTION_ITEM,
+        is_initialized: legacy.is_initialized,
+        board: legacy.board,
+        action_item_id: legacy.action_item_id,
+        description: legacy.description,
+        owner: legacy.owner,
+        verifiers: legacy.verifiers,
+        threshold: legacy.threshold,
+        approvals: legacy.approvals,
+        rejections: 0,
+        status: legacy.status,
+        created_at_slot: legacy.created_at_slot,
+        verified_at_slot: legacy.verified_at_slot,
+        due_at: None,
+        // Only the facilitator could create action items
+        rent_payer: board.rent_payer,
+        bump: legacy.bump,
+    };
+
+    Ok((action_item.try_to_vec()?, ActionItem::MAX_LEN, action_item.rent_payer))
+}
+
+fn migrate_verification_vote(verification_vote_info: &AccountInfo) -> Result<(Vec<u8>, usize, Pubkey), ProgramError> {
+    check_legacy_len(verification_vote_info, LegacyVerificationVote::LEN)?;
+    let legacy = LegacyVerificationVote::deserialize(&mut &verification_vote_info.data.borrow()[..])?;
+
+    let verification_vote = VerificationVote {
+        discriminator: DISCRIMINATOR_VERIFICATION_VOTE,
+        is_initialized: legacy.is_initialized,
+        action_item: legacy.action_item,
+        verifier: legacy.verifier,
+        approved: legacy.approved,
+        voted_at_slot: legacy.voted_at_slot,
+        rent_payer: legacy.verifier,
+        bump: legacy.bump,
+    };
+
+    Ok((verification_vote.try_to_vec()?, VerificationVote::LEN, verification_vote.rent_payer))
+}
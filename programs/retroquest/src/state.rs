@@ -1,16 +1,21 @@
 use borsh::{BorshDeserialize, BorshSerialize};
-use solana_program::pubkey::Pubkey;
+use solana_program::{hash::hashv, pubkey::Pubkey};
 
 // Constants
 pub const MAX_NOTE_CHARS: usize = 280;
 pub const MAX_GROUP_TITLE_CHARS: usize = 80;
-pub const MAX_PARTICIPANTS: usize = 8;
+pub const MAX_PARTICIPANTS: usize = 64;
+pub const MAX_INITIAL_PARTICIPANTS: usize = 8; // CreateBoard with a full config must fit one 1232-byte transaction
 pub const MAX_CATEGORIES: usize = 5;
 pub const MAX_CATEGORY_NAME_LEN: usize = 32;
 pub const VOTING_CREDITS_DEFAULT: u8 = 5;
 pub const MAX_ACTION_DESCRIPTION_CHARS: usize = 280;
-pub const MAX_VERIFIERS: usize = 7; // MAX_PARTICIPANTS - 1 (owner can't verify)
+pub const MAX_VERIFIERS: usize = 7; // Bounded by ActionItem account size, not MAX_PARTICIPANTS
 pub const MAX_USERNAME_CHARS: usize = 32;
+pub const MAX_TEAM_NAME_LEN: usize = 32; // Used as a PDA seed, so bounded by the 32-byte seed limit
+pub const MAX_TEAM_MEMBERS: usize = 32;
+pub const MAX_STAGES: usize = 7; // One of each BoardStage
+pub const MAX_BALLOT_RANKS: usize = 10;
 
 // PDA Seeds
 pub const FACILITATOR_REGISTRY_SEED: &[u8] = b"facilitator_registry";
@@ -22,6 +27,10 @@ pub const VOTE_SEED: &[u8] = b"vote";
 pub const ACTION_ITEM_SEED: &[u8] = b"action_item";
 pub const VERIFICATION_VOTE_SEED: &[u8] = b"verification_vote";
 pub const PARTICIPANT_IDENTITY_SEED: &[u8] = b"participant";
+pub const REPUTATION_SEED: &[u8] = b"reputation";
+pub const TEAM_SEED: &[u8] = b"team";
+pub const BALLOT_SEED: &[u8] = b"ballot";
+pub const VOTE_COMMITMENT_SEED: &[u8] = b"vote_commitment";
 
 // Account Type Discriminators (offset 0)
 // These uniquely identify each account type to prevent type confusion
@@ -35,6 +44,10 @@ pub const DISCRIMINATOR_ACTION_ITEM: u8 = 7;
 pub const DISCRIMINATOR_VERIFICATION_VOTE: u8 = 8;
 pub const DISCRIMINATOR_PARTICIPANT_IDENTITY: u8 = 9;
 pub const DISCRIMINATOR_SESSION_TOKEN: u8 = 10;
+pub const DISCRIMINATOR_REPUTATION: u8 = 11;
+pub const DISCRIMINATOR_TEAM: u8 = 12;
+pub const DISCRIMINATOR_BALLOT: u8 = 13;
+pub const DISCRIMINATOR_VOTE_COMMITMENT: u8 = 14;
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -44,21 +57,156 @@ pub enum BoardStage {
     GroupDuplicates = 2,
     Vote = 3,
     Discuss = 4,
+    /// Follow-up boards verify their predecessor's action items between Setup and WriteNotes
+    ReviewPreviousActions = 5,
+    /// Sealed boards open their vote commitments between Vote and Discuss
+    RevealVotes = 6,
 }
 
 impl BoardStage {
-    pub fn can_advance_to(&self, next: BoardStage) -> bool {
-        let current = *self as u8;
-        let target = next as u8;
-        target == current + 1
+    /// Position in a pipeline; enabled stages must appear in increasing rank
+    pub fn rank(&self) -> u8 {
+        match self {
+            BoardStage::Setup => 0,
+            BoardStage::ReviewPreviousActions => 1,
+            BoardStage::WriteNotes => 2,
+            BoardStage::GroupDuplicates => 3,
+            BoardStage::Vote => 4,
+            BoardStage::RevealVotes => 5,
+            BoardStage::Discuss => 6,
+        }
+    }
+
+    pub fn default_pipeline(follow_up: bool, sealed_votes: bool) -> Vec<BoardStage> {
+        let mut stages = vec![BoardStage::Setup];
+        if follow_up {
+            stages.push(BoardStage::ReviewPreviousActions);
+        }
+        stages.extend([
+            BoardStage::WriteNotes,
+            BoardStage::GroupDuplicates,
+            BoardStage::Vote,
+        ]);
+        if sealed_votes {
+            stages.push(BoardStage::RevealVotes);
+        }
+        stages.push(BoardStage::Discuss);
+        stages
+    }
+
+    /// What participants and the facilitator can do while the board sits in this stage
+    pub fn capabilities(&self) -> StageCapabilities {
+        let none = StageCapabilities::default();
+        match self {
+            BoardStage::Setup => StageCapabilities {
+                manage_members: true,
+                ..none
+            },
+            BoardStage::ReviewPreviousActions => StageCapabilities {
+                review: true,
+                manage_members: true,
+                ..none
+            },
+            BoardStage::WriteNotes => StageCapabilities {
+                notes: true,
+                manage_members: true,
+                ..none
+            },
+            BoardStage::GroupDuplicates => StageCapabilities {
+                grouping: true,
+                manage_members: true,
+                ..none
+            },
+            BoardStage::Vote => StageCapabilities {
+                voting: true,
+                ..none
+            },
+            BoardStage::RevealVotes => StageCapabilities {
+                reveal: true,
+                ..none
+            },
+            BoardStage::Discuss => StageCapabilities {
+                action_items: true,
+                ..none
+            },
+        }
     }
 }
 
+/// Per-stage capability table; processors check the flag for their action
+/// instead of comparing against a fixed stage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StageCapabilities {
+    /// Vote on the predecessor board's action items
+    pub review: bool,
+    /// Create, edit and delete notes
+    pub notes: bool,
+    /// Create, merge and delete groups and move notes between them
+    pub grouping: bool,
+    /// Cast, retract, ballot or commit votes
+    pub voting: bool,
+    /// Open sealed vote commitments
+    pub reveal: bool,
+    /// Create action items from the vote results
+    pub action_items: bool,
+    /// Remove participants and change their credit budgets; closed once credits can be spent
+    pub manage_members: bool,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum VotingMode {
+    /// Each vote costs one credit
+    Linear = 0,
+    /// n votes on one group cost n² credits
+    Quadratic = 1,
+    /// Participants rank groups on a Ballot instead of spending credits.
+    /// TallyBallots adds their Borda points to the groups before the board leaves Vote.
+    Ranked = 2,
+    /// Participants commit a hash of their allocation during Vote and open it
+    /// in RevealVotes; tallies only count revealed allocations
+    Sealed = 3,
+}
+
+impl VotingMode {
+    /// Credits needed to hold `votes` votes on a single group
+    pub fn cost(&self, votes: u8) -> u64 {
+        match self {
+            // Ranked boards don't spend credits; CastVote is rejected before this is reached
+            VotingMode::Linear | VotingMode::Ranked | VotingMode::Sealed => votes as u64,
+            VotingMode::Quadratic => (votes as u64) * (votes as u64),
+        }
+    }
+
+    /// Votes go straight into group tallies through CastVote
+    pub fn has_live_tally(&self) -> bool {
+        matches!(self, VotingMode::Linear | VotingMode::Quadratic)
+    }
+}
+
+/// Votes placed on one group in a sealed allocation
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteAllocation {
+    pub group_id: u64,
+    pub votes: u8,
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum ActionItemStatus {
     Pending = 0,
     Completed = 1,
+    /// Due date passed before the threshold was met
+    Expired = 2,
+    /// Too many verifiers rejected for the threshold to still be met
+    Rejected = 3,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum TeamRole {
+    Member = 0,
+    Facilitator = 1,
 }
 
 /// FacilitatorRegistry tracks how many boards a facilitator has created.
@@ -77,43 +225,151 @@ impl FacilitatorRegistry {
     pub const LEN: usize = 1 + 1 + 32 + 8 + 1;
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamMember {
+    pub member: Pubkey,
+    pub role: TeamRole,
+}
+
+/// Team owns a series of boards so that any of its facilitators can run them.
+/// Boards are derived from the team the same way FacilitatorRegistry derives them from a wallet.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Team {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub creator: Pubkey,
+    pub name: String,
+    pub members: Vec<TeamMember>,
+    pub board_count: u64,
+    pub bump: u8,
+}
+
+impl Team {
+    // discriminator(1) + is_initialized(1) + creator(32) + name(4 + MAX_TEAM_NAME_LEN) +
+    // members(4 + MAX_TEAM_MEMBERS * (32 + 1)) + board_count(8) + bump(1)
+    pub const MAX_LEN: usize = 1 + 1 + 32 + (4 + MAX_TEAM_NAME_LEN) + (4 + MAX_TEAM_MEMBERS * 33) + 8 + 1;
+
+    pub fn role_of(&self, member: &Pubkey) -> Option<TeamRole> {
+        self.members.iter().find(|m| m.member == *member).map(|m| m.role)
+    }
+
+    pub fn is_facilitator(&self, member: &Pubkey) -> bool {
+        self.role_of(member) == Some(TeamRole::Facilitator)
+    }
+
+    pub fn facilitator_count(&self) -> usize {
+        self.members.iter().filter(|m| m.role == TeamRole::Facilitator).count()
+    }
+}
+
 /// RetroBoard is the main entity where participants post notes and vote.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct RetroBoard {
     pub discriminator: u8,
     pub is_initialized: bool,
     pub facilitator: Pubkey,
+    /// Participant offered the facilitator role; takes over once they accept
+    pub pending_facilitator: Option<Pubkey>,
     pub board_index: u64,
     pub stage: BoardStage,
+    /// Enabled stages in order, starting with Setup
+    pub stages: Vec<BoardStage>,
+    /// Timebox per entry in `stages`, in slots (0 = untimed); empty when the board has no timeboxes
+    pub stage_duration_slots: Vec<u64>,
     pub closed: bool,
     pub categories: Vec<String>,
-    pub allowlist: Vec<Pubkey>,
     pub voting_credits_per_participant: u8,
+    pub voting_mode: VotingMode,
+    /// Bumped when RevertStage discards votes; memberships, groups and vote records
+    /// from an older round count as zero and are reset when next touched
+    pub vote_round: u32,
+    /// Ballots submitted in the current vote round (ranked boards)
+    pub ballot_count: u32,
+    /// TallyBallots progress for the current vote round: groups scanned so far (in id order)
+    /// and how many of them could be ranked
+    pub tallied_group_count: u64,
+    pub candidate_count: u64,
+    /// Ballots tallied so far, and the last one's participant; ballots go in participant order
+    pub tallied_ballot_count: u32,
+    pub last_tallied_participant: Option<Pubkey>,
+    /// Notes store a salted commitment instead of the author's key, and no rent payer
+    /// until the author reveals themselves. This keeps authors off the board, not off
+    /// the chain: the creating transaction still names the wallet that signed.
+    pub hide_note_authors: bool,
+    /// Next note id. Ids are never reused, so deleted notes leave gaps in 0..note_count.
     pub note_count: u64,
     pub group_count: u64,
     pub action_item_count: u64,
+    /// Action items still awaiting verification
+    pub pending_action_item_count: u64,
+    /// Action items whose accounts have not been reclaimed yet
+    pub live_action_item_count: u64,
+    /// Notes still in the pre-migration layout; their groups don't count them yet
+    pub legacy_note_count: u64,
+    /// Live BoardMembership accounts; a participant is on the allowlist iff their membership exists
+    pub membership_count: u32,
+    /// Team that owns the board; any of its facilitators can run it
+    pub team: Option<Pubkey>,
+    /// Closed board whose action items this board follows up on
+    pub predecessor: Option<Pubkey>,
     pub created_at_slot: u64,
     pub stage_changed_at_slot: u64,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl RetroBoard {
-    // Base size without dynamic Vecs
-    // discriminator(1) + is_initialized(1) + facilitator(32) + board_index(8) +
-    // stage(1) + closed(1) + voting_credits(1) +
-    // note_count(8) + group_count(8) + action_item_count(8) + created_at_slot(8) + stage_changed_at_slot(8) + bump(1)
-    pub const BASE_LEN: usize = 1 + 1 + 32 + 8 + 1 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1;
+    // Base size without the dynamic categories and stages Vecs
+    // discriminator(1) + is_initialized(1) + facilitator(32) + pending_facilitator(1 + 32) + board_index(8) +
+    // stage(1) + closed(1) + voting_credits(1) + voting_mode(1) + vote_round(4) + ballot_count(4) +
+    // tallied_group_count(8) + candidate_count(8) + tallied_ballot_count(4) + last_tallied_participant(1 + 32) +
+    // hide_note_authors(1) +
+    // note_count(8) + group_count(8) + action_item_count(8) +
+    // pending_action_item_count(8) + live_action_item_count(8) + legacy_note_count(8) + membership_count(4) +
+    // team(1 + 32) + predecessor(1 + 32) + created_at_slot(8) + stage_changed_at_slot(8) + rent_payer(32) + bump(1)
+    pub const BASE_LEN: usize = 1 + 1 + 32 + 33 + 8 + 1 + 1 + 1 + 1 + 4 + 4 + 8 + 8 + 4 + 33 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 33 + 33 + 8 + 8 + 32 + 1;
 
     // Categories: vec_len(4) + MAX_CATEGORIES * (str_len(4) + MAX_CATEGORY_NAME_LEN)
     pub const CATEGORIES_LEN: usize = 4 + (MAX_CATEGORIES * (4 + MAX_CATEGORY_NAME_LEN));
 
-    // Allowlist: vec_len(4) + MAX_PARTICIPANTS * pubkey(32)
-    pub const ALLOWLIST_LEN: usize = 4 + (MAX_PARTICIPANTS * 32);
+    // Stages: vec_len(4) + MAX_STAGES * stage(1), plus durations: vec_len(4) + MAX_STAGES * slots(8)
+    pub const STAGES_LEN: usize = 4 + MAX_STAGES + 4 + MAX_STAGES * 8;
+
+    pub const MAX_LEN: usize = Self::BASE_LEN + Self::CATEGORIES_LEN + Self::STAGES_LEN;
+
+    /// Stage that follows the current one in this board's pipeline
+    pub fn next_stage(&self) -> Option<BoardStage> {
+        let position = self.stages.iter().position(|s| *s == self.stage)?;
+        self.stages.get(position + 1).copied()
+    }
+
+    pub fn is_final_stage(&self) -> bool {
+        self.stages.last() == Some(&self.stage)
+    }
+
+    /// Slot at which the current stage's timebox runs out, if it has one
+    pub fn stage_deadline_slot(&self) -> Option<u64> {
+        let position = self.stages.iter().position(|s| *s == self.stage)?;
+        match self.stage_duration_slots.get(position) {
+            Some(&duration) if duration > 0 => Some(self.stage_changed_at_slot.saturating_add(duration)),
+            _ => None,
+        }
+    }
+
+    /// Ranked tallying has started, which closes the round to new and changed ballots
+    pub fn is_tallying(&self) -> bool {
+        self.tallied_group_count > 0
+    }
 
-    pub const MAX_LEN: usize = Self::BASE_LEN + Self::CATEGORIES_LEN + Self::ALLOWLIST_LEN;
+    /// Every ballot of the current round has been tallied into its groups
+    pub fn ballots_tallied(&self) -> bool {
+        self.tallied_ballot_count == self.ballot_count
+    }
 }
 
 /// BoardMembership links a participant to a board.
+/// Its existence is what puts a participant on the board's allowlist.
 /// Enables board discovery and tracks voting credits spent.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
 pub struct BoardMembership {
@@ -121,14 +377,31 @@ pub struct BoardMembership {
     pub is_initialized: bool,
     pub board: Pubkey,
     pub participant: Pubkey,
+    /// Voting budget; starts at the board's voting_credits_per_participant
+    pub voting_credits: u8,
     pub credits_spent: u8,
     pub total_score: u64,
+    /// Owned action items that verifiers rejected
+    pub rejected_count: u64,
+    /// Board vote round that `credits_spent` belongs to; credits from an older round
+    /// count as unspent
+    pub vote_round: u32,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl BoardMembership {
-    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + credits_spent(1) + total_score(8) + bump(1)
-    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8 + 1;
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + voting_credits(1) + credits_spent(1) + total_score(8) + rejected_count(8) + vote_round(4) + rent_payer(32) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 1 + 8 + 8 + 4 + 32 + 1;
+
+    /// Refund credits spent in a round that RevertStage discarded
+    pub fn sync_vote_round(&mut self, round: u32) {
+        if self.vote_round != round {
+            self.credits_spent = 0;
+            self.vote_round = round;
+        }
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -137,17 +410,32 @@ pub struct Note {
     pub is_initialized: bool,
     pub board: Pubkey,
     pub note_id: u64,
+    /// Author's wallet, or the default pubkey while a hidden author is unrevealed
     pub author: Pubkey,
+    /// Set on boards that hide note authors: hash(author || board || salt)
+    pub author_commitment: Option<[u8; 32]>,
     pub category_id: u8,
     pub content: String,
     pub created_at_slot: u64,
     pub group_id: Option<u64>,
+    /// Wallet that paid the rent, which is refunded to it when the account closes.
+    /// Left as the default key while the author is hidden; the author gets the rent back.
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl Note {
-    // discriminator(1) + is_initialized(1) + board(32) + note_id(8) + author(32) + category_id(1) + content(4 + MAX) + created_at_slot(8) + group_id(9) + bump(1)
-    pub const MAX_LEN: usize = 1 + 1 + 32 + 8 + 32 + 1 + (4 + MAX_NOTE_CHARS) + 8 + 9 + 1;
+    // discriminator(1) + is_initialized(1) + board(32) + note_id(8) + author(32) + author_commitment(1 + 32) + category_id(1) + content(4 + MAX) + created_at_slot(8) + group_id(9) + rent_payer(32) + bump(1)
+    pub const MAX_LEN: usize = 1 + 1 + 32 + 8 + 32 + 33 + 1 + (4 + MAX_NOTE_CHARS) + 8 + 9 + 32 + 1;
+
+    /// Commitment an author stores in place of their key and later opens with the salt.
+    pub fn author_commitment(author: &Pubkey, board: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
+        hashv(&[author.as_ref(), board.as_ref(), salt]).to_bytes()
+    }
+
+    pub fn is_author_hidden(&self) -> bool {
+        self.author_commitment.is_some() && self.author == Pubkey::default()
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -159,12 +447,27 @@ pub struct Group {
     pub title: String,
     pub created_by: Pubkey,
     pub vote_tally: u64,
+    /// Board vote round that `vote_tally` belongs to; a tally from an older round
+    /// counts as zero
+    pub vote_round: u32,
+    /// Notes currently assigned to the group
+    pub note_count: u32,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl Group {
-    // discriminator(1) + is_initialized(1) + board(32) + group_id(8) + title(4 + MAX) + created_by(32) + vote_tally(8) + bump(1)
-    pub const MAX_LEN: usize = 1 + 1 + 32 + 8 + (4 + MAX_GROUP_TITLE_CHARS) + 32 + 8 + 1;
+    // discriminator(1) + is_initialized(1) + board(32) + group_id(8) + title(4 + MAX) + created_by(32) + vote_tally(8) + vote_round(4) + note_count(4) + rent_payer(32) + bump(1)
+    pub const MAX_LEN: usize = 1 + 1 + 32 + 8 + (4 + MAX_GROUP_TITLE_CHARS) + 32 + 8 + 4 + 4 + 32 + 1;
+
+    /// Clear a tally from a round that RevertStage discarded
+    pub fn sync_vote_round(&mut self, round: u32) {
+        if self.vote_round != round {
+            self.vote_tally = 0;
+            self.vote_round = round;
+        }
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
@@ -174,13 +477,30 @@ pub struct VoteRecord {
     pub board: Pubkey,
     pub participant: Pubkey,
     pub group_id: u64,
+    /// Votes added to the group's tally
+    pub votes: u8,
+    /// Credits those votes cost under the board's voting mode
     pub credits_spent: u8,
+    /// Board vote round that `votes` and `credits_spent` belong to; both count as zero
+    /// when it is older than the board's
+    pub vote_round: u32,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl VoteRecord {
-    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + group_id(8) + credits_spent(1) + bump(1)
-    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 1 + 1;
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + group_id(8) + votes(1) + credits_spent(1) + vote_round(4) + rent_payer(32) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 1 + 1 + 4 + 32 + 1;
+
+    /// Drop votes from a round that RevertStage discarded
+    pub fn sync_vote_round(&mut self, round: u32) {
+        if self.vote_round != round {
+            self.votes = 0;
+            self.credits_spent = 0;
+            self.vote_round = round;
+        }
+    }
 }
 
 /// ActionItem represents a task committed to during a retrospective.
@@ -196,19 +516,33 @@ pub struct ActionItem {
     pub verifiers: Vec<Pubkey>,
     pub threshold: u8,
     pub approvals: u8,
+    pub rejections: u8,
     pub status: ActionItemStatus,
     pub created_at_slot: u64,
     pub verified_at_slot: Option<u64>,
+    /// Unix timestamp after which the item can no longer be verified
+    pub due_at: Option<i64>,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl ActionItem {
     // discriminator(1) + is_initialized(1) + board(32) + action_item_id(8) +
     // description(4 + MAX_ACTION_DESCRIPTION_CHARS) + owner(32) +
-    // verifiers(4 + MAX_VERIFIERS * 32) + threshold(1) + approvals(1) +
-    // status(1) + created_at_slot(8) + verified_at_slot(1 + 8) + bump(1)
+    // verifiers(4 + MAX_VERIFIERS * 32) + threshold(1) + approvals(1) + rejections(1) +
+    // status(1) + created_at_slot(8) + verified_at_slot(1 + 8) + due_at(1 + 8) + rent_payer(32) + bump(1)
     pub const MAX_LEN: usize = 1 + 1 + 32 + 8 + (4 + MAX_ACTION_DESCRIPTION_CHARS) + 32
-        + (4 + MAX_VERIFIERS * 32) + 1 + 1 + 1 + 8 + 9 + 1;
+        + (4 + MAX_VERIFIERS * 32) + 1 + 1 + 1 + 1 + 8 + 9 + 9 + 32 + 1;
+
+    /// Whether enough verifiers are left who have not rejected for the threshold to be met
+    pub fn can_reach_threshold(&self) -> bool {
+        self.verifiers.len() - self.rejections as usize >= self.threshold as usize
+    }
+
+    pub fn is_past_due(&self, now: i64) -> bool {
+        self.due_at.is_some_and(|due_at| now > due_at)
+    }
 }
 
 /// VerificationVote records a verifier's vote on an action item.
@@ -220,12 +554,14 @@ pub struct VerificationVote {
     pub verifier: Pubkey,
     pub approved: bool,
     pub voted_at_slot: u64,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
     pub bump: u8,
 }
 
 impl VerificationVote {
-    // discriminator(1) + is_initialized(1) + action_item(32) + verifier(32) + approved(1) + voted_at_slot(8) + bump(1)
-    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8 + 1;
+    // discriminator(1) + is_initialized(1) + action_item(32) + verifier(32) + approved(1) + voted_at_slot(8) + rent_payer(32) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 1 + 8 + 32 + 1;
 }
 
 /// ParticipantIdentity stores a user's display name.
@@ -243,3 +579,108 @@ impl ParticipantIdentity {
     // discriminator(1) + is_initialized(1) + authority(32) + username(4 + MAX_USERNAME_CHARS) + bump(1)
     pub const MAX_LEN: usize = 1 + 1 + 32 + (4 + MAX_USERNAME_CHARS) + 1;
 }
+
+/// Reputation aggregates a participant's action item outcomes across all boards.
+/// Derived from their ParticipantIdentity PDA; outcomes are recorded from the moment it exists.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Reputation {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub authority: Pubkey,
+    pub identity: Pubkey,
+    pub completed: u64,
+    pub rejected: u64,
+    pub expired: u64,
+    /// Consecutive completions since the last rejected or expired item
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub bump: u8,
+}
+
+impl Reputation {
+    // discriminator(1) + is_initialized(1) + authority(32) + identity(32) + completed(8) + rejected(8) + expired(8) + current_streak(4) + best_streak(4) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 8 + 8 + 8 + 4 + 4 + 1;
+
+    pub fn record(&mut self, status: ActionItemStatus) {
+        match status {
+            ActionItemStatus::Completed => {
+                self.completed += 1;
+                self.current_streak += 1;
+                self.best_streak = self.best_streak.max(self.current_streak);
+            }
+            ActionItemStatus::Rejected => {
+                self.rejected += 1;
+                self.current_streak = 0;
+            }
+            ActionItemStatus::Expired => {
+                self.expired += 1;
+                self.current_streak = 0;
+            }
+            ActionItemStatus::Pending => {}
+        }
+    }
+}
+
+/// Ballot holds one participant's ranking of groups on a ranked board.
+/// Only ballots from the board's current vote round are tallied.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct Ballot {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub participant: Pubkey,
+    /// Group ids, most preferred first
+    pub ranking: Vec<u64>,
+    pub vote_round: u32,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl Ballot {
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) +
+    // ranking(4 + MAX_BALLOT_RANKS * 8) + vote_round(4) + rent_payer(32) + bump(1)
+    pub const MAX_LEN: usize = 1 + 1 + 32 + 32 + (4 + MAX_BALLOT_RANKS * 8) + 4 + 32 + 1;
+
+    /// Borda points per ranked group: first place earns `candidates` (the groups that could be
+    /// ranked), each later place one less
+    pub fn borda_points(&self, candidates: u64) -> impl Iterator<Item = (u64, u64)> + '_ {
+        self.ranking
+            .iter()
+            .enumerate()
+            .map(move |(position, group_id)| (*group_id, candidates.saturating_sub(position as u64)))
+    }
+}
+
+/// VoteCommitment hides a participant's vote allocation on a sealed board until RevealVotes.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone)]
+pub struct VoteCommitment {
+    pub discriminator: u8,
+    pub is_initialized: bool,
+    pub board: Pubkey,
+    pub participant: Pubkey,
+    pub commitment: [u8; 32],
+    pub revealed: bool,
+    pub vote_round: u32,
+    /// Wallet that paid the rent, which is refunded to it when the account closes
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+}
+
+impl VoteCommitment {
+    // discriminator(1) + is_initialized(1) + board(32) + participant(32) + commitment(32) +
+    // revealed(1) + vote_round(4) + rent_payer(32) + bump(1)
+    pub const LEN: usize = 1 + 1 + 32 + 32 + 32 + 1 + 4 + 32 + 1;
+
+    /// Commitment a voter submits during Vote and later opens with the allocations and salt.
+    /// The allocations are hashed in their Borsh encoding.
+    pub fn commitment(
+        voter: &Pubkey,
+        board: &Pubkey,
+        allocations: &[VoteAllocation],
+        salt: &[u8; 32],
+    ) -> Result<[u8; 32], std::io::Error> {
+        let encoded = allocations.try_to_vec()?;
+        Ok(hashv(&[voter.as_ref(), board.as_ref(), &encoded, salt]).to_bytes())
+    }
+}
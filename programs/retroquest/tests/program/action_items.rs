@@ -0,0 +1,336 @@
+use retroquest::{
+    error::RetroError,
+    state::{ActionItem, ActionItemStatus, BoardStage},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board in Discuss with four participants; the first owns nothing yet
+fn discuss_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 4, |_| {});
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture.advance(ctx, BoardStage::Discuss);
+    fixture
+}
+
+#[test]
+fn action_items_reject_duplicate_verifiers() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, verifier, other, _] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier, verifier],
+        2,
+    ));
+    assert_eq!(error_code(result), RetroError::DuplicateVerifier as u32);
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier, other],
+        2,
+    ))
+    .unwrap();
+}
+
+#[test]
+fn reassignment_rejects_duplicate_verifiers() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, verifier, other, _] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier, other],
+        2,
+    ))
+    .unwrap();
+
+    let result = ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[other, other],
+        2,
+        &[(verifier, verifier)],
+    ));
+    assert_eq!(error_code(result), RetroError::DuplicateVerifier as u32);
+}
+
+#[test]
+fn only_the_facilitator_can_reassign() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, verifier, other, _] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier],
+        1,
+    ))
+    .unwrap();
+
+    let result = ctx.process(reassign_action_item(
+        &fixture.board,
+        &owner,
+        0,
+        &owner,
+        &[other],
+        1,
+        &[(verifier, verifier)],
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    let result = ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &verifier,
+        &[verifier],
+        1,
+        &[],
+    ));
+    assert_eq!(error_code(result), RetroError::OwnerCannotVerify as u32);
+}
+
+#[test]
+fn reassignment_refunds_dropped_votes_and_takes_them_out_of_the_tally() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, first, second, third] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[first, second],
+        2,
+    ))
+    .unwrap();
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+
+    let before = ctx.lamports(&first);
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &first,
+        true,
+    ))
+    .unwrap();
+
+    // The dropped vote's rent goes back to whoever paid for it, not anyone else
+    let result = ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[second, third],
+        2,
+        &[(first, second)],
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[second, third],
+        2,
+        &[(first, first)],
+    ))
+    .unwrap();
+
+    let action_item_key = action_item_pda(&fixture.board, 0);
+    assert!(ctx
+        .account(&verification_vote_pda(&action_item_key, &first))
+        .is_none());
+    assert_eq!(ctx.lamports(&first), before);
+
+    let action_item: ActionItem = ctx.state(&action_item_key);
+    assert_eq!(action_item.verifiers, vec![second, third]);
+    assert_eq!(action_item.approvals, 0);
+    assert_eq!(action_item.status, ActionItemStatus::Pending);
+
+    let result = ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &first,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::NotAVerifier as u32);
+}
+
+#[test]
+fn reassignment_settles_items_the_remaining_votes_decide() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, first, second, _] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[first, second],
+        2,
+    ))
+    .unwrap();
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &first,
+        true,
+    ))
+    .unwrap();
+    assert_eq!(fixture.board_state(&ctx).pending_action_item_count, 1);
+
+    // Lowering the threshold to the one approval already cast completes the item
+    ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[first, second],
+        1,
+        &[],
+    ))
+    .unwrap();
+
+    let action_item: ActionItem = ctx.state(&action_item_pda(&fixture.board, 0));
+    assert_eq!(action_item.status, ActionItemStatus::Completed);
+    assert_eq!(fixture.board_state(&ctx).pending_action_item_count, 0);
+
+    let result = ctx.process(reassign_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[second],
+        1,
+        &[(first, first)],
+    ));
+    assert_eq!(error_code(result), RetroError::ActionItemNotPending as u32);
+}
+
+#[test]
+fn verifiers_can_change_their_vote_until_the_item_settles() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, first, second, third] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[first, second, third],
+        2,
+    ))
+    .unwrap();
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &first,
+        true,
+    ))
+    .unwrap();
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &second,
+        false,
+    ))
+    .unwrap();
+
+    let result = ctx.process(update_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &second,
+        false,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::VerificationVoteUnchanged as u32
+    );
+
+    // There is nothing to change before the first vote
+    let result = ctx.process(update_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &third,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidAccountOwner as u32);
+
+    ctx.process(update_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &second,
+        true,
+    ))
+    .unwrap();
+
+    let action_item: ActionItem = ctx.state(&action_item_pda(&fixture.board, 0));
+    assert_eq!(action_item.approvals, 2);
+    assert_eq!(action_item.rejections, 0);
+    assert_eq!(action_item.status, ActionItemStatus::Completed);
+    assert_eq!(fixture.board_state(&ctx).pending_action_item_count, 0);
+
+    let result = ctx.process(update_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &first,
+        false,
+    ));
+    assert_eq!(error_code(result), RetroError::ActionItemNotPending as u32);
+}
@@ -0,0 +1,929 @@
+//! Instruction builders and board fixtures shared by the tests.
+
+use borsh::BorshSerialize;
+use retroquest::{
+    instructions::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    },
+    state::*,
+};
+
+use crate::harness::{instruction, TestContext, SYSTEM_PROGRAM_ID};
+
+pub fn registry_pda(facilitator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[FACILITATOR_REGISTRY_SEED, facilitator.as_ref()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn board_pda(authority: &Pubkey, board_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BOARD_SEED, authority.as_ref(), &board_index.to_le_bytes()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn membership_pda(board: &Pubkey, participant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[MEMBERSHIP_SEED, board.as_ref(), participant.as_ref()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn note_pda(board: &Pubkey, note_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[NOTE_SEED, board.as_ref(), &note_id.to_le_bytes()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn group_pda(board: &Pubkey, group_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[GROUP_SEED, board.as_ref(), &group_id.to_le_bytes()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn vote_record_pda(board: &Pubkey, participant: &Pubkey, group_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            VOTE_SEED,
+            board.as_ref(),
+            participant.as_ref(),
+            &group_id.to_le_bytes(),
+        ],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn action_item_pda(board: &Pubkey, action_item_id: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            ACTION_ITEM_SEED,
+            board.as_ref(),
+            &action_item_id.to_le_bytes(),
+        ],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn verification_vote_pda(action_item: &Pubkey, verifier: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            VERIFICATION_VOTE_SEED,
+            action_item.as_ref(),
+            verifier.as_ref(),
+        ],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn ballot_pda(board: &Pubkey, participant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[BALLOT_SEED, board.as_ref(), participant.as_ref()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn vote_commitment_pda(board: &Pubkey, participant: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[VOTE_COMMITMENT_SEED, board.as_ref(), participant.as_ref()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+pub fn reputation_pda(participant: &Pubkey) -> Pubkey {
+    let identity = Pubkey::find_program_address(
+        &[PARTICIPANT_IDENTITY_SEED, participant.as_ref()],
+        &retroquest::ID,
+    )
+    .0;
+    Pubkey::find_program_address(&[REPUTATION_SEED, identity.as_ref()], &retroquest::ID).0
+}
+
+pub fn team_pda(creator: &Pubkey, name: &str) -> Pubkey {
+    Pubkey::find_program_address(
+        &[TEAM_SEED, creator.as_ref(), name.as_bytes()],
+        &retroquest::ID,
+    )
+    .0
+}
+
+/// CreateBoard payload, laid out like `BoardConfig`
+#[derive(BorshSerialize, Clone)]
+pub struct Config {
+    pub categories: Vec<String>,
+    pub allowlist: Vec<Pubkey>,
+    pub voting_credits_per_participant: Option<u8>,
+    pub hide_note_authors: bool,
+    pub predecessor: Option<Pubkey>,
+    pub stages: Option<Vec<BoardStage>>,
+    pub stage_duration_slots: Option<Vec<u64>>,
+    pub voting_mode: VotingMode,
+    pub credit_budgets: Option<Vec<u8>>,
+}
+
+impl Config {
+    pub fn new(allowlist: Vec<Pubkey>) -> Self {
+        Self {
+            categories: vec!["Went well".to_string(), "To improve".to_string()],
+            allowlist,
+            voting_credits_per_participant: None,
+            hide_note_authors: false,
+            predecessor: None,
+            stages: None,
+            stage_duration_slots: None,
+            voting_mode: VotingMode::Linear,
+            credit_budgets: None,
+        }
+    }
+}
+
+fn data<T: BorshSerialize>(discriminator: u8, payload: T) -> Vec<u8> {
+    let mut data = vec![discriminator];
+    data.extend(payload.try_to_vec().unwrap());
+    data
+}
+
+pub fn init_registry(facilitator: &Pubkey) -> Instruction {
+    instruction(
+        vec![INIT_FACILITATOR_REGISTRY],
+        vec![
+            AccountMeta::new(registry_pda(facilitator), false),
+            AccountMeta::new(*facilitator, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn create_board(facilitator: &Pubkey, board_index: u64, config: &Config) -> Instruction {
+    let board = board_pda(facilitator, board_index);
+    let mut accounts = vec![
+        AccountMeta::new(registry_pda(facilitator), false),
+        AccountMeta::new(board, false),
+        AccountMeta::new(*facilitator, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    for participant in &config.allowlist {
+        accounts.push(AccountMeta::new(membership_pda(&board, participant), false));
+    }
+    if let Some(predecessor) = config.predecessor {
+        accounts.push(AccountMeta::new_readonly(predecessor, false));
+    }
+    instruction(data(CREATE_BOARD, config), accounts)
+}
+
+pub fn create_team_board(
+    team: &Pubkey,
+    facilitator: &Pubkey,
+    board_index: u64,
+    config: &Config,
+) -> Instruction {
+    let board = board_pda(team, board_index);
+    let mut accounts = vec![
+        AccountMeta::new(*team, false),
+        AccountMeta::new(board, false),
+        AccountMeta::new(*facilitator, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    for participant in &config.allowlist {
+        accounts.push(AccountMeta::new(membership_pda(&board, participant), false));
+    }
+    if let Some(predecessor) = config.predecessor {
+        accounts.push(AccountMeta::new_readonly(predecessor, false));
+    }
+    instruction(data(CREATE_BOARD, config), accounts)
+}
+
+pub fn create_team(creator: &Pubkey, name: &str) -> Instruction {
+    instruction(
+        data(CREATE_TEAM, name.to_string()),
+        vec![
+            AccountMeta::new(team_pda(creator, name), false),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn advance_stage(board: &Pubkey, facilitator: &Pubkey, new_stage: BoardStage) -> Instruction {
+    instruction(
+        data(ADVANCE_STAGE, new_stage as u8),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new_readonly(*facilitator, true),
+        ],
+    )
+}
+
+pub fn crank_advance_stage(board: &Pubkey) -> Instruction {
+    instruction(
+        vec![CRANK_ADVANCE_STAGE],
+        vec![AccountMeta::new(*board, false)],
+    )
+}
+
+pub fn revert_stage(board: &Pubkey, facilitator: &Pubkey, target_stage: BoardStage) -> Instruction {
+    instruction(
+        data(REVERT_STAGE, target_stage as u8),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new_readonly(*facilitator, true),
+        ],
+    )
+}
+
+pub fn create_note(
+    board: &Pubkey,
+    note_id: u64,
+    author: &Pubkey,
+    content: &str,
+    author_commitment: Option<[u8; 32]>,
+) -> Instruction {
+    instruction(
+        data(CREATE_NOTE, (0u8, content.to_string(), author_commitment)),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new_readonly(membership_pda(board, author), false),
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn reveal_note_author(
+    board: &Pubkey,
+    note_id: u64,
+    author: &Pubkey,
+    salt: [u8; 32],
+) -> Instruction {
+    instruction(
+        data(REVEAL_NOTE_AUTHOR, salt),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new_readonly(*author, true),
+        ],
+    )
+}
+
+pub fn delete_note(
+    board: &Pubkey,
+    note_id: u64,
+    rent_recipient: &Pubkey,
+    author: &Pubkey,
+    salt: Option<[u8; 32]>,
+) -> Instruction {
+    instruction(
+        data(DELETE_NOTE, (note_id, salt)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*author, true),
+        ],
+    )
+}
+
+pub fn reclaim_note(
+    board: &Pubkey,
+    note_id: u64,
+    rent_recipient: &Pubkey,
+    authority: &Pubkey,
+    salt: Option<[u8; 32]>,
+) -> Instruction {
+    instruction(
+        data(RECLAIM_NOTE, salt),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn add_participant(board: &Pubkey, facilitator: &Pubkey, participant: &Pubkey) -> Instruction {
+    instruction(
+        data(ADD_PARTICIPANT, *participant),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(membership_pda(board, participant), false),
+            AccountMeta::new(*facilitator, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn remove_participant(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    participant: &Pubkey,
+    rent_recipient: &Pubkey,
+) -> Instruction {
+    instruction(
+        data(REMOVE_PARTICIPANT, *participant),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(membership_pda(board, participant), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*facilitator, true),
+        ],
+    )
+}
+
+pub fn reclaim_board(board: &Pubkey, rent_recipient: &Pubkey, authority: &Pubkey) -> Instruction {
+    instruction(
+        vec![RECLAIM_BOARD],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn reclaim_membership(
+    board: &Pubkey,
+    participant: &Pubkey,
+    rent_recipient: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    instruction(
+        vec![RECLAIM_MEMBERSHIP],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(membership_pda(board, participant), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn reclaim_action_item(
+    board: &Pubkey,
+    action_item_id: u64,
+    rent_recipient: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    instruction(
+        vec![RECLAIM_ACTION_ITEM],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(action_item_pda(board, action_item_id), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn reclaim_verification_vote(
+    board: &Pubkey,
+    action_item_id: u64,
+    verifier: &Pubkey,
+    rent_recipient: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let action_item = action_item_pda(board, action_item_id);
+    instruction(
+        vec![RECLAIM_VERIFICATION_VOTE],
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new_readonly(action_item, false),
+            AccountMeta::new(verification_vote_pda(&action_item, verifier), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn create_group(board: &Pubkey, group_id: u64, creator: &Pubkey, title: &str) -> Instruction {
+    instruction(
+        data(CREATE_GROUP, title.to_string()),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(group_pda(board, group_id), false),
+            AccountMeta::new_readonly(membership_pda(board, creator), false),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn assign_note(
+    board: &Pubkey,
+    note_id: u64,
+    group_id: u64,
+    participant: &Pubkey,
+) -> Instruction {
+    instruction(
+        data(ASSIGN_NOTE_TO_GROUP, (note_id, group_id)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new(group_pda(board, group_id), false),
+            AccountMeta::new_readonly(membership_pda(board, participant), false),
+            AccountMeta::new_readonly(*participant, true),
+        ],
+    )
+}
+
+pub fn unassign_note(
+    board: &Pubkey,
+    note_id: u64,
+    group_id: u64,
+    participant: &Pubkey,
+) -> Instruction {
+    instruction(
+        data(UNASSIGN_NOTE, note_id),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new(group_pda(board, group_id), false),
+            AccountMeta::new_readonly(membership_pda(board, participant), false),
+            AccountMeta::new_readonly(*participant, true),
+        ],
+    )
+}
+
+pub fn merge_groups(
+    board: &Pubkey,
+    target_group_id: u64,
+    source_group_id: u64,
+    rent_recipient: &Pubkey,
+    participant: &Pubkey,
+    note_ids: &[u64],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*board, false),
+        AccountMeta::new(group_pda(board, target_group_id), false),
+        AccountMeta::new(group_pda(board, source_group_id), false),
+        AccountMeta::new(*rent_recipient, false),
+        AccountMeta::new_readonly(membership_pda(board, participant), false),
+        AccountMeta::new_readonly(*participant, true),
+    ];
+    accounts.extend(
+        note_ids
+            .iter()
+            .map(|note_id| AccountMeta::new(note_pda(board, *note_id), false)),
+    );
+    instruction(
+        data(
+            MERGE_GROUPS,
+            (target_group_id, source_group_id, note_ids.to_vec()),
+        ),
+        accounts,
+    )
+}
+
+/// `new_group_id` is the board's group count, which the new group takes as its id
+pub fn split_group(
+    board: &Pubkey,
+    group_id: u64,
+    new_group_id: u64,
+    participant: &Pubkey,
+    title: &str,
+    note_ids: &[u64],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new(group_pda(board, group_id), false),
+        AccountMeta::new(group_pda(board, new_group_id), false),
+        AccountMeta::new_readonly(membership_pda(board, participant), false),
+        AccountMeta::new(*participant, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    accounts.extend(
+        note_ids
+            .iter()
+            .map(|note_id| AccountMeta::new(note_pda(board, *note_id), false)),
+    );
+    instruction(
+        data(
+            SPLIT_GROUP,
+            (group_id, title.to_string(), note_ids.to_vec()),
+        ),
+        accounts,
+    )
+}
+
+pub fn move_note(
+    board: &Pubkey,
+    note_id: u64,
+    from_group_id: u64,
+    to_group_id: u64,
+    participant: &Pubkey,
+) -> Instruction {
+    instruction(
+        data(MOVE_NOTE, (note_id, from_group_id, to_group_id)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(note_pda(board, note_id), false),
+            AccountMeta::new(group_pda(board, from_group_id), false),
+            AccountMeta::new(group_pda(board, to_group_id), false),
+            AccountMeta::new_readonly(membership_pda(board, participant), false),
+            AccountMeta::new_readonly(*participant, true),
+        ],
+    )
+}
+
+pub fn delete_group(
+    board: &Pubkey,
+    group_id: u64,
+    rent_recipient: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    instruction(
+        data(DELETE_GROUP, group_id),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(group_pda(board, group_id), false),
+            AccountMeta::new(*rent_recipient, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+    )
+}
+
+pub fn cast_vote(board: &Pubkey, voter: &Pubkey, group_id: u64, credits_delta: u8) -> Instruction {
+    instruction(
+        data(CAST_VOTE, (group_id, credits_delta)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(membership_pda(board, voter), false),
+            AccountMeta::new(group_pda(board, group_id), false),
+            AccountMeta::new(vote_record_pda(board, voter, group_id), false),
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn submit_ballot(board: &Pubkey, voter: &Pubkey, ranking: &[u64]) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new_readonly(membership_pda(board, voter), false),
+        AccountMeta::new(ballot_pda(board, voter), false),
+        AccountMeta::new(*voter, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    accounts.extend(
+        ranking
+            .iter()
+            .map(|group_id| AccountMeta::new_readonly(group_pda(board, *group_id), false)),
+    );
+    instruction(data(SUBMIT_BALLOT, ranking.to_vec()), accounts)
+}
+
+pub fn set_credit_budget(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    participant: &Pubkey,
+    credits: u8,
+) -> Instruction {
+    instruction(
+        data(SET_CREDIT_BUDGET, (*participant, credits)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(membership_pda(board, participant), false),
+            AccountMeta::new_readonly(*facilitator, true),
+        ],
+    )
+}
+
+pub fn commit_votes(board: &Pubkey, voter: &Pubkey, commitment: [u8; 32]) -> Instruction {
+    instruction(
+        data(COMMIT_VOTES, commitment),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new_readonly(membership_pda(board, voter), false),
+            AccountMeta::new(vote_commitment_pda(board, voter), false),
+            AccountMeta::new(*voter, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn reveal_votes(
+    board: &Pubkey,
+    voter: &Pubkey,
+    allocations: &[VoteAllocation],
+    salt: [u8; 32],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*board, false),
+        AccountMeta::new(membership_pda(board, voter), false),
+        AccountMeta::new(vote_commitment_pda(board, voter), false),
+        AccountMeta::new_readonly(*voter, true),
+    ];
+    accounts.extend(
+        allocations
+            .iter()
+            .map(|allocation| AccountMeta::new(group_pda(board, allocation.group_id), false)),
+    );
+    instruction(data(REVEAL_VOTES, (allocations.to_vec(), salt)), accounts)
+}
+
+/// One TallyBallots batch: the groups still to scan, then each ballot with the groups it ranks
+pub fn tally_ballots(
+    board: &Pubkey,
+    signer: &Pubkey,
+    group_ids: &[u64],
+    ballots: &[(Pubkey, &[u64])],
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new_readonly(*signer, true),
+    ];
+    accounts.extend(
+        group_ids
+            .iter()
+            .map(|group_id| AccountMeta::new(group_pda(board, *group_id), false)),
+    );
+    for (participant, ranking) in ballots {
+        accounts.push(AccountMeta::new_readonly(
+            ballot_pda(board, participant),
+            false,
+        ));
+        accounts.extend(
+            ranking
+                .iter()
+                .map(|group_id| AccountMeta::new(group_pda(board, *group_id), false)),
+        );
+    }
+    instruction(vec![TALLY_BALLOTS], accounts)
+}
+
+pub fn create_action_item(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifiers: &[Pubkey],
+    threshold: u8,
+) -> Instruction {
+    create_action_item_due_at(
+        board,
+        facilitator,
+        action_item_id,
+        owner,
+        verifiers,
+        threshold,
+        None,
+    )
+}
+
+pub fn create_action_item_due_at(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifiers: &[Pubkey],
+    threshold: u8,
+    due_at: Option<i64>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new(action_item_pda(board, action_item_id), false),
+        AccountMeta::new(*facilitator, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        AccountMeta::new_readonly(membership_pda(board, owner), false),
+    ];
+    for verifier in verifiers {
+        accounts.push(AccountMeta::new_readonly(
+            membership_pda(board, verifier),
+            false,
+        ));
+    }
+    instruction(
+        data(
+            CREATE_ACTION_ITEM,
+            (
+                "Follow up".to_string(),
+                *owner,
+                verifiers.to_vec(),
+                threshold,
+                due_at,
+            ),
+        ),
+        accounts,
+    )
+}
+
+pub fn expire_action_item(board: &Pubkey, action_item_id: u64, owner: &Pubkey) -> Instruction {
+    instruction(
+        vec![EXPIRE_ACTION_ITEM],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(action_item_pda(board, action_item_id), false),
+            AccountMeta::new(reputation_pda(owner), false),
+        ],
+    )
+}
+
+pub fn close_board(board: &Pubkey, facilitator: &Pubkey) -> Instruction {
+    instruction(
+        vec![CLOSE_BOARD],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new_readonly(*facilitator, true),
+        ],
+    )
+}
+
+pub fn cast_verification_vote(
+    board: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifier: &Pubkey,
+    approved: bool,
+) -> Instruction {
+    let action_item = action_item_pda(board, action_item_id);
+    instruction(
+        data(CAST_VERIFICATION_VOTE, (action_item_id, approved)),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(action_item, false),
+            AccountMeta::new(verification_vote_pda(&action_item, verifier), false),
+            AccountMeta::new(membership_pda(board, owner), false),
+            AccountMeta::new(reputation_pda(owner), false),
+            AccountMeta::new(*verifier, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+pub fn update_verification_vote(
+    board: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifier: &Pubkey,
+    approved: bool,
+) -> Instruction {
+    let action_item = action_item_pda(board, action_item_id);
+    instruction(
+        data(UPDATE_VERIFICATION_VOTE, approved),
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new(action_item, false),
+            AccountMeta::new(verification_vote_pda(&action_item, verifier), false),
+            AccountMeta::new(membership_pda(board, owner), false),
+            AccountMeta::new(reputation_pda(owner), false),
+            AccountMeta::new_readonly(*verifier, true),
+        ],
+    )
+}
+
+pub fn review_action_item(
+    board: &Pubkey,
+    predecessor: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifier: &Pubkey,
+    approved: bool,
+) -> Instruction {
+    let action_item = action_item_pda(predecessor, action_item_id);
+    instruction(
+        data(REVIEW_ACTION_ITEM, (action_item_id, approved)),
+        vec![
+            AccountMeta::new_readonly(*board, false),
+            AccountMeta::new(*predecessor, false),
+            AccountMeta::new(action_item, false),
+            AccountMeta::new(verification_vote_pda(&action_item, verifier), false),
+            AccountMeta::new(membership_pda(predecessor, owner), false),
+            AccountMeta::new(reputation_pda(owner), false),
+            AccountMeta::new(*verifier, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+/// `dropped` pairs each dropped verifier with whoever paid for their vote
+pub fn reassign_action_item(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    action_item_id: u64,
+    owner: &Pubkey,
+    verifiers: &[Pubkey],
+    threshold: u8,
+    dropped: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let action_item = action_item_pda(board, action_item_id);
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new(action_item, false),
+        AccountMeta::new_readonly(*facilitator, true),
+        AccountMeta::new(membership_pda(board, owner), false),
+        AccountMeta::new(reputation_pda(owner), false),
+    ];
+    for verifier in verifiers {
+        accounts.push(AccountMeta::new_readonly(
+            membership_pda(board, verifier),
+            false,
+        ));
+    }
+    for (verifier, rent_payer) in dropped {
+        accounts.push(AccountMeta::new(
+            verification_vote_pda(&action_item, verifier),
+            false,
+        ));
+        accounts.push(AccountMeta::new(*rent_payer, false));
+    }
+    instruction(
+        data(
+            REASSIGN_ACTION_ITEM,
+            (*owner, verifiers.to_vec(), threshold),
+        ),
+        accounts,
+    )
+}
+
+pub fn propose_facilitator(
+    board: &Pubkey,
+    facilitator: &Pubkey,
+    new_facilitator: &Pubkey,
+    team: Option<&Pubkey>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*board, false),
+        AccountMeta::new_readonly(membership_pda(board, new_facilitator), false),
+        AccountMeta::new_readonly(*facilitator, true),
+    ];
+    if let Some(team) = team {
+        accounts.push(AccountMeta::new_readonly(*team, false));
+    }
+    instruction(data(PROPOSE_FACILITATOR, *new_facilitator), accounts)
+}
+
+pub fn accept_facilitator(board: &Pubkey, new_facilitator: &Pubkey) -> Instruction {
+    instruction(
+        vec![ACCEPT_FACILITATOR],
+        vec![
+            AccountMeta::new(*board, false),
+            AccountMeta::new_readonly(membership_pda(board, new_facilitator), false),
+            AccountMeta::new_readonly(*new_facilitator, true),
+        ],
+    )
+}
+
+pub fn migrate_account(account: &Pubkey, payer: &Pubkey, extra: Vec<AccountMeta>) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+    ];
+    accounts.extend(extra);
+    instruction(vec![MIGRATE_ACCOUNT], accounts)
+}
+
+/// A facilitator's first board with its participants, sitting in Setup
+pub struct BoardFixture {
+    pub facilitator: Pubkey,
+    pub participants: Vec<Pubkey>,
+    pub board: Pubkey,
+}
+
+impl BoardFixture {
+    pub fn new(
+        ctx: &mut TestContext,
+        participant_count: usize,
+        configure: impl FnOnce(&mut Config),
+    ) -> Self {
+        let facilitator = ctx.wallet();
+        let participants: Vec<Pubkey> = (0..participant_count).map(|_| ctx.wallet()).collect();
+
+        let mut config = Config::new(participants.clone());
+        configure(&mut config);
+
+        ctx.process(init_registry(&facilitator)).unwrap();
+        ctx.process(create_board(&facilitator, 0, &config)).unwrap();
+
+        Self {
+            facilitator,
+            participants,
+            board: board_pda(&facilitator, 0),
+        }
+    }
+
+    pub fn advance(&self, ctx: &mut TestContext, stage: BoardStage) {
+        ctx.process(advance_stage(&self.board, &self.facilitator, stage))
+            .unwrap();
+    }
+
+    pub fn board_state(&self, ctx: &TestContext) -> RetroBoard {
+        ctx.state(&self.board)
+    }
+}
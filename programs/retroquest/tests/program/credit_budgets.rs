@@ -0,0 +1,117 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardMembership, BoardStage},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board whose two participants start with five and one credits, with a note in group 0
+fn weighted_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |config| {
+        config.voting_credits_per_participant = Some(3);
+        config.credit_budgets = Some(vec![5, 1]);
+    });
+    let author = fixture.participants[0];
+
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    ctx.process(create_note(&fixture.board, 0, &author, "Flaky CI", None))
+        .unwrap();
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    ctx.process(create_group(&fixture.board, 0, &author, "CI"))
+        .unwrap();
+    ctx.process(assign_note(&fixture.board, 0, 0, &author))
+        .unwrap();
+    fixture
+}
+
+fn voting_credits(ctx: &TestContext, fixture: &BoardFixture, participant: usize) -> u8 {
+    let membership: BoardMembership = ctx.state(&membership_pda(
+        &fixture.board,
+        &fixture.participants[participant],
+    ));
+    membership.voting_credits
+}
+
+#[test]
+fn budgets_are_given_per_participant_at_creation() {
+    let mut ctx = TestContext::new();
+    let facilitator = ctx.wallet();
+    let participants = vec![ctx.wallet(), ctx.wallet()];
+    ctx.process(init_registry(&facilitator)).unwrap();
+
+    let mut config = Config::new(participants);
+    config.credit_budgets = Some(vec![5]);
+    let result = ctx.process(create_board(&facilitator, 0, &config));
+    assert_eq!(error_code(result), RetroError::InvalidCreditBudgets as u32);
+
+    let fixture = weighted_board(&mut ctx);
+    assert_eq!(voting_credits(&ctx, &fixture, 0), 5);
+    assert_eq!(voting_credits(&ctx, &fixture, 1), 1);
+
+    // Later arrivals get the board's default
+    let newcomer = ctx.wallet();
+    ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &newcomer,
+    ))
+    .unwrap();
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &newcomer));
+    assert_eq!(membership.voting_credits, 3);
+}
+
+#[test]
+fn each_participant_votes_within_their_own_budget() {
+    let mut ctx = TestContext::new();
+    let fixture = weighted_board(&mut ctx);
+    let [heavy, light] = fixture.participants[..] else {
+        unreachable!()
+    };
+    fixture.advance(&mut ctx, BoardStage::Vote);
+
+    let result = ctx.process(cast_vote(&fixture.board, &light, 0, 2));
+    assert_eq!(error_code(result), RetroError::InsufficientCredits as u32);
+    ctx.process(cast_vote(&fixture.board, &light, 0, 1))
+        .unwrap();
+    ctx.process(cast_vote(&fixture.board, &heavy, 0, 5))
+        .unwrap();
+}
+
+#[test]
+fn the_facilitator_adjusts_budgets_until_voting_starts() {
+    let mut ctx = TestContext::new();
+    let fixture = weighted_board(&mut ctx);
+    let [heavy, light] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(set_credit_budget(&fixture.board, &light, &light, 9));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    ctx.process(set_credit_budget(
+        &fixture.board,
+        &fixture.facilitator,
+        &light,
+        4,
+    ))
+    .unwrap();
+    assert_eq!(voting_credits(&ctx, &fixture, 1), 4);
+
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let result = ctx.process(set_credit_budget(
+        &fixture.board,
+        &fixture.facilitator,
+        &heavy,
+        1,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+
+    ctx.process(cast_vote(&fixture.board, &light, 0, 4))
+        .unwrap();
+}
@@ -0,0 +1,107 @@
+use retroquest::{
+    error::RetroError,
+    state::{ActionItem, ActionItemStatus, BoardStage},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+const NOW: i64 = 1_700_000_000;
+const DUE_AT: i64 = NOW + 7 * 24 * 60 * 60;
+
+/// A board in Discuss with an action item for the first participant, due a week from now,
+/// that the second verifies alone
+fn board_with_due_item(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |_| {});
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture.advance(ctx, BoardStage::Discuss);
+    ctx.warp_to_timestamp(NOW);
+    ctx.process(create_action_item_due_at(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier],
+        1,
+        Some(DUE_AT),
+    ))
+    .unwrap();
+    fixture
+}
+
+#[test]
+fn due_dates_must_be_in_the_future() {
+    let mut ctx = TestContext::new();
+    let fixture = board_with_due_item(&mut ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(create_action_item_due_at(
+        &fixture.board,
+        &fixture.facilitator,
+        1,
+        &owner,
+        &[verifier],
+        1,
+        Some(NOW),
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidDueDate as u32);
+
+    let action_item: ActionItem = ctx.state(&action_item_pda(&fixture.board, 0));
+    assert_eq!(action_item.due_at, Some(DUE_AT));
+}
+
+#[test]
+fn verification_closes_at_the_due_date() {
+    let mut ctx = TestContext::new();
+    let fixture = board_with_due_item(&mut ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+
+    // An item is only overdue once its due date has passed
+    ctx.warp_to_timestamp(DUE_AT);
+    let result = ctx.process(expire_action_item(&fixture.board, 0, &owner));
+    assert_eq!(error_code(result), RetroError::ActionItemNotDue as u32);
+
+    ctx.warp_to_timestamp(DUE_AT + 1);
+    let result = ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::ActionItemPastDue as u32);
+}
+
+#[test]
+fn anyone_can_expire_an_overdue_item() {
+    let mut ctx = TestContext::new();
+    let fixture = board_with_due_item(&mut ctx);
+    let owner = fixture.participants[0];
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    assert_eq!(fixture.board_state(&ctx).pending_action_item_count, 1);
+
+    ctx.warp_to_timestamp(DUE_AT + 1);
+    ctx.process(expire_action_item(&fixture.board, 0, &owner))
+        .unwrap();
+
+    let action_item: ActionItem = ctx.state(&action_item_pda(&fixture.board, 0));
+    assert_eq!(action_item.status, ActionItemStatus::Expired);
+    assert_eq!(fixture.board_state(&ctx).pending_action_item_count, 0);
+
+    let result = ctx.process(expire_action_item(&fixture.board, 0, &owner));
+    assert_eq!(error_code(result), RetroError::ActionItemNotPending as u32);
+}
@@ -0,0 +1,197 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardStage, RetroBoard},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+#[test]
+fn proposed_facilitator_takes_over_on_accepting() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    let successor = fixture.participants[0];
+
+    ctx.process(propose_facilitator(
+        &fixture.board,
+        &fixture.facilitator,
+        &successor,
+        None,
+    ))
+    .unwrap();
+    assert_eq!(
+        fixture.board_state(&ctx).pending_facilitator,
+        Some(successor)
+    );
+
+    ctx.process(accept_facilitator(&fixture.board, &successor))
+        .unwrap();
+    let board = fixture.board_state(&ctx);
+    assert_eq!(board.facilitator, successor);
+    assert_eq!(board.pending_facilitator, None);
+    assert_eq!(board.rent_payer, fixture.facilitator);
+
+    let result = ctx.process(advance_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::WriteNotes,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+    ctx.process(advance_stage(
+        &fixture.board,
+        &successor,
+        BoardStage::WriteNotes,
+    ))
+    .unwrap();
+}
+
+#[test]
+fn only_the_facilitator_can_propose_a_participant() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    let (participant, other) = (fixture.participants[0], fixture.participants[1]);
+    let outsider = ctx.wallet();
+
+    let result = ctx.process(propose_facilitator(
+        &fixture.board,
+        &participant,
+        &other,
+        None,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    let result = ctx.process(propose_facilitator(
+        &fixture.board,
+        &fixture.facilitator,
+        &outsider,
+        None,
+    ));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+}
+
+#[test]
+fn only_the_proposed_participant_can_accept() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    let (successor, other) = (fixture.participants[0], fixture.participants[1]);
+
+    let result = ctx.process(accept_facilitator(&fixture.board, &successor));
+    assert_eq!(
+        error_code(result),
+        RetroError::NotProposedFacilitator as u32
+    );
+
+    ctx.process(propose_facilitator(
+        &fixture.board,
+        &fixture.facilitator,
+        &successor,
+        None,
+    ))
+    .unwrap();
+
+    let result = ctx.process(accept_facilitator(&fixture.board, &other));
+    assert_eq!(
+        error_code(result),
+        RetroError::NotProposedFacilitator as u32
+    );
+
+    // Proposing again replaces the earlier proposal
+    ctx.process(propose_facilitator(
+        &fixture.board,
+        &fixture.facilitator,
+        &other,
+        None,
+    ))
+    .unwrap();
+    let result = ctx.process(accept_facilitator(&fixture.board, &successor));
+    assert_eq!(
+        error_code(result),
+        RetroError::NotProposedFacilitator as u32
+    );
+
+    ctx.process(accept_facilitator(&fixture.board, &other))
+        .unwrap();
+    assert_eq!(fixture.board_state(&ctx).facilitator, other);
+}
+
+#[test]
+fn team_boards_cannot_be_handed_over() {
+    let mut ctx = TestContext::new();
+    let facilitator = ctx.wallet();
+    let participant = ctx.wallet();
+    let team = team_pda(&facilitator, "Platform");
+
+    ctx.process(create_team(&facilitator, "Platform")).unwrap();
+    ctx.process(create_team_board(
+        &team,
+        &facilitator,
+        0,
+        &Config::new(vec![participant]),
+    ))
+    .unwrap();
+    let board = board_pda(&team, 0);
+
+    let result = ctx.process(propose_facilitator(
+        &board,
+        &facilitator,
+        &participant,
+        Some(&team),
+    ));
+    assert_eq!(error_code(result), RetroError::TeamBoardFacilitator as u32);
+
+    let result = ctx.process(accept_facilitator(&board, &participant));
+    assert_eq!(error_code(result), RetroError::TeamBoardFacilitator as u32);
+}
+
+#[test]
+fn both_facilitators_can_follow_up_a_handed_over_board() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |config| {
+        config.stages = Some(vec![BoardStage::Setup, BoardStage::Discuss])
+    });
+    let successor = fixture.participants[0];
+
+    ctx.process(propose_facilitator(
+        &fixture.board,
+        &fixture.facilitator,
+        &successor,
+        None,
+    ))
+    .unwrap();
+    ctx.process(accept_facilitator(&fixture.board, &successor))
+        .unwrap();
+    ctx.process(advance_stage(
+        &fixture.board,
+        &successor,
+        BoardStage::Discuss,
+    ))
+    .unwrap();
+    ctx.process(close_board(&fixture.board, &successor))
+        .unwrap();
+
+    let mut config = Config::new(Vec::new());
+    config.predecessor = Some(fixture.board);
+
+    // The board was created under the original facilitator's registry
+    ctx.process(create_board(&fixture.facilitator, 1, &config))
+        .unwrap();
+    let follow_up: RetroBoard = ctx.state(&board_pda(&fixture.facilitator, 1));
+    assert_eq!(follow_up.predecessor, Some(fixture.board));
+
+    // and is now run by the successor
+    ctx.process(init_registry(&successor)).unwrap();
+    ctx.process(create_board(&successor, 0, &config)).unwrap();
+
+    let outsider = ctx.wallet();
+    ctx.process(init_registry(&outsider)).unwrap();
+    let result = ctx.process(create_board(&outsider, 0, &config));
+    assert_eq!(error_code(result), RetroError::InvalidPredecessor as u32);
+}
@@ -0,0 +1,142 @@
+use retroquest::{
+    error::RetroError,
+    state::{ActionItem, ActionItemStatus, BoardStage, RetroBoard},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board in Discuss whose first participant owns an action item the second verifies
+fn previous_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |_| {});
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture.advance(ctx, BoardStage::Discuss);
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier],
+        1,
+    ))
+    .unwrap();
+    fixture
+}
+
+fn follow_up_config(fixture: &BoardFixture) -> Config {
+    let mut config = Config::new(fixture.participants.clone());
+    config.predecessor = Some(fixture.board);
+    config
+}
+
+#[test]
+fn follow_up_boards_need_a_closed_board_from_the_same_series() {
+    let mut ctx = TestContext::new();
+    let previous = previous_board(&mut ctx);
+    let config = follow_up_config(&previous);
+
+    let result = ctx.process(create_board(&previous.facilitator, 1, &config));
+    assert_eq!(error_code(result), RetroError::InvalidPredecessor as u32);
+
+    ctx.process(close_board(&previous.board, &previous.facilitator))
+        .unwrap();
+
+    // Someone else's board can't be followed up, even once it is closed
+    let stranger = ctx.wallet();
+    ctx.process(init_registry(&stranger)).unwrap();
+    let result = ctx.process(create_board(&stranger, 0, &config));
+    assert_eq!(error_code(result), RetroError::InvalidPredecessor as u32);
+
+    // Reviewing is what a follow-up board is for, so its pipeline has to include it
+    let mut without_review = config.clone();
+    without_review.stages = Some(vec![BoardStage::Setup, BoardStage::WriteNotes]);
+    let result = ctx.process(create_board(&previous.facilitator, 1, &without_review));
+    assert_eq!(error_code(result), RetroError::InvalidStagePipeline as u32);
+
+    ctx.process(create_board(&previous.facilitator, 1, &config))
+        .unwrap();
+    let board: RetroBoard = ctx.state(&board_pda(&previous.facilitator, 1));
+    assert_eq!(board.predecessor, Some(previous.board));
+    assert_eq!(
+        board.stages[..2],
+        [BoardStage::Setup, BoardStage::ReviewPreviousActions]
+    );
+}
+
+#[test]
+fn verifiers_settle_carried_over_action_items_on_the_follow_up() {
+    let mut ctx = TestContext::new();
+    let previous = previous_board(&mut ctx);
+    let [owner, verifier] = previous.participants[..] else {
+        unreachable!()
+    };
+    ctx.process(close_board(&previous.board, &previous.facilitator))
+        .unwrap();
+    ctx.process(create_board(
+        &previous.facilitator,
+        1,
+        &follow_up_config(&previous),
+    ))
+    .unwrap();
+    let board = board_pda(&previous.facilitator, 1);
+
+    let result = ctx.process(review_action_item(
+        &board,
+        &previous.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+
+    ctx.process(advance_stage(
+        &board,
+        &previous.facilitator,
+        BoardStage::ReviewPreviousActions,
+    ))
+    .unwrap();
+
+    // Only the board it follows up can be reviewed
+    let other = BoardFixture::new(&mut ctx, 1, |_| {});
+    let result = ctx.process(review_action_item(
+        &board,
+        &other.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::PredecessorMismatch as u32);
+
+    let result = ctx.process(review_action_item(
+        &board,
+        &previous.board,
+        0,
+        &owner,
+        &owner,
+        true,
+    ));
+    assert_eq!(error_code(result), RetroError::NotAVerifier as u32);
+
+    ctx.process(review_action_item(
+        &board,
+        &previous.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ))
+    .unwrap();
+    let action_item: ActionItem = ctx.state(&action_item_pda(&previous.board, 0));
+    assert_eq!(action_item.status, ActionItemStatus::Completed);
+    let previous_state = previous.board_state(&ctx);
+    assert_eq!(previous_state.pending_action_item_count, 0);
+}
@@ -0,0 +1,263 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardStage, Group, Note},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board in GroupDuplicates with three notes by the first participant: notes 0 and 1
+/// in the first participant's group 0, note 2 in the second participant's group 1
+fn grouped_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |_| {});
+    let [author, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    for note_id in 0..3 {
+        ctx.process(create_note(&fixture.board, note_id, &author, "Idea", None))
+            .unwrap();
+    }
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    ctx.process(create_group(&fixture.board, 0, &author, "CI"))
+        .unwrap();
+    ctx.process(create_group(&fixture.board, 1, &other, "Builds"))
+        .unwrap();
+    for (note_id, group_id) in [(0, 0), (1, 0), (2, 1)] {
+        ctx.process(assign_note(&fixture.board, note_id, group_id, &author))
+            .unwrap();
+    }
+    fixture
+}
+
+fn group(ctx: &TestContext, fixture: &BoardFixture, group_id: u64) -> Group {
+    ctx.state(&group_pda(&fixture.board, group_id))
+}
+
+fn note_group(ctx: &TestContext, fixture: &BoardFixture, note_id: u64) -> Option<u64> {
+    let note: Note = ctx.state(&note_pda(&fixture.board, note_id));
+    note.group_id
+}
+
+#[test]
+fn merging_moves_every_note_and_closes_the_source() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let [author, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(merge_groups(
+        &fixture.board,
+        0,
+        0,
+        &author,
+        &author,
+        &[0, 1],
+    ));
+    assert_eq!(error_code(result), RetroError::SameGroup as u32);
+
+    // Closing group 1 is up to whoever created it
+    let result = ctx.process(merge_groups(&fixture.board, 0, 1, &other, &author, &[2]));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    let result = ctx.process(merge_groups(&fixture.board, 0, 1, &other, &other, &[]));
+    assert_eq!(error_code(result), RetroError::GroupNotesMissing as u32);
+
+    let result = ctx.process(merge_groups(&fixture.board, 0, 1, &author, &other, &[2]));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    let before = ctx.lamports(&other);
+    let rent = ctx.lamports(&group_pda(&fixture.board, 1));
+    ctx.process(merge_groups(&fixture.board, 0, 1, &other, &other, &[2]))
+        .unwrap();
+
+    assert!(ctx.account(&group_pda(&fixture.board, 1)).is_none());
+    assert_eq!(ctx.lamports(&other), before + rent);
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 3);
+    assert_eq!(note_group(&ctx, &fixture, 2), Some(0));
+}
+
+#[test]
+fn splitting_moves_the_chosen_notes_into_a_new_group() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let [author, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(split_group(&fixture.board, 0, 2, &other, "Flaky", &[]));
+    assert_eq!(error_code(result), RetroError::NoNotesSelected as u32);
+
+    let result = ctx.process(split_group(&fixture.board, 0, 2, &other, "Flaky", &[2]));
+    assert_eq!(error_code(result), RetroError::NoteNotInGroup as u32);
+
+    // Any participant can split, and the new group is theirs
+    ctx.process(split_group(&fixture.board, 0, 2, &other, "Flaky", &[1]))
+        .unwrap();
+
+    let new_group = group(&ctx, &fixture, 2);
+    assert_eq!(new_group.title, "Flaky");
+    assert_eq!(new_group.created_by, other);
+    assert_eq!(new_group.note_count, 1);
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 1);
+    assert_eq!(note_group(&ctx, &fixture, 0), Some(0));
+    assert_eq!(note_group(&ctx, &fixture, 1), Some(2));
+    assert_eq!(fixture.board_state(&ctx).group_count, 3);
+
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let result = ctx.process(split_group(&fixture.board, 0, 3, &author, "Late", &[0]));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+    let result = ctx.process(merge_groups(&fixture.board, 0, 2, &other, &other, &[1]));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+}
+
+#[test]
+fn a_note_moves_between_groups_in_one_step() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let other = fixture.participants[1];
+    let outsider = ctx.wallet();
+
+    let result = ctx.process(move_note(&fixture.board, 0, 0, 0, &other));
+    assert_eq!(error_code(result), RetroError::SameGroup as u32);
+
+    let result = ctx.process(move_note(&fixture.board, 2, 0, 1, &other));
+    assert_eq!(error_code(result), RetroError::NoteNotInGroup as u32);
+
+    let result = ctx.process(move_note(&fixture.board, 0, 0, 1, &outsider));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+
+    // Any participant can move any note, not just its author
+    ctx.process(move_note(&fixture.board, 0, 0, 1, &other))
+        .unwrap();
+    assert_eq!(note_group(&ctx, &fixture, 0), Some(1));
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 1);
+    assert_eq!(group(&ctx, &fixture, 1).note_count, 2);
+
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let result = ctx.process(move_note(&fixture.board, 0, 1, 0, &other));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+}
+
+#[test]
+fn groups_count_the_notes_assigned_to_them() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let [author, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 2);
+    assert_eq!(group(&ctx, &fixture, 1).note_count, 1);
+
+    let result = ctx.process(assign_note(&fixture.board, 0, 1, &other));
+    assert_eq!(error_code(result), RetroError::NoteAlreadyGrouped as u32);
+
+    ctx.process(unassign_note(&fixture.board, 2, 1, &other))
+        .unwrap();
+    assert_eq!(group(&ctx, &fixture, 1).note_count, 0);
+    assert_eq!(note_group(&ctx, &fixture, 2), None);
+
+    // Empty groups can't collect votes
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let result = ctx.process(cast_vote(&fixture.board, &author, 1, 1));
+    assert_eq!(error_code(result), RetroError::EmptyGroup as u32);
+    ctx.process(cast_vote(&fixture.board, &author, 0, 1))
+        .unwrap();
+
+    // Back in WriteNotes a grouped note has to leave its group before it can be deleted
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::WriteNotes,
+    ))
+    .unwrap();
+    let result = ctx.process(delete_note(&fixture.board, 0, &author, &author, None));
+    assert_eq!(error_code(result), RetroError::NoteAlreadyGrouped as u32);
+    ctx.process(delete_note(&fixture.board, 2, &author, &author, None))
+        .unwrap();
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 2);
+}
+
+#[test]
+fn notes_from_another_board_cannot_be_grouped() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let elsewhere = grouped_board(&mut ctx);
+    let author = elsewhere.participants[0];
+    ctx.process(unassign_note(&elsewhere.board, 2, 1, &author))
+        .unwrap();
+
+    let mut assign = assign_note(&fixture.board, 2, 1, &fixture.participants[0]);
+    assign.accounts[1].pubkey = note_pda(&elsewhere.board, 2);
+    let result = ctx.process(assign);
+    assert_eq!(error_code(result), RetroError::InvalidPDA as u32);
+
+    let mut unassign = unassign_note(&fixture.board, 0, 0, &fixture.participants[0]);
+    unassign.accounts[1].pubkey = note_pda(&elsewhere.board, 0);
+    let result = ctx.process(unassign);
+    assert_eq!(error_code(result), RetroError::InvalidPDA as u32);
+
+    assert_eq!(group(&ctx, &fixture, 0).note_count, 2);
+    assert_eq!(group(&ctx, &fixture, 1).note_count, 1);
+    assert_eq!(note_group(&ctx, &elsewhere, 2), None);
+}
+
+#[test]
+fn only_empty_groups_can_be_deleted() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let [author, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let facilitator = fixture.facilitator;
+
+    let result = ctx.process(delete_group(&fixture.board, 0, &author, &author));
+    assert_eq!(error_code(result), RetroError::GroupNotEmpty as u32);
+
+    ctx.process(unassign_note(&fixture.board, 2, 1, &author))
+        .unwrap();
+
+    // Participants can only delete the groups they created
+    let result = ctx.process(delete_group(&fixture.board, 1, &other, &author));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    // The facilitator may delete anyone's group, but the rent goes back to its creator
+    let result = ctx.process(delete_group(&fixture.board, 1, &facilitator, &facilitator));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    let before = ctx.lamports(&other);
+    let rent = ctx.lamports(&group_pda(&fixture.board, 1));
+    ctx.process(delete_group(&fixture.board, 1, &other, &facilitator))
+        .unwrap();
+    assert!(ctx.account(&group_pda(&fixture.board, 1)).is_none());
+    assert_eq!(ctx.lamports(&other), before + rent);
+}
+
+#[test]
+fn creators_delete_their_own_groups_while_grouping() {
+    let mut ctx = TestContext::new();
+    let fixture = grouped_board(&mut ctx);
+    let other = fixture.participants[1];
+
+    ctx.process(create_group(&fixture.board, 2, &other, "Typo"))
+        .unwrap();
+    ctx.process(delete_group(&fixture.board, 2, &other, &other))
+        .unwrap();
+    assert!(ctx.account(&group_pda(&fixture.board, 2)).is_none());
+
+    ctx.process(create_group(&fixture.board, 3, &other, "Later"))
+        .unwrap();
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let result = ctx.process(delete_group(&fixture.board, 3, &other, &other));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+}
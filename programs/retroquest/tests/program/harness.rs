@@ -0,0 +1,346 @@
+//! Minimal in-process runtime for driving the program from `cargo test`.
+//!
+//! Accounts are serialized the way the runtime hands them to an entrypoint, so
+//! `AccountInfo::resize` works, and the syscall stubs provide the clock, rent and
+//! the two system program instructions the program invokes.
+
+use std::{cell::Cell, collections::HashMap, mem::size_of, sync::Once};
+
+use borsh::BorshDeserialize;
+use retroquest::solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::{self, ProgramResult, MAX_PERMITTED_DATA_INCREASE},
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    program_stubs::{self, SyscallStubs},
+    pubkey::Pubkey,
+    rent::Rent,
+};
+
+pub const LAMPORTS_PER_WALLET: u64 = 10_000_000_000;
+
+/// The system program's address is all zeroes
+pub const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array([0; 32]);
+
+thread_local! {
+    // Each test runs on its own thread, so its context can move its own clock
+    static SLOT: Cell<u64> = const { Cell::new(0) };
+    static UNIX_TIMESTAMP: Cell<i64> = const { Cell::new(0) };
+}
+
+struct Stubs;
+
+impl SyscallStubs for Stubs {
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            slot: SLOT.get(),
+            unix_timestamp: UNIX_TIMESTAMP.get(),
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+
+    fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+        unsafe { *(var_addr as *mut Rent) = Rent::default() };
+        0
+    }
+
+    fn sol_invoke_signed(
+        &self,
+        instruction: &Instruction,
+        account_infos: &[AccountInfo],
+        signers_seeds: &[&[&[u8]]],
+    ) -> ProgramResult {
+        invoke_system_program(instruction, account_infos, signers_seeds)
+    }
+}
+
+// CreateAccount and Transfer are the only system instructions the program invokes
+fn invoke_system_program(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+    signers_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    assert_eq!(
+        instruction.program_id, SYSTEM_PROGRAM_ID,
+        "unexpected CPI target"
+    );
+
+    let info = |key: &Pubkey| {
+        account_infos
+            .iter()
+            .find(|info| info.key == key)
+            .expect("CPI account missing from account infos")
+    };
+    for meta in &instruction.accounts {
+        let signed_by_program = signers_seeds.iter().any(|seeds| {
+            Pubkey::create_program_address(seeds, &retroquest::ID).ok() == Some(meta.pubkey)
+        });
+        if meta.is_signer && !info(&meta.pubkey).is_signer && !signed_by_program {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    }
+
+    let data = &instruction.data;
+    let tag = u32::from_le_bytes(data[0..4].try_into().unwrap());
+    let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+    let from = info(&instruction.accounts[0].pubkey);
+    let to = info(&instruction.accounts[1].pubkey);
+    if from.lamports() < lamports {
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    match tag {
+        // CreateAccount { lamports, space, owner }
+        0 => {
+            let space = u64::from_le_bytes(data[12..20].try_into().unwrap()) as usize;
+            let owner = Pubkey::try_from(&data[20..52]).unwrap();
+            if to.lamports() > 0 || !to.data_is_empty() || *to.owner != SYSTEM_PROGRAM_ID {
+                return Err(ProgramError::AccountAlreadyInitialized);
+            }
+            **from.lamports.borrow_mut() -= lamports;
+            **to.lamports.borrow_mut() += lamports;
+            to.resize(space)?;
+            to.assign(&owner);
+        }
+        // Transfer { lamports }
+        2 => {
+            **from.lamports.borrow_mut() -= lamports;
+            **to.lamports.borrow_mut() += lamports;
+        }
+        _ => panic!("unexpected system instruction {tag}"),
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestAccount {
+    pub lamports: u64,
+    pub data: Vec<u8>,
+    pub owner: Pubkey,
+}
+
+pub struct TestContext {
+    accounts: HashMap<Pubkey, TestAccount>,
+}
+
+impl TestContext {
+    pub fn new() -> Self {
+        static STUBS: Once = Once::new();
+        STUBS.call_once(|| {
+            program_stubs::set_syscall_stubs(Box::new(Stubs));
+        });
+
+        SLOT.set(0);
+        UNIX_TIMESTAMP.set(0);
+
+        Self {
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Move the clock the program sees forward (or back) to `slot`
+    pub fn warp_to_slot(&mut self, slot: u64) {
+        SLOT.set(slot);
+    }
+
+    /// Move the wall clock the program sees to `unix_timestamp`
+    pub fn warp_to_timestamp(&mut self, unix_timestamp: i64) {
+        UNIX_TIMESTAMP.set(unix_timestamp);
+    }
+
+    /// A funded system account that can sign
+    pub fn wallet(&mut self) -> Pubkey {
+        let key = Pubkey::new_unique();
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports: LAMPORTS_PER_WALLET,
+                data: Vec::new(),
+                owner: SYSTEM_PROGRAM_ID,
+            },
+        );
+        key
+    }
+
+    /// Send lamports to any address, as a system transfer from outside the program would
+    pub fn airdrop(&mut self, key: &Pubkey, lamports: u64) {
+        self.accounts
+            .entry(*key)
+            .or_insert(TestAccount {
+                lamports: 0,
+                data: Vec::new(),
+                owner: SYSTEM_PROGRAM_ID,
+            })
+            .lamports += lamports;
+    }
+
+    /// Put an account in place as if an earlier version of the program had written it
+    pub fn set_account(&mut self, key: Pubkey, data: Vec<u8>) {
+        let lamports = Rent::default().minimum_balance(data.len());
+        self.accounts.insert(
+            key,
+            TestAccount {
+                lamports,
+                data,
+                owner: retroquest::ID,
+            },
+        );
+    }
+
+    pub fn account(&self, key: &Pubkey) -> Option<&TestAccount> {
+        self.accounts.get(key)
+    }
+
+    pub fn lamports(&self, key: &Pubkey) -> u64 {
+        self.accounts.get(key).map_or(0, |account| account.lamports)
+    }
+
+    pub fn state<T: BorshDeserialize>(&self, key: &Pubkey) -> T {
+        let account = self.accounts.get(key).expect("account does not exist");
+        T::deserialize(&mut &account.data[..]).expect("account does not deserialize")
+    }
+
+    /// Run one instruction; account changes are kept only if it succeeds
+    pub fn process(&mut self, instruction: Instruction) -> ProgramResult {
+        let (mut input, keys) = self.serialize_input(&instruction);
+
+        let (program_id, account_infos, data) =
+            unsafe { entrypoint::deserialize(input.as_mut_ptr() as *mut u8) };
+        retroquest::processor::process_instruction(program_id, &account_infos, data)?;
+        drop(account_infos);
+
+        // Read the results back from the input, as the runtime does; the program may have
+        // moved its own data slices while writing through them
+        let bytes: Vec<u8> = input.iter().flat_map(|word| word.to_le_bytes()).collect();
+        let mut lamports_before = 0u128;
+        let mut lamports_after = 0u128;
+        for (key, offset) in keys {
+            let read = |at: usize, len: usize| &bytes[offset + at..offset + at + len];
+            let data_len = u64::from_le_bytes(read(72, 8).try_into().unwrap()) as usize;
+            let after = TestAccount {
+                lamports: u64::from_le_bytes(read(64, 8).try_into().unwrap()),
+                data: read(80, data_len).to_vec(),
+                owner: Pubkey::try_from(read(32, 32)).unwrap(),
+            };
+            let before = self.accounts.get(&key).cloned().unwrap_or(TestAccount {
+                lamports: 0,
+                data: Vec::new(),
+                owner: SYSTEM_PROGRAM_ID,
+            });
+            lamports_before += before.lamports as u128;
+            lamports_after += after.lamports as u128;
+
+            if before != after {
+                assert!(
+                    instruction
+                        .accounts
+                        .iter()
+                        .any(|m| m.pubkey == key && m.is_writable),
+                    "read-only account {key} was modified"
+                );
+            }
+
+            if after.lamports == 0 {
+                self.accounts.remove(&key);
+            } else {
+                self.accounts.insert(key, after);
+            }
+        }
+        assert_eq!(
+            lamports_before, lamports_after,
+            "instruction created or destroyed lamports"
+        );
+
+        Ok(())
+    }
+
+    // Lay the accounts out as the runtime does for a program entrypoint,
+    // and note where each account's key starts
+    fn serialize_input(&self, instruction: &Instruction) -> (Vec<u64>, Vec<(Pubkey, usize)>) {
+        let mut bytes: Vec<u8> = Vec::new();
+        let mut keys: Vec<(Pubkey, usize)> = Vec::new();
+
+        bytes.extend_from_slice(&(instruction.accounts.len() as u64).to_le_bytes());
+        for meta in &instruction.accounts {
+            // A repeated key points back at its first position in the instruction's accounts
+            if keys.iter().any(|(key, _)| *key == meta.pubkey) {
+                let position = instruction
+                    .accounts
+                    .iter()
+                    .position(|m| m.pubkey == meta.pubkey)
+                    .unwrap();
+                bytes.push(position as u8);
+                bytes.extend_from_slice(&[0u8; 7]);
+                continue;
+            }
+
+            // A key listed twice is a signer or writable if any of its entries is
+            let is_signer = instruction
+                .accounts
+                .iter()
+                .any(|m| m.pubkey == meta.pubkey && m.is_signer);
+            let is_writable = instruction
+                .accounts
+                .iter()
+                .any(|m| m.pubkey == meta.pubkey && m.is_writable);
+            let account = self
+                .accounts
+                .get(&meta.pubkey)
+                .cloned()
+                .unwrap_or(TestAccount {
+                    lamports: 0,
+                    data: Vec::new(),
+                    owner: SYSTEM_PROGRAM_ID,
+                });
+            let executable = meta.pubkey == SYSTEM_PROGRAM_ID;
+
+            bytes.push(u8::MAX);
+            bytes.push(is_signer as u8);
+            bytes.push(is_writable as u8);
+            bytes.push(executable as u8);
+            bytes.extend_from_slice(&[0u8; size_of::<u32>()]);
+            keys.push((meta.pubkey, bytes.len()));
+            bytes.extend_from_slice(meta.pubkey.as_ref());
+            bytes.extend_from_slice(account.owner.as_ref());
+            bytes.extend_from_slice(&account.lamports.to_le_bytes());
+            bytes.extend_from_slice(&(account.data.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(&account.data);
+            bytes.resize(bytes.len() + MAX_PERMITTED_DATA_INCREASE, 0);
+            bytes.resize(bytes.len().next_multiple_of(8), 0);
+            bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(instruction.data.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&instruction.data);
+        bytes.extend_from_slice(instruction.program_id.as_ref());
+
+        // Back the input with u64s so account data is aligned as on chain
+        let mut input = vec![0u64; bytes.len().div_ceil(8)];
+        for (word, chunk) in input.iter_mut().zip(bytes.chunks(8)) {
+            let mut padded = [0u8; 8];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            *word = u64::from_le_bytes(padded);
+        }
+        (input, keys)
+    }
+}
+
+pub fn instruction(data: Vec<u8>, accounts: Vec<AccountMeta>) -> Instruction {
+    Instruction {
+        program_id: retroquest::ID,
+        accounts,
+        data,
+    }
+}
+
+/// Custom program error code of a failed instruction
+pub fn error_code(result: ProgramResult) -> u32 {
+    match result {
+        Err(ProgramError::Custom(code)) => code,
+        other => panic!("expected a custom program error, got {other:?}"),
+    }
+}
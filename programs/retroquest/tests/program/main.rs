@@ -0,0 +1,20 @@
+//! Program tests, run in process against the syscall stubs in `harness`.
+
+mod client;
+mod harness;
+
+mod action_items;
+mod credit_budgets;
+mod deadlines;
+mod facilitators;
+mod follow_up;
+mod groups;
+mod memberships;
+mod migration;
+mod notes;
+mod participants;
+mod ranked_voting;
+mod reclaim;
+mod sealed_voting;
+mod timeboxes;
+mod voting;
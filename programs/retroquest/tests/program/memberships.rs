@@ -0,0 +1,87 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardMembership, BoardStage, RetroBoard, MAX_INITIAL_PARTICIPANTS, MAX_PARTICIPANTS},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+#[test]
+fn boards_start_small_and_grow_to_the_participant_cap() {
+    let mut ctx = TestContext::new();
+    let facilitator = ctx.wallet();
+    ctx.process(init_registry(&facilitator)).unwrap();
+
+    // CreateBoard has to fit one transaction, so it only takes the first few participants
+    let too_many: Vec<_> = (0..=MAX_INITIAL_PARTICIPANTS)
+        .map(|_| ctx.wallet())
+        .collect();
+    let result = ctx.process(create_board(&facilitator, 0, &Config::new(too_many)));
+    assert_eq!(
+        error_code(result),
+        RetroError::MaxParticipantsReached as u32
+    );
+
+    let initial: Vec<_> = (0..MAX_INITIAL_PARTICIPANTS)
+        .map(|_| ctx.wallet())
+        .collect();
+    ctx.process(create_board(&facilitator, 0, &Config::new(initial.clone())))
+        .unwrap();
+    let board = board_pda(&facilitator, 0);
+    for participant in &initial {
+        let membership: BoardMembership = ctx.state(&membership_pda(&board, participant));
+        assert_eq!(membership.participant, *participant);
+    }
+
+    for _ in MAX_INITIAL_PARTICIPANTS..MAX_PARTICIPANTS {
+        let participant = ctx.wallet();
+        ctx.process(add_participant(&board, &facilitator, &participant))
+            .unwrap();
+    }
+    let state: RetroBoard = ctx.state(&board);
+    assert_eq!(state.membership_count as usize, MAX_PARTICIPANTS);
+
+    let one_too_many = ctx.wallet();
+    let result = ctx.process(add_participant(&board, &facilitator, &one_too_many));
+    assert_eq!(
+        error_code(result),
+        RetroError::MaxParticipantsReached as u32
+    );
+}
+
+#[test]
+fn each_membership_tracks_its_own_participant() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |config| {
+        config.voting_credits_per_participant = Some(3)
+    });
+    let [voter, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let outsider = ctx.wallet();
+
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    ctx.process(create_note(&fixture.board, 0, &voter, "Flaky CI", None))
+        .unwrap();
+    let result = ctx.process(create_note(&fixture.board, 1, &outsider, "Let me in", None));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+    ctx.process(create_group(&fixture.board, 0, &voter, "CI"))
+        .unwrap();
+    ctx.process(assign_note(&fixture.board, 0, 0, &voter))
+        .unwrap();
+    fixture.advance(&mut ctx, BoardStage::Vote);
+
+    ctx.process(cast_vote(&fixture.board, &voter, 0, 2))
+        .unwrap();
+    let result = ctx.process(cast_vote(&fixture.board, &outsider, 0, 1));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &voter));
+    assert_eq!(membership.credits_spent, 2);
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &other));
+    assert_eq!(membership.credits_spent, 0);
+}
@@ -0,0 +1,683 @@
+use borsh::BorshSerialize;
+use retroquest::{
+    error::RetroError,
+    legacy::*,
+    solana_program::{instruction::AccountMeta, pubkey::Pubkey},
+    state::*,
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+fn legacy_data<T: BorshSerialize>(account: &T, len: usize) -> Vec<u8> {
+    let mut data = account.try_to_vec().unwrap();
+    data.resize(len, 0);
+    data
+}
+
+fn legacy_board(
+    facilitator: Pubkey,
+    allowlist: Vec<Pubkey>,
+    note_count: u64,
+    action_item_count: u64,
+) -> LegacyRetroBoard {
+    LegacyRetroBoard {
+        discriminator: DISCRIMINATOR_RETRO_BOARD,
+        is_initialized: true,
+        facilitator,
+        board_index: 0,
+        stage: BoardStage::Vote,
+        closed: false,
+        categories: vec!["Went well".to_string()],
+        allowlist,
+        voting_credits_per_participant: 5,
+        note_count,
+        group_count: 1,
+        action_item_count,
+        created_at_slot: 10,
+        stage_changed_at_slot: 20,
+        bump: 255,
+    }
+}
+
+fn legacy_membership(
+    board: Pubkey,
+    participant: Pubkey,
+    credits_spent: u8,
+) -> LegacyBoardMembership {
+    LegacyBoardMembership {
+        discriminator: DISCRIMINATOR_BOARD_MEMBERSHIP,
+        is_initialized: true,
+        board,
+        participant,
+        credits_spent,
+        total_score: 3,
+        bump: 254,
+    }
+}
+
+fn legacy_note(board: Pubkey, note_id: u64, author: Pubkey, group_id: Option<u64>) -> LegacyNote {
+    LegacyNote {
+        discriminator: DISCRIMINATOR_NOTE,
+        is_initialized: true,
+        board,
+        note_id,
+        author,
+        category_id: 0,
+        content: "Standups ran long".to_string(),
+        created_at_slot: 12,
+        group_id,
+        bump: 253,
+    }
+}
+
+fn legacy_action_item(
+    board: Pubkey,
+    action_item_id: u64,
+    owner: Pubkey,
+    status: ActionItemStatus,
+) -> LegacyActionItem {
+    LegacyActionItem {
+        discriminator: DISCRIMINATOR_ACTION_ITEM,
+        is_initialized: true,
+        board,
+        action_item_id,
+        description: "Timebox standups".to_string(),
+        owner,
+        verifiers: vec![Pubkey::new_unique()],
+        threshold: 1,
+        approvals: 0,
+        status,
+        created_at_slot: 30,
+        verified_at_slot: None,
+        bump: 252,
+    }
+}
+
+fn writable(keys: &[Pubkey]) -> Vec<AccountMeta> {
+    keys.iter()
+        .map(|key| AccountMeta::new(*key, false))
+        .collect()
+}
+
+fn readonly(keys: &[Pubkey]) -> Vec<AccountMeta> {
+    keys.iter()
+        .map(|key| AccountMeta::new_readonly(*key, false))
+        .collect()
+}
+
+/// A legacy board with two participants, only the first of whom has a membership
+struct LegacyFixture {
+    facilitator: Pubkey,
+    participants: [Pubkey; 2],
+    board: Pubkey,
+}
+
+impl LegacyFixture {
+    fn new(ctx: &mut TestContext, note_count: u64, action_items: &[ActionItemStatus]) -> Self {
+        let facilitator = ctx.wallet();
+        let participants = [ctx.wallet(), ctx.wallet()];
+        let board = board_pda(&facilitator, 0);
+
+        let legacy = legacy_board(
+            facilitator,
+            participants.to_vec(),
+            note_count,
+            action_items.len() as u64,
+        );
+        ctx.set_account(board, legacy_data(&legacy, LegacyRetroBoard::LEN));
+
+        let membership = legacy_membership(board, participants[0], 4);
+        ctx.set_account(
+            membership_pda(&board, &participants[0]),
+            legacy_data(&membership, LegacyBoardMembership::LEN),
+        );
+
+        for (action_item_id, status) in action_items.iter().enumerate() {
+            let item = legacy_action_item(board, action_item_id as u64, participants[0], *status);
+            ctx.set_account(
+                action_item_pda(&board, action_item_id as u64),
+                legacy_data(&item, LegacyActionItem::LEN),
+            );
+        }
+
+        Self {
+            facilitator,
+            participants,
+            board,
+        }
+    }
+
+    fn memberships(&self) -> Vec<Pubkey> {
+        self.participants
+            .iter()
+            .map(|participant| membership_pda(&self.board, participant))
+            .collect()
+    }
+
+    fn migrate_board(&self, ctx: &mut TestContext) {
+        ctx.process(migrate_account(
+            &self.board,
+            &self.facilitator,
+            writable(&self.memberships()),
+        ))
+        .unwrap();
+    }
+
+    /// A legacy group created by the first participant
+    fn add_group(&self, ctx: &mut TestContext, group_id: u64) -> Pubkey {
+        let group = group_pda(&self.board, group_id);
+        let legacy = LegacyGroup {
+            discriminator: DISCRIMINATOR_GROUP,
+            is_initialized: true,
+            board: self.board,
+            group_id,
+            title: "Meetings".to_string(),
+            created_by: self.participants[0],
+            vote_tally: 6,
+            bump: 251,
+        };
+        ctx.set_account(group, legacy_data(&legacy, LegacyGroup::LEN));
+        group
+    }
+
+    /// A legacy note written by the first participant
+    fn add_note(&self, ctx: &mut TestContext, note_id: u64, group_id: Option<u64>) -> Pubkey {
+        let note = note_pda(&self.board, note_id);
+        let legacy = legacy_note(self.board, note_id, self.participants[0], group_id);
+        ctx.set_account(note, legacy_data(&legacy, LegacyNote::LEN));
+        note
+    }
+}
+
+#[test]
+fn board_migration_creates_missing_memberships() {
+    let mut ctx = TestContext::new();
+    let statuses = [
+        ActionItemStatus::Pending,
+        ActionItemStatus::Completed,
+        ActionItemStatus::Pending,
+    ];
+    let fixture = LegacyFixture::new(&mut ctx, 0, &statuses);
+
+    fixture.migrate_board(&mut ctx);
+
+    assert_eq!(
+        ctx.account(&fixture.board).unwrap().data.len(),
+        RetroBoard::MAX_LEN
+    );
+    let board: RetroBoard = ctx.state(&fixture.board);
+    assert_eq!(board.facilitator, fixture.facilitator);
+    assert_eq!(board.stage, BoardStage::Vote);
+    assert_eq!(board.stages, BoardStage::default_pipeline(false, false));
+    assert_eq!(board.voting_mode, VotingMode::Linear);
+    assert_eq!(board.voting_credits_per_participant, 5);
+    assert_eq!(board.membership_count, 2);
+    assert_eq!(board.action_item_count, 3);
+    assert_eq!(board.live_action_item_count, 3);
+    assert_eq!(board.rent_payer, fixture.facilitator);
+    assert_eq!(board.created_at_slot, 10);
+
+    // The existing membership is left for its own migration
+    let memberships = fixture.memberships();
+    assert_eq!(
+        ctx.account(&memberships[0]).unwrap().data.len(),
+        LegacyBoardMembership::LEN
+    );
+
+    let created: BoardMembership = ctx.state(&memberships[1]);
+    assert_eq!(created.participant, fixture.participants[1]);
+    assert_eq!(created.voting_credits, 5);
+    assert_eq!(created.credits_spent, 0);
+    assert_eq!(created.rent_payer, fixture.facilitator);
+}
+
+#[test]
+fn board_migration_checks_the_membership_pdas() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 0, &[]);
+
+    let mut memberships = fixture.memberships();
+    memberships.reverse();
+    let result = ctx.process(migrate_account(
+        &fixture.board,
+        &fixture.facilitator,
+        writable(&memberships),
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidPDA as u32);
+}
+
+#[test]
+fn migrated_accounts_cannot_be_migrated_again() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 0, &[]);
+    let payer = fixture.facilitator;
+
+    fixture.migrate_board(&mut ctx);
+
+    let result = ctx.process(migrate_account(
+        &fixture.board,
+        &payer,
+        writable(&fixture.memberships()),
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::AccountAlreadyMigrated as u32
+    );
+
+    // Memberships created by the board migration are already in the current layout
+    let created = fixture.memberships()[1];
+    let result = ctx.process(migrate_account(
+        &created,
+        &payer,
+        readonly(&[fixture.board]),
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::AccountAlreadyMigrated as u32
+    );
+}
+
+#[test]
+fn boards_created_by_this_version_are_not_migrated() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    let payer = ctx.wallet();
+
+    let memberships = writable(&[membership_pda(&fixture.board, &fixture.participants[0])]);
+    let result = ctx.process(migrate_account(&fixture.board, &payer, memberships));
+    assert_eq!(
+        error_code(result),
+        RetroError::AccountAlreadyMigrated as u32
+    );
+}
+
+#[test]
+fn membership_migration_keeps_spent_credits() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 0, &[]);
+    let payer = fixture.facilitator;
+    let membership = fixture.memberships()[0];
+
+    let result = ctx.process(migrate_account(
+        &membership,
+        &payer,
+        readonly(&[fixture.board]),
+    ));
+    assert_eq!(error_code(result), RetroError::BoardNotMigrated as u32);
+
+    fixture.migrate_board(&mut ctx);
+    ctx.process(migrate_account(
+        &membership,
+        &payer,
+        readonly(&[fixture.board]),
+    ))
+    .unwrap();
+
+    assert_eq!(
+        ctx.account(&membership).unwrap().data.len(),
+        BoardMembership::LEN
+    );
+    let migrated: BoardMembership = ctx.state(&membership);
+    assert_eq!(migrated.participant, fixture.participants[0]);
+    assert_eq!(migrated.voting_credits, 5);
+    assert_eq!(migrated.credits_spent, 4);
+    assert_eq!(migrated.total_score, 3);
+    assert_eq!(migrated.vote_round, 0);
+    assert_eq!(migrated.rent_payer, fixture.facilitator);
+}
+
+#[test]
+fn membership_migration_requires_its_own_board() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 0, &[]);
+    let other = LegacyFixture::new(&mut ctx, 0, &[]);
+
+    fixture.migrate_board(&mut ctx);
+    other.migrate_board(&mut ctx);
+
+    let membership = fixture.memberships()[0];
+    let result = ctx.process(migrate_account(
+        &membership,
+        &fixture.facilitator,
+        readonly(&[other.board]),
+    ));
+    assert_eq!(error_code(result), RetroError::AccountBoardMismatch as u32);
+}
+
+#[test]
+fn notes_count_themselves_into_their_migrated_group() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 3, &[]);
+    let author = fixture.participants[0];
+
+    // Notes 0 and 2 are in the group, note 1 is not
+    let group = fixture.add_group(&mut ctx, 0);
+    let notes = [
+        fixture.add_note(&mut ctx, 0, Some(0)),
+        fixture.add_note(&mut ctx, 1, None),
+        fixture.add_note(&mut ctx, 2, Some(0)),
+    ];
+
+    let result = ctx.process(migrate_account(&group, &author, readonly(&[fixture.board])));
+    assert_eq!(error_code(result), RetroError::BoardNotMigrated as u32);
+    fixture.migrate_board(&mut ctx);
+
+    let result = ctx.process(migrate_account(
+        &notes[0],
+        &author,
+        writable(&[fixture.board, group]),
+    ));
+    assert_eq!(error_code(result), RetroError::GroupNotMigrated as u32);
+
+    ctx.process(migrate_account(&group, &author, readonly(&[fixture.board])))
+        .unwrap();
+    let migrated: Group = ctx.state(&group);
+    assert_eq!(migrated.title, "Meetings");
+    assert_eq!(migrated.vote_tally, 6);
+    assert_eq!(migrated.vote_round, 0);
+    assert_eq!(migrated.note_count, 0);
+    assert_eq!(migrated.rent_payer, author);
+
+    ctx.process(migrate_account(
+        &notes[0],
+        &author,
+        writable(&[fixture.board, group]),
+    ))
+    .unwrap();
+    ctx.process(migrate_account(
+        &notes[1],
+        &author,
+        writable(&[fixture.board]),
+    ))
+    .unwrap();
+    ctx.process(migrate_account(
+        &notes[2],
+        &author,
+        writable(&[fixture.board, group]),
+    ))
+    .unwrap();
+
+    assert_eq!(ctx.state::<Group>(&group).note_count, 2);
+    assert_eq!(ctx.state::<RetroBoard>(&fixture.board).legacy_note_count, 0);
+    let note: Note = ctx.state(&notes[2]);
+    assert_eq!(note.group_id, Some(0));
+    assert_eq!(note.rent_payer, author);
+}
+
+#[test]
+fn notes_of_a_deleted_group_become_ungrouped() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 1, &[]);
+    let other = LegacyFixture::new(&mut ctx, 0, &[]);
+    let author = fixture.participants[0];
+    let note = fixture.add_note(&mut ctx, 0, Some(1));
+    fixture.migrate_board(&mut ctx);
+
+    // The note's group is looked up on its own board
+    let result = ctx.process(migrate_account(
+        &note,
+        &author,
+        writable(&[fixture.board, group_pda(&other.board, 1)]),
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidPDA as u32);
+
+    ctx.process(migrate_account(
+        &note,
+        &author,
+        writable(&[fixture.board, group_pda(&fixture.board, 1)]),
+    ))
+    .unwrap();
+    let migrated: Note = ctx.state(&note);
+    assert_eq!(migrated.group_id, None);
+}
+
+#[test]
+fn standalone_accounts_migrate_without_their_board() {
+    let mut ctx = TestContext::new();
+    let board = Pubkey::new_unique();
+    let author = ctx.wallet();
+
+    let vote_record = vote_record_pda(&board, &author, 1);
+    let legacy = LegacyVoteRecord {
+        discriminator: DISCRIMINATOR_VOTE_RECORD,
+        is_initialized: true,
+        board,
+        participant: author,
+        group_id: 1,
+        credits_spent: 3,
+        bump: 250,
+    };
+    ctx.set_account(vote_record, legacy_data(&legacy, LegacyVoteRecord::LEN));
+    ctx.process(migrate_account(&vote_record, &author, Vec::new()))
+        .unwrap();
+
+    let migrated: VoteRecord = ctx.state(&vote_record);
+    assert_eq!(migrated.votes, 3);
+    assert_eq!(migrated.credits_spent, 3);
+    assert_eq!(migrated.vote_round, 0);
+    assert_eq!(migrated.rent_payer, author);
+
+    let verification_vote = Pubkey::new_unique();
+    let legacy = LegacyVerificationVote {
+        discriminator: DISCRIMINATOR_VERIFICATION_VOTE,
+        is_initialized: true,
+        action_item: Pubkey::new_unique(),
+        verifier: author,
+        approved: true,
+        voted_at_slot: 40,
+        bump: 249,
+    };
+    ctx.set_account(
+        verification_vote,
+        legacy_data(&legacy, LegacyVerificationVote::LEN),
+    );
+    ctx.process(migrate_account(&verification_vote, &author, Vec::new()))
+        .unwrap();
+
+    let migrated: VerificationVote = ctx.state(&verification_vote);
+    assert!(migrated.approved);
+    assert_eq!(migrated.rent_payer, author);
+
+    let result = ctx.process(migrate_account(&vote_record, &author, Vec::new()));
+    assert_eq!(
+        error_code(result),
+        RetroError::AccountAlreadyMigrated as u32
+    );
+}
+
+#[test]
+fn action_items_stay_pending_on_the_board_until_migrated() {
+    let mut ctx = TestContext::new();
+    let statuses = [ActionItemStatus::Pending, ActionItemStatus::Completed];
+    let fixture = LegacyFixture::new(&mut ctx, 0, &statuses);
+    let items = [
+        action_item_pda(&fixture.board, 0),
+        action_item_pda(&fixture.board, 1),
+    ];
+
+    let result = ctx.process(migrate_account(
+        &items[0],
+        &fixture.facilitator,
+        writable(&[fixture.board]),
+    ));
+    assert_eq!(error_code(result), RetroError::BoardNotMigrated as u32);
+
+    fixture.migrate_board(&mut ctx);
+    assert_eq!(
+        ctx.state::<RetroBoard>(&fixture.board)
+            .pending_action_item_count,
+        2
+    );
+
+    ctx.process(migrate_account(
+        &items[0],
+        &fixture.facilitator,
+        writable(&[fixture.board]),
+    ))
+    .unwrap();
+    assert_eq!(
+        ctx.state::<RetroBoard>(&fixture.board)
+            .pending_action_item_count,
+        2
+    );
+
+    ctx.process(migrate_account(
+        &items[1],
+        &fixture.facilitator,
+        writable(&[fixture.board]),
+    ))
+    .unwrap();
+    assert_eq!(
+        ctx.state::<RetroBoard>(&fixture.board)
+            .pending_action_item_count,
+        1
+    );
+
+    let migrated: ActionItem = ctx.state(&items[0]);
+    assert_eq!(migrated.owner, fixture.participants[0]);
+    assert_eq!(migrated.status, ActionItemStatus::Pending);
+    assert_eq!(migrated.rejections, 0);
+    assert_eq!(migrated.due_at, None);
+    assert_eq!(migrated.rent_payer, fixture.facilitator);
+}
+
+#[test]
+fn only_the_rent_payer_can_migrate_an_account() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 1, &[]);
+    let (author, other) = (fixture.participants[0], fixture.participants[1]);
+    let note = fixture.add_note(&mut ctx, 0, None);
+
+    let result = ctx.process(migrate_account(
+        &fixture.board,
+        &author,
+        writable(&fixture.memberships()),
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::MigrationPayerMismatch as u32
+    );
+    fixture.migrate_board(&mut ctx);
+
+    // The facilitator can't top up a note whose rent goes back to its author
+    for payer in [fixture.facilitator, other] {
+        let result = ctx.process(migrate_account(&note, &payer, writable(&[fixture.board])));
+        assert_eq!(
+            error_code(result),
+            RetroError::MigrationPayerMismatch as u32
+        );
+    }
+    ctx.process(migrate_account(&note, &author, writable(&[fixture.board])))
+        .unwrap();
+}
+
+#[test]
+fn migration_tops_up_rent_from_the_payer() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 1, &[]);
+    let payer = fixture.participants[0];
+    let note = fixture.add_note(&mut ctx, 0, None);
+    fixture.migrate_board(&mut ctx);
+
+    let payer_before = ctx.lamports(&payer);
+    let note_before = ctx.lamports(&note);
+
+    ctx.process(migrate_account(&note, &payer, writable(&[fixture.board])))
+        .unwrap();
+
+    let migrated: Note = ctx.state(&note);
+    assert_eq!(ctx.account(&note).unwrap().data.len(), Note::MAX_LEN);
+    assert_eq!(migrated.author, payer);
+    assert_eq!(migrated.author_commitment, None);
+    assert_eq!(migrated.rent_payer, payer);
+
+    let rent = retroquest::solana_program::rent::Rent::default();
+    assert_eq!(ctx.lamports(&note), rent.minimum_balance(Note::MAX_LEN));
+    assert_eq!(
+        payer_before - ctx.lamports(&payer),
+        ctx.lamports(&note) - note_before
+    );
+}
+
+#[test]
+fn migration_needs_a_signing_payer() {
+    let mut ctx = TestContext::new();
+    let payer = ctx.wallet();
+    let (board, author) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+    let note = note_pda(&board, 0);
+    ctx.set_account(
+        note,
+        legacy_data(&legacy_note(board, 0, author, None), LegacyNote::LEN),
+    );
+
+    let mut instruction = migrate_account(&note, &payer, Vec::new());
+    instruction.accounts[1].is_signer = false;
+    let result = ctx.process(instruction);
+    assert_eq!(
+        result,
+        Err(retroquest::solana_program::program_error::ProgramError::MissingRequiredSignature)
+    );
+}
+
+#[test]
+fn groups_wait_for_every_legacy_note_before_votes_or_deletion() {
+    let mut ctx = TestContext::new();
+    let fixture = LegacyFixture::new(&mut ctx, 2, &[]);
+    let [author, other] = fixture.participants;
+    let group = fixture.add_group(&mut ctx, 0);
+    let notes = [
+        fixture.add_note(&mut ctx, 0, Some(0)),
+        fixture.add_note(&mut ctx, 1, Some(0)),
+    ];
+
+    fixture.migrate_board(&mut ctx);
+    ctx.process(migrate_account(&group, &author, readonly(&[fixture.board])))
+        .unwrap();
+    ctx.process(migrate_account(
+        &notes[0],
+        &author,
+        writable(&[fixture.board, group]),
+    ))
+    .unwrap();
+    assert_eq!(ctx.state::<RetroBoard>(&fixture.board).legacy_note_count, 1);
+
+    // The group only counts one of its notes so far, so nothing may treat it as settled
+    let result = ctx.process(cast_vote(&fixture.board, &other, 0, 1));
+    assert_eq!(error_code(result), RetroError::NotesNotMigrated as u32);
+
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::GroupDuplicates,
+    ))
+    .unwrap();
+    ctx.process(create_group(&fixture.board, 1, &other, "Empty"))
+        .unwrap();
+    let result = ctx.process(delete_group(&fixture.board, 1, &other, &other));
+    assert_eq!(error_code(result), RetroError::NotesNotMigrated as u32);
+    let result = ctx.process(merge_groups(&fixture.board, 1, 0, &author, &author, &[0]));
+    assert_eq!(error_code(result), RetroError::NotesNotMigrated as u32);
+
+    ctx.process(migrate_account(
+        &notes[1],
+        &author,
+        writable(&[fixture.board, group]),
+    ))
+    .unwrap();
+    assert_eq!(ctx.state::<Group>(&group).note_count, 2);
+
+    ctx.process(delete_group(&fixture.board, 1, &other, &other))
+        .unwrap();
+    ctx.process(advance_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::Vote,
+    ))
+    .unwrap();
+    ctx.process(cast_vote(&fixture.board, &other, 0, 1))
+        .unwrap();
+}
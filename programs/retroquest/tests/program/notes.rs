@@ -0,0 +1,312 @@
+use retroquest::{
+    error::RetroError,
+    solana_program::pubkey::Pubkey,
+    state::{BoardStage, Note},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+const SALT: [u8; 32] = [7; 32];
+
+fn hidden_author_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |config| config.hide_note_authors = true);
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    fixture
+}
+
+#[test]
+fn hidden_author_notes_store_the_commitment_instead_of_the_author() {
+    let mut ctx = TestContext::new();
+    let fixture = hidden_author_board(&mut ctx);
+    let author = fixture.participants[0];
+
+    let result = ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "No commitment",
+        None,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::InvalidAuthorCommitment as u32
+    );
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ))
+    .unwrap();
+
+    let note: Note = ctx.state(&note_pda(&fixture.board, 0));
+    assert!(note.is_author_hidden());
+    assert_eq!(note.author, Pubkey::default());
+    assert_eq!(note.author_commitment, Some(commitment));
+    assert_eq!(note.rent_payer, Pubkey::default());
+}
+
+#[test]
+fn visible_author_boards_reject_commitments() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let author = fixture.participants[0];
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    let result = ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::InvalidAuthorCommitment as u32
+    );
+
+    ctx.process(create_note(&fixture.board, 0, &author, "Visible", None))
+        .unwrap();
+    let note: Note = ctx.state(&note_pda(&fixture.board, 0));
+    assert_eq!(note.author, author);
+
+    let result = ctx.process(reveal_note_author(&fixture.board, 0, &author, SALT));
+    assert_eq!(error_code(result), RetroError::NoteAuthorNotHidden as u32);
+}
+
+#[test]
+fn only_the_author_with_the_salt_can_reveal_a_hidden_note() {
+    let mut ctx = TestContext::new();
+    let fixture = hidden_author_board(&mut ctx);
+    let (author, other) = (fixture.participants[0], fixture.participants[1]);
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ))
+    .unwrap();
+
+    let result = ctx.process(reveal_note_author(&fixture.board, 0, &author, [8; 32]));
+    assert_eq!(
+        error_code(result),
+        RetroError::AuthorCommitmentMismatch as u32
+    );
+
+    let result = ctx.process(reveal_note_author(&fixture.board, 0, &other, SALT));
+    assert_eq!(
+        error_code(result),
+        RetroError::AuthorCommitmentMismatch as u32
+    );
+
+    ctx.process(reveal_note_author(&fixture.board, 0, &author, SALT))
+        .unwrap();
+    let note: Note = ctx.state(&note_pda(&fixture.board, 0));
+    assert_eq!(note.author, author);
+    assert!(!note.is_author_hidden());
+}
+
+#[test]
+fn notes_require_a_membership() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let outsider = ctx.wallet();
+
+    let result = ctx.process(create_note(&fixture.board, 0, &outsider, "Let me in", None));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+}
+
+#[test]
+fn deleting_a_note_refunds_its_payer() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let (author, other) = (fixture.participants[0], fixture.participants[1]);
+
+    let before = ctx.lamports(&author);
+    ctx.process(create_note(&fixture.board, 0, &author, "Oops", None))
+        .unwrap();
+
+    let result = ctx.process(delete_note(&fixture.board, 0, &author, &other, None));
+    assert_eq!(error_code(result), RetroError::NotNoteAuthor as u32);
+
+    let result = ctx.process(delete_note(&fixture.board, 0, &other, &author, None));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    ctx.process(delete_note(&fixture.board, 0, &author, &author, None))
+        .unwrap();
+    assert!(ctx.account(&note_pda(&fixture.board, 0)).is_none());
+    assert_eq!(ctx.lamports(&author), before);
+}
+
+#[test]
+fn deleting_a_hidden_note_requires_the_salt() {
+    let mut ctx = TestContext::new();
+    let fixture = hidden_author_board(&mut ctx);
+    let author = fixture.participants[0];
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ))
+    .unwrap();
+
+    let result = ctx.process(delete_note(&fixture.board, 0, &author, &author, None));
+    assert_eq!(error_code(result), RetroError::NotNoteAuthor as u32);
+
+    let result = ctx.process(delete_note(
+        &fixture.board,
+        0,
+        &author,
+        &author,
+        Some([8; 32]),
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::AuthorCommitmentMismatch as u32
+    );
+
+    let before = ctx.lamports(&author);
+    let rent = ctx.lamports(&note_pda(&fixture.board, 0));
+    ctx.process(delete_note(&fixture.board, 0, &author, &author, Some(SALT)))
+        .unwrap();
+    assert!(ctx.account(&note_pda(&fixture.board, 0)).is_none());
+    assert_eq!(ctx.lamports(&author), before + rent);
+}
+
+#[test]
+fn revealing_a_hidden_note_records_the_author_as_its_payer() {
+    let mut ctx = TestContext::new();
+    let fixture = hidden_author_board(&mut ctx);
+    let author = fixture.participants[0];
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ))
+    .unwrap();
+    ctx.process(reveal_note_author(&fixture.board, 0, &author, SALT))
+        .unwrap();
+
+    let note: Note = ctx.state(&note_pda(&fixture.board, 0));
+    assert_eq!(note.rent_payer, author);
+
+    // Revealed notes are deleted like any other
+    ctx.process(delete_note(&fixture.board, 0, &author, &author, None))
+        .unwrap();
+}
+
+#[test]
+fn only_the_author_can_reclaim_a_note_that_still_hides_them() {
+    let mut ctx = TestContext::new();
+    let fixture = hidden_author_board(&mut ctx);
+    let author = fixture.participants[0];
+
+    let commitment = Note::author_commitment(&author, &fixture.board, &SALT);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &author,
+        "Hidden",
+        Some(commitment),
+    ))
+    .unwrap();
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+
+    let result = ctx.process(reclaim_note(
+        &fixture.board,
+        0,
+        &fixture.facilitator,
+        &fixture.facilitator,
+        None,
+    ));
+    assert_eq!(error_code(result), RetroError::NotNoteAuthor as u32);
+
+    let result = ctx.process(reclaim_note(&fixture.board, 0, &author, &author, None));
+    assert_eq!(error_code(result), RetroError::NotNoteAuthor as u32);
+
+    let result = ctx.process(reclaim_note(
+        &fixture.board,
+        0,
+        &fixture.facilitator,
+        &author,
+        Some(SALT),
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    let before = ctx.lamports(&author);
+    let rent = ctx.lamports(&note_pda(&fixture.board, 0));
+    ctx.process(reclaim_note(
+        &fixture.board,
+        0,
+        &author,
+        &author,
+        Some(SALT),
+    ))
+    .unwrap();
+    assert_eq!(ctx.lamports(&author), before + rent);
+}
+
+#[test]
+fn notes_cannot_be_deleted_after_writing_ends() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let author = fixture.participants[0];
+
+    ctx.process(create_note(&fixture.board, 0, &author, "Keep me", None))
+        .unwrap();
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+
+    let result = ctx.process(delete_note(&fixture.board, 0, &author, &author, None));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+}
+
+#[test]
+fn grouped_notes_cannot_be_deleted() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let author = fixture.participants[0];
+
+    ctx.process(create_note(&fixture.board, 0, &author, "Grouped", None))
+        .unwrap();
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+    ctx.process(create_group(&fixture.board, 0, &author, "Meetings"))
+        .unwrap();
+    ctx.process(assign_note(&fixture.board, 0, 0, &author))
+        .unwrap();
+
+    // Reverting to WriteNotes keeps the note in its group
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::WriteNotes,
+    ))
+    .unwrap();
+    let result = ctx.process(delete_note(&fixture.board, 0, &author, &author, None));
+    assert_eq!(error_code(result), RetroError::NoteAlreadyGrouped as u32);
+}
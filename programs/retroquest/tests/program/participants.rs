@@ -0,0 +1,153 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardMembership, BoardStage},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+#[test]
+fn the_facilitator_adds_participants_after_creation() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 1, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let newcomer = ctx.wallet();
+
+    let result = ctx.process(add_participant(
+        &fixture.board,
+        &fixture.participants[0],
+        &newcomer,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &newcomer,
+    ))
+    .unwrap();
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &newcomer));
+    assert_eq!(membership.participant, newcomer);
+    assert_eq!(membership.rent_payer, fixture.facilitator);
+    assert_eq!(fixture.board_state(&ctx).membership_count, 2);
+
+    let result = ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &newcomer,
+    ));
+    assert_eq!(error_code(result), RetroError::AlreadyOnAllowlist as u32);
+
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &newcomer,
+        "Late but here",
+        None,
+    ))
+    .unwrap();
+}
+
+#[test]
+fn removed_participants_lose_access_and_refund_their_rent() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    let [leaver, stayer] = fixture.participants[..] else {
+        unreachable!()
+    };
+
+    let result = ctx.process(remove_participant(
+        &fixture.board,
+        &stayer,
+        &leaver,
+        &fixture.facilitator,
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    let result = ctx.process(remove_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &leaver,
+        &leaver,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    let before = ctx.lamports(&fixture.facilitator);
+    let rent = ctx.lamports(&membership_pda(&fixture.board, &leaver));
+    ctx.process(remove_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &leaver,
+        &fixture.facilitator,
+    ))
+    .unwrap();
+    assert_eq!(ctx.lamports(&fixture.facilitator), before + rent);
+    assert_eq!(fixture.board_state(&ctx).membership_count, 1);
+
+    let result = ctx.process(create_note(&fixture.board, 0, &leaver, "Still here?", None));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+    let result = ctx.process(remove_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &leaver,
+        &fixture.facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::NotOnAllowlist as u32);
+
+    // A removed participant can be invited back
+    ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &leaver,
+    ))
+    .unwrap();
+    ctx.process(create_note(&fixture.board, 0, &leaver, "Back again", None))
+        .unwrap();
+}
+
+#[test]
+fn participants_stay_once_voting_starts() {
+    let mut ctx = TestContext::new();
+    let fixture = BoardFixture::new(&mut ctx, 2, |_| {});
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    let participant = fixture.participants[0];
+
+    let result = ctx.process(remove_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &participant,
+        &fixture.facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+
+    // Adding still works until the board closes
+    let newcomer = ctx.wallet();
+    ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &newcomer,
+    ))
+    .unwrap();
+
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    let latecomer = ctx.wallet();
+    let result = ctx.process(add_participant(
+        &fixture.board,
+        &fixture.facilitator,
+        &latecomer,
+    ));
+    assert_eq!(error_code(result), RetroError::BoardClosed as u32);
+}
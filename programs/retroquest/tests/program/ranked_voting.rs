@@ -0,0 +1,218 @@
+use retroquest::{
+    error::RetroError,
+    solana_program::pubkey::Pubkey,
+    state::{BoardStage, Group, VotingMode},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A ranked board in Vote with one note in each of groups 0..3 and an empty group 3.
+/// Participants are sorted, which is the order their ballots are tallied in.
+fn ranked_board(ctx: &mut TestContext, vote_duration_slots: u64) -> BoardFixture {
+    let mut fixture = BoardFixture::new(ctx, 3, |config| {
+        config.voting_mode = VotingMode::Ranked;
+        config.stages = Some(vec![
+            BoardStage::Setup,
+            BoardStage::WriteNotes,
+            BoardStage::GroupDuplicates,
+            BoardStage::Vote,
+            BoardStage::Discuss,
+        ]);
+        config.stage_duration_slots = Some(vec![0, 0, 0, vote_duration_slots, 0]);
+    });
+    fixture.participants.sort();
+    let author = fixture.participants[0];
+
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    for note_id in 0..3 {
+        ctx.process(create_note(
+            &fixture.board,
+            note_id,
+            &author,
+            "Flaky CI",
+            None,
+        ))
+        .unwrap();
+    }
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    for group_id in 0..4 {
+        ctx.process(create_group(&fixture.board, group_id, &author, "CI"))
+            .unwrap();
+    }
+    for note_id in 0..3 {
+        ctx.process(assign_note(&fixture.board, note_id, note_id, &author))
+            .unwrap();
+    }
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture
+}
+
+const RANKINGS: [&[u64]; 3] = [&[0, 1], &[1, 0, 2], &[2]];
+
+fn submit_ballots(ctx: &mut TestContext, fixture: &BoardFixture) -> Vec<(Pubkey, &'static [u64])> {
+    let ballots: Vec<(Pubkey, &[u64])> =
+        fixture.participants.iter().copied().zip(RANKINGS).collect();
+    for (voter, ranking) in &ballots {
+        ctx.process(submit_ballot(&fixture.board, voter, ranking))
+            .unwrap();
+    }
+    ballots
+}
+
+fn tallies(ctx: &TestContext, fixture: &BoardFixture) -> Vec<u64> {
+    (0..4)
+        .map(|group_id| {
+            ctx.state::<Group>(&group_pda(&fixture.board, group_id))
+                .vote_tally
+        })
+        .collect()
+}
+
+#[test]
+fn ballots_are_tallied_in_batches_before_leaving_vote() {
+    let mut ctx = TestContext::new();
+    let fixture = ranked_board(&mut ctx, 0);
+    let ballots = submit_ballots(&mut ctx, &fixture);
+
+    let result = ctx.process(advance_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::Discuss,
+    ));
+    assert_eq!(error_code(result), RetroError::BallotsNotTallied as u32);
+
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &fixture.facilitator,
+        &[0, 1],
+        &[],
+    ))
+    .unwrap();
+    let board = fixture.board_state(&ctx);
+    assert_eq!(board.tallied_group_count, 2);
+    assert_eq!(board.candidate_count, 2);
+
+    // Tallying has started, so the round is closed to ballots
+    let result = ctx.process(submit_ballot(&fixture.board, &ballots[0].0, &[2]));
+    assert_eq!(error_code(result), RetroError::TallyInProgress as u32);
+
+    // Anyone can carry on once the facilitator has started
+    let continuer = fixture.participants[2];
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &continuer,
+        &[2, 3],
+        &ballots[..1],
+    ))
+    .unwrap();
+    let board = fixture.board_state(&ctx);
+    assert_eq!(board.candidate_count, 3);
+    assert_eq!(board.tallied_ballot_count, 1);
+
+    let result = ctx.process(tally_ballots(
+        &fixture.board,
+        &continuer,
+        &[],
+        &ballots[..2],
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidTallyAccounts as u32);
+
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &continuer,
+        &[],
+        &ballots[1..],
+    ))
+    .unwrap();
+    assert!(fixture.board_state(&ctx).ballots_tallied());
+
+    // First place earns one point per candidate, each later place one less
+    assert_eq!(tallies(&ctx, &fixture), vec![5, 5, 4, 0]);
+
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+}
+
+#[test]
+fn only_the_facilitator_can_start_tallying_before_vote_times_out() {
+    let mut ctx = TestContext::new();
+    let fixture = ranked_board(&mut ctx, 100);
+    let ballots = submit_ballots(&mut ctx, &fixture);
+    let participant = fixture.participants[0];
+
+    let result = ctx.process(tally_ballots(
+        &fixture.board,
+        &participant,
+        &[0, 1, 2, 3],
+        &[],
+    ));
+    assert_eq!(
+        error_code(result),
+        RetroError::UnauthorizedFacilitator as u32
+    );
+
+    ctx.warp_to_slot(100);
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(error_code(result), RetroError::BallotsNotTallied as u32);
+
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &participant,
+        &[0, 1, 2, 3],
+        &ballots,
+    ))
+    .unwrap();
+    ctx.process(crank_advance_stage(&fixture.board)).unwrap();
+    assert_eq!(fixture.board_state(&ctx).stage, BoardStage::Discuss);
+}
+
+#[test]
+fn reverting_to_vote_discards_tallied_ballots() {
+    let mut ctx = TestContext::new();
+    let fixture = ranked_board(&mut ctx, 0);
+    let ballots = submit_ballots(&mut ctx, &fixture);
+
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &fixture.facilitator,
+        &[0, 1, 2, 3],
+        &ballots,
+    ))
+    .unwrap();
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::Vote,
+    ))
+    .unwrap();
+
+    let board = fixture.board_state(&ctx);
+    assert_eq!(board.vote_round, 1);
+    assert_eq!(board.ballot_count, 0);
+    assert!(!board.is_tallying());
+    assert_eq!(board.last_tallied_participant, None);
+
+    // The old ballots no longer count, and a fresh one replaces them
+    let (voter, _) = ballots[0];
+    ctx.process(submit_ballot(&fixture.board, &voter, &[2, 1]))
+        .unwrap();
+    let result = ctx.process(tally_ballots(
+        &fixture.board,
+        &fixture.facilitator,
+        &[0, 1, 2, 3],
+        &ballots[1..2],
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidTallyAccounts as u32);
+
+    ctx.process(tally_ballots(
+        &fixture.board,
+        &fixture.facilitator,
+        &[0, 1, 2, 3],
+        &[(voter, &[2, 1])],
+    ))
+    .unwrap();
+    assert_eq!(tallies(&ctx, &fixture), vec![0, 2, 3, 0]);
+}
@@ -0,0 +1,262 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardMembership, BoardStage},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board in Discuss with a note by the first participant and an action item
+/// the second verifies alone
+fn discuss_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |_| {});
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    ctx.process(create_note(&fixture.board, 0, &owner, "Flaky CI", None))
+        .unwrap();
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture.advance(ctx, BoardStage::Discuss);
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier],
+        1,
+    ))
+    .unwrap();
+    fixture
+}
+
+/// The same board, closed with its action item verified
+fn settled_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = discuss_board(ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ))
+    .unwrap();
+    fixture
+}
+
+#[test]
+fn reclaiming_waits_for_the_board_to_close_and_settle() {
+    let mut ctx = TestContext::new();
+    let fixture = discuss_board(&mut ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let facilitator = fixture.facilitator;
+
+    let result = ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &facilitator,
+        &facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::BoardNotClosed as u32);
+
+    ctx.process(close_board(&fixture.board, &facilitator))
+        .unwrap();
+
+    // Closing is not enough while an action item still waits for its verifiers
+    let result = ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &facilitator,
+        &facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::ActionItemsPending as u32);
+    let result = ctx.process(reclaim_note(&fixture.board, 0, &owner, &owner, None));
+    assert_eq!(error_code(result), RetroError::ActionItemsPending as u32);
+
+    ctx.process(cast_verification_vote(
+        &fixture.board,
+        0,
+        &owner,
+        &verifier,
+        true,
+    ))
+    .unwrap();
+
+    let before = ctx.lamports(&facilitator);
+    let rent = ctx.lamports(&membership_pda(&fixture.board, &owner));
+    ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &facilitator,
+        &facilitator,
+    ))
+    .unwrap();
+    assert!(ctx
+        .account(&membership_pda(&fixture.board, &owner))
+        .is_none());
+    assert_eq!(ctx.lamports(&facilitator), before + rent);
+    assert_eq!(fixture.board_state(&ctx).membership_count, 1);
+}
+
+#[test]
+fn rent_only_goes_back_to_whoever_paid_it() {
+    let mut ctx = TestContext::new();
+    let fixture = settled_board(&mut ctx);
+    let [owner, _] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let facilitator = fixture.facilitator;
+
+    // The facilitator paid for the membership, so it cannot be sent to its participant
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &owner));
+    assert_eq!(membership.rent_payer, facilitator);
+    let result = ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &owner,
+        &facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    // Nor can a participant who paid for nothing reclaim it for the facilitator
+    let result = ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &facilitator,
+        &owner,
+    ));
+    assert_eq!(error_code(result), RetroError::UnauthorizedReclaim as u32);
+
+    // The author paid for the note; the facilitator may close it, but only into the author's wallet
+    let result = ctx.process(reclaim_note(
+        &fixture.board,
+        0,
+        &facilitator,
+        &facilitator,
+        None,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+
+    let before = ctx.lamports(&owner);
+    let rent = ctx.lamports(&note_pda(&fixture.board, 0));
+    ctx.process(reclaim_note(&fixture.board, 0, &owner, &facilitator, None))
+        .unwrap();
+    assert_eq!(ctx.lamports(&owner), before + rent);
+
+    let result = ctx.process(reclaim_action_item(&fixture.board, 0, &owner, &facilitator));
+    assert_eq!(error_code(result), RetroError::InvalidRentRecipient as u32);
+}
+
+#[test]
+fn accounts_cannot_be_reclaimed_twice() {
+    let mut ctx = TestContext::new();
+    let fixture = settled_board(&mut ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let facilitator = fixture.facilitator;
+
+    // Memberships and action items update the board's counts, so the board goes last
+    let result = ctx.process(reclaim_board(&fixture.board, &facilitator, &facilitator));
+    assert_eq!(error_code(result), RetroError::BoardHasOpenAccounts as u32);
+
+    for participant in [owner, verifier] {
+        ctx.process(reclaim_membership(
+            &fixture.board,
+            &participant,
+            &facilitator,
+            &facilitator,
+        ))
+        .unwrap();
+    }
+    let result = ctx.process(reclaim_membership(
+        &fixture.board,
+        &owner,
+        &facilitator,
+        &facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidAccountOwner as u32);
+
+    ctx.process(reclaim_action_item(
+        &fixture.board,
+        0,
+        &facilitator,
+        &facilitator,
+    ))
+    .unwrap();
+    let result = ctx.process(reclaim_action_item(
+        &fixture.board,
+        0,
+        &facilitator,
+        &facilitator,
+    ));
+    assert_eq!(error_code(result), RetroError::InvalidAccountOwner as u32);
+
+    ctx.process(reclaim_board(&fixture.board, &facilitator, &facilitator))
+        .unwrap();
+    assert!(ctx.account(&fixture.board).is_none());
+    let result = ctx.process(reclaim_board(&fixture.board, &facilitator, &facilitator));
+    assert_eq!(error_code(result), RetroError::AccountNotInitialized as u32);
+
+    // Once the board is gone only the payer can reclaim what is left, and only once
+    let result = ctx.process(reclaim_note(&fixture.board, 0, &owner, &facilitator, None));
+    assert_eq!(error_code(result), RetroError::UnauthorizedReclaim as u32);
+    ctx.process(reclaim_note(&fixture.board, 0, &owner, &owner, None))
+        .unwrap();
+    let result = ctx.process(reclaim_note(&fixture.board, 0, &owner, &owner, None));
+    assert_eq!(error_code(result), RetroError::InvalidAccountOwner as u32);
+}
+
+#[test]
+fn funding_a_reclaimed_address_does_not_strand_what_is_left() {
+    let mut ctx = TestContext::new();
+    let fixture = settled_board(&mut ctx);
+    let [owner, verifier] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let facilitator = fixture.facilitator;
+
+    ctx.process(reclaim_action_item(
+        &fixture.board,
+        0,
+        &facilitator,
+        &facilitator,
+    ))
+    .unwrap();
+    for participant in [owner, verifier] {
+        ctx.process(reclaim_membership(
+            &fixture.board,
+            &participant,
+            &facilitator,
+            &facilitator,
+        ))
+        .unwrap();
+    }
+    ctx.process(reclaim_board(&fixture.board, &facilitator, &facilitator))
+        .unwrap();
+
+    // Anyone can send a lamport to a closed address, which leaves it with the system program
+    ctx.airdrop(&action_item_pda(&fixture.board, 0), 1);
+    ctx.airdrop(&fixture.board, 1);
+
+    ctx.process(reclaim_verification_vote(
+        &fixture.board,
+        0,
+        &verifier,
+        &verifier,
+        &verifier,
+    ))
+    .unwrap();
+    ctx.process(reclaim_note(&fixture.board, 0, &owner, &owner, None))
+        .unwrap();
+}
@@ -0,0 +1,148 @@
+use retroquest::{
+    error::RetroError,
+    solana_program::pubkey::Pubkey,
+    state::{BoardMembership, BoardStage, Group, VoteAllocation, VoteCommitment, VotingMode},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+const SALT: [u8; 32] = [3; 32];
+
+/// A sealed board in Vote with three credits per participant and a note in groups 0 and 1
+fn sealed_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |config| {
+        config.voting_mode = VotingMode::Sealed;
+        config.voting_credits_per_participant = Some(3);
+    });
+    let author = fixture.participants[0];
+
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    for note_id in 0..2 {
+        ctx.process(create_note(&fixture.board, note_id, &author, "Idea", None))
+            .unwrap();
+    }
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    for group_id in 0..2 {
+        ctx.process(create_group(&fixture.board, group_id, &author, "Theme"))
+            .unwrap();
+        ctx.process(assign_note(&fixture.board, group_id, group_id, &author))
+            .unwrap();
+    }
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture
+}
+
+fn allocations(votes: &[(u64, u8)]) -> Vec<VoteAllocation> {
+    votes
+        .iter()
+        .map(|&(group_id, votes)| VoteAllocation { group_id, votes })
+        .collect()
+}
+
+fn commitment(fixture: &BoardFixture, voter: &Pubkey, allocations: &[VoteAllocation]) -> [u8; 32] {
+    VoteCommitment::commitment(voter, &fixture.board, allocations, &SALT).unwrap()
+}
+
+fn tally(ctx: &TestContext, fixture: &BoardFixture, group_id: u64) -> u64 {
+    let group: Group = ctx.state(&group_pda(&fixture.board, group_id));
+    group.vote_tally
+}
+
+#[test]
+fn votes_stay_hidden_until_they_are_revealed() {
+    let mut ctx = TestContext::new();
+    let fixture = sealed_board(&mut ctx);
+    let [voter, other] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let votes = allocations(&[(0, 2), (1, 1)]);
+
+    // Sealed boards take commitments instead of live votes
+    let result = ctx.process(cast_vote(&fixture.board, &voter, 0, 1));
+    assert_eq!(error_code(result), RetroError::InvalidVotingMode as u32);
+
+    ctx.process(commit_votes(
+        &fixture.board,
+        &voter,
+        commitment(&fixture, &voter, &votes),
+    ))
+    .unwrap();
+    assert_eq!(tally(&ctx, &fixture, 0), 0);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &voter, &votes, SALT));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+
+    fixture.advance(&mut ctx, BoardStage::RevealVotes);
+    let result = ctx.process(commit_votes(&fixture.board, &other, [1; 32]));
+    assert_eq!(error_code(result), RetroError::InvalidStage as u32);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &voter, &votes, [4; 32]));
+    assert_eq!(
+        error_code(result),
+        RetroError::VoteCommitmentMismatch as u32
+    );
+
+    ctx.process(reveal_votes(&fixture.board, &voter, &votes, SALT))
+        .unwrap();
+    assert_eq!(tally(&ctx, &fixture, 0), 2);
+    assert_eq!(tally(&ctx, &fixture, 1), 1);
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &voter));
+    assert_eq!(membership.credits_spent, 3);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &voter, &votes, SALT));
+    assert_eq!(error_code(result), RetroError::VotesAlreadyRevealed as u32);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &other, &votes, SALT));
+    assert_eq!(error_code(result), RetroError::NoVoteCommitment as u32);
+}
+
+#[test]
+fn only_the_last_commitment_counts_and_it_must_fit_the_budget() {
+    let mut ctx = TestContext::new();
+    let fixture = sealed_board(&mut ctx);
+    let [voter, greedy] = fixture.participants[..] else {
+        unreachable!()
+    };
+    let first = allocations(&[(0, 3)]);
+    let second = allocations(&[(1, 3)]);
+    let too_many = allocations(&[(0, 2), (1, 2)]);
+
+    ctx.process(commit_votes(
+        &fixture.board,
+        &voter,
+        commitment(&fixture, &voter, &first),
+    ))
+    .unwrap();
+    ctx.process(commit_votes(
+        &fixture.board,
+        &voter,
+        commitment(&fixture, &voter, &second),
+    ))
+    .unwrap();
+
+    // The budget can only be checked once the allocation is opened
+    ctx.process(commit_votes(
+        &fixture.board,
+        &greedy,
+        commitment(&fixture, &greedy, &too_many),
+    ))
+    .unwrap();
+
+    fixture.advance(&mut ctx, BoardStage::RevealVotes);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &voter, &first, SALT));
+    assert_eq!(
+        error_code(result),
+        RetroError::VoteCommitmentMismatch as u32
+    );
+    ctx.process(reveal_votes(&fixture.board, &voter, &second, SALT))
+        .unwrap();
+    assert_eq!(tally(&ctx, &fixture, 0), 0);
+    assert_eq!(tally(&ctx, &fixture, 1), 3);
+
+    let result = ctx.process(reveal_votes(&fixture.board, &greedy, &too_many, SALT));
+    assert_eq!(error_code(result), RetroError::InsufficientCredits as u32);
+}
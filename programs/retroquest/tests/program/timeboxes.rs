@@ -0,0 +1,89 @@
+use retroquest::{error::RetroError, state::BoardStage};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// WriteNotes and Vote run for 100 slots each; GroupDuplicates waits for the facilitator
+fn timeboxed_board(ctx: &mut TestContext) -> BoardFixture {
+    BoardFixture::new(ctx, 1, |config| {
+        config.stage_duration_slots = Some(vec![0, 100, 0, 100, 0]);
+    })
+}
+
+#[test]
+fn durations_must_cover_the_whole_pipeline() {
+    let mut ctx = TestContext::new();
+    let facilitator = ctx.wallet();
+    ctx.process(init_registry(&facilitator)).unwrap();
+
+    let mut config = Config::new(vec![]);
+    config.stage_duration_slots = Some(vec![0, 100]);
+    let result = ctx.process(create_board(&facilitator, 0, &config));
+    assert_eq!(error_code(result), RetroError::InvalidStageDurations as u32);
+}
+
+#[test]
+fn anyone_can_crank_a_stage_once_its_timebox_runs_out() {
+    let mut ctx = TestContext::new();
+    let fixture = timeboxed_board(&mut ctx);
+
+    // Setup has no timebox, so only the facilitator moves the board on
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(
+        error_code(result),
+        RetroError::StageTimeboxNotElapsed as u32
+    );
+
+    ctx.warp_to_slot(10);
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+
+    ctx.warp_to_slot(109);
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(
+        error_code(result),
+        RetroError::StageTimeboxNotElapsed as u32
+    );
+
+    ctx.warp_to_slot(110);
+    ctx.process(crank_advance_stage(&fixture.board)).unwrap();
+    let board = fixture.board_state(&ctx);
+    assert_eq!(board.stage, BoardStage::GroupDuplicates);
+    assert_eq!(board.stage_changed_at_slot, 110);
+
+    // GroupDuplicates is untimed, however long it takes
+    ctx.warp_to_slot(10_000);
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(
+        error_code(result),
+        RetroError::StageTimeboxNotElapsed as u32
+    );
+}
+
+#[test]
+fn the_facilitator_can_still_advance_before_the_timebox_ends() {
+    let mut ctx = TestContext::new();
+    let fixture = timeboxed_board(&mut ctx);
+    fixture.advance(&mut ctx, BoardStage::WriteNotes);
+    fixture.advance(&mut ctx, BoardStage::GroupDuplicates);
+    fixture.advance(&mut ctx, BoardStage::Vote);
+
+    // The timebox restarts with each stage
+    ctx.warp_to_slot(50);
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    assert_eq!(fixture.board_state(&ctx).stage_changed_at_slot, 50);
+
+    // There is nothing after the last stage to crank into
+    ctx.warp_to_slot(1_000);
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(
+        error_code(result),
+        RetroError::InvalidStageTransition as u32
+    );
+
+    ctx.process(close_board(&fixture.board, &fixture.facilitator))
+        .unwrap();
+    let result = ctx.process(crank_advance_stage(&fixture.board));
+    assert_eq!(error_code(result), RetroError::BoardClosed as u32);
+}
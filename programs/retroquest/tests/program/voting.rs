@@ -0,0 +1,129 @@
+use retroquest::{
+    error::RetroError,
+    state::{BoardMembership, BoardStage, Group, VoteRecord},
+};
+
+use crate::{
+    client::*,
+    harness::{error_code, TestContext},
+};
+
+/// A board in Vote with one note in group 0 and three credits per participant
+fn voting_board(ctx: &mut TestContext) -> BoardFixture {
+    let fixture = BoardFixture::new(ctx, 2, |config| {
+        config.voting_credits_per_participant = Some(3)
+    });
+    let participant = fixture.participants[0];
+
+    fixture.advance(ctx, BoardStage::WriteNotes);
+    ctx.process(create_note(
+        &fixture.board,
+        0,
+        &participant,
+        "Flaky CI",
+        None,
+    ))
+    .unwrap();
+    fixture.advance(ctx, BoardStage::GroupDuplicates);
+    ctx.process(create_group(&fixture.board, 0, &participant, "CI"))
+        .unwrap();
+    ctx.process(assign_note(&fixture.board, 0, 0, &participant))
+        .unwrap();
+    fixture.advance(ctx, BoardStage::Vote);
+    fixture
+}
+
+#[test]
+fn reverting_before_vote_discards_the_round_lazily() {
+    let mut ctx = TestContext::new();
+    let fixture = voting_board(&mut ctx);
+    let voter = fixture.participants[0];
+
+    ctx.process(cast_vote(&fixture.board, &voter, 0, 3))
+        .unwrap();
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::GroupDuplicates,
+    ))
+    .unwrap();
+    assert_eq!(fixture.board_state(&ctx).vote_round, 1);
+
+    // Nothing was touched yet, so the old round is still stored as is
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &voter));
+    assert_eq!(membership.credits_spent, 3);
+    assert_eq!(membership.vote_round, 0);
+    let group: Group = ctx.state(&group_pda(&fixture.board, 0));
+    assert_eq!(group.vote_tally, 3);
+    assert_eq!(group.vote_round, 0);
+
+    // The next vote resets the membership, group and vote record before counting
+    fixture.advance(&mut ctx, BoardStage::Vote);
+    ctx.process(cast_vote(&fixture.board, &voter, 0, 1))
+        .unwrap();
+
+    let membership: BoardMembership = ctx.state(&membership_pda(&fixture.board, &voter));
+    assert_eq!(membership.credits_spent, 1);
+    assert_eq!(membership.vote_round, 1);
+    let group: Group = ctx.state(&group_pda(&fixture.board, 0));
+    assert_eq!(group.vote_tally, 1);
+    assert_eq!(group.vote_round, 1);
+    let vote_record: VoteRecord = ctx.state(&vote_record_pda(&fixture.board, &voter, 0));
+    assert_eq!(vote_record.votes, 1);
+    assert_eq!(vote_record.credits_spent, 1);
+    assert_eq!(vote_record.vote_round, 1);
+}
+
+#[test]
+fn reverting_from_discuss_to_vote_keeps_the_round() {
+    let mut ctx = TestContext::new();
+    let fixture = voting_board(&mut ctx);
+    let voter = fixture.participants[0];
+
+    ctx.process(cast_vote(&fixture.board, &voter, 0, 2))
+        .unwrap();
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::Vote,
+    ))
+    .unwrap();
+    assert_eq!(fixture.board_state(&ctx).vote_round, 0);
+
+    // Credits from before the revert still count against the budget
+    let result = ctx.process(cast_vote(&fixture.board, &voter, 0, 2));
+    assert_eq!(error_code(result), RetroError::InsufficientCredits as u32);
+
+    ctx.process(cast_vote(&fixture.board, &voter, 0, 1))
+        .unwrap();
+    let group: Group = ctx.state(&group_pda(&fixture.board, 0));
+    assert_eq!(group.vote_tally, 3);
+}
+
+#[test]
+fn reverting_out_of_discuss_is_blocked_once_action_items_exist() {
+    let mut ctx = TestContext::new();
+    let fixture = voting_board(&mut ctx);
+    let owner = fixture.participants[0];
+    let verifier = fixture.participants[1];
+
+    fixture.advance(&mut ctx, BoardStage::Discuss);
+    ctx.process(create_action_item(
+        &fixture.board,
+        &fixture.facilitator,
+        0,
+        &owner,
+        &[verifier],
+        1,
+    ))
+    .unwrap();
+
+    let result = ctx.process(revert_stage(
+        &fixture.board,
+        &fixture.facilitator,
+        BoardStage::Vote,
+    ));
+    assert_eq!(error_code(result), RetroError::ActionItemsExist as u32);
+    assert_eq!(fixture.board_state(&ctx).stage, BoardStage::Discuss);
+}
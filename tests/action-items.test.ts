@@ -1,746 +1,395 @@
 import { describe, it, expect } from "vitest";
-import { start } from "solana-bankrun";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { ProgramTestContext } from "solana-bankrun";
 import {
-  PublicKey,
-  Transaction,
-  Keypair,
-  LAMPORTS_PER_SOL,
-} from "@solana/web3.js";
-import {
-  findTeamRegistryPda,
-  findSessionPda,
-  findParticipantEntryPda,
   findActionItemPda,
   findVerificationVotePda,
+  findParticipantIdentityPda,
+  findReputationPda,
 } from "./helpers/pda";
 import {
-  createInitTeamRegistryInstruction,
-  createCreateSessionInstruction,
-  createAdvanceStageInstruction,
-  createCloseSessionInstruction,
+  createCloseBoardInstruction,
   createCreateActionItemInstruction,
   createCastVerificationVoteInstruction,
-  SessionStage,
+  createCreateIdentityInstruction,
+  createInitReputationInstruction,
+  BoardStage,
 } from "./helpers/instructions";
-
-// Program ID (must match lib.rs)
-const PROGRAM_ID = new PublicKey(
-  "CZ1xaAyDaXa5GyWPHCytfcJjnmJhuFnVeHJLrYiijVLx"
-);
+import {
+  decodeBoard,
+  decodeMembership,
+  decodeActionItem,
+  decodeReputation,
+  ActionItemStatus,
+} from "./helpers/accounts";
+import {
+  PROGRAM_ID,
+  RetroError,
+  startProgram,
+  send,
+  expectProgramError,
+  fundedKeypair,
+  accountData,
+  membershipOf,
+  createBoard,
+  advanceTo,
+  BoardFixture,
+} from "./helpers/bankrun";
+
+const TO_DISCUSS = [
+  BoardStage.WriteNotes,
+  BoardStage.GroupDuplicates,
+  BoardStage.Vote,
+  BoardStage.Discuss,
+];
+
+// A board with an owner and two verifiers on its allowlist
+async function setupBoard(context: ProgramTestContext) {
+  const owner = await fundedKeypair(context);
+  const verifier1 = await fundedKeypair(context);
+  const verifier2 = await fundedKeypair(context);
+  const fixture = await createBoard(context, [
+    owner.publicKey,
+    verifier1.publicKey,
+    verifier2.publicKey,
+  ]);
+  return { fixture, owner, verifier1, verifier2 };
+}
+
+function createActionItem(
+  context: ProgramTestContext,
+  fixture: BoardFixture,
+  signer: Keypair,
+  owner: PublicKey,
+  verifiers: PublicKey[],
+  threshold: number
+): Promise<void> {
+  const [actionItem] = findActionItemPda(fixture.board, 0n, PROGRAM_ID);
+  return send(
+    context,
+    [signer],
+    createCreateActionItemInstruction(
+      fixture.board,
+      actionItem,
+      signer.publicKey,
+      null,
+      "Reduce meeting frequency",
+      owner,
+      verifiers,
+      threshold,
+      null,
+      membershipOf(fixture.board, owner),
+      verifiers.map((verifier) => membershipOf(fixture.board, verifier)),
+      PROGRAM_ID
+    )
+  );
+}
+
+function closeBoard(
+  context: ProgramTestContext,
+  fixture: BoardFixture
+): Promise<void> {
+  return send(
+    context,
+    [fixture.facilitator],
+    createCloseBoardInstruction(
+      fixture.board,
+      fixture.facilitator.publicKey,
+      null,
+      PROGRAM_ID
+    )
+  );
+}
+
+function verify(
+  context: ProgramTestContext,
+  fixture: BoardFixture,
+  verifier: Keypair,
+  owner: PublicKey,
+  approved: boolean,
+  ownerReputation: PublicKey = findReputationPda(owner, PROGRAM_ID)[0]
+): Promise<void> {
+  const [actionItem] = findActionItemPda(fixture.board, 0n, PROGRAM_ID);
+  const [verificationVote] = findVerificationVotePda(
+    actionItem,
+    verifier.publicKey,
+    PROGRAM_ID
+  );
+  return send(
+    context,
+    [verifier],
+    createCastVerificationVoteInstruction(
+      fixture.board,
+      actionItem,
+      verificationVote,
+      membershipOf(fixture.board, owner),
+      ownerReputation,
+      verifier.publicKey,
+      0n,
+      approved,
+      PROGRAM_ID
+    )
+  );
+}
 
 describe("Action Items", () => {
   describe("Happy Path", () => {
     it("creates an action item successfully", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      // Create participants
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-      const participant3 = Keypair.generate();
-
-      // Fund participants
-      await client.requestAirdrop(participant1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant2.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant3.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Initialize team registry
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      // Create session with 3 participants
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const categories = ["What went well", "What to improve", "Action items"];
-      const allowlist = [participant1.publicKey, participant2.publicKey, participant3.publicKey];
-
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          categories,
-          allowlist,
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance through stages to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Create action item
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Reduce meeting frequency",
-          participant1.publicKey, // owner
-          [participant2.publicKey, participant3.publicKey], // verifiers
-          2, // threshold: both must approve
-          PROGRAM_ID
-        )
-      );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-      await client.processTransaction(createActionItemTx);
-
-      // Verify action item was created
-      const actionItemAccount = await client.getAccount(actionItem);
-      expect(actionItemAccount).not.toBeNull();
+      const context = await startProgram();
+      const { fixture, owner, verifier1, verifier2 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey, verifier2.publicKey],
+        2
+      );
+
+      const [actionItem] = findActionItemPda(fixture.board, 0n, PROGRAM_ID);
+      const item = decodeActionItem(await accountData(context, actionItem));
+      expect(item.status).toBe(ActionItemStatus.Pending);
+      expect(item.owner.equals(owner.publicKey)).toBe(true);
+      expect(item.verifiers.length).toBe(2);
+
+      const boardState = decodeBoard(await accountData(context, fixture.board));
+      expect(boardState.actionItemCount).toBe(1n);
+      expect(boardState.pendingActionItemCount).toBe(1n);
     });
 
-    it("completes full verification flow", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      // Setup participants
-      const owner = Keypair.generate();
-      const verifier1 = Keypair.generate();
-      const verifier2 = Keypair.generate();
-
-      await client.requestAirdrop(owner.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(verifier1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(verifier2.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Initialize registry
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      // Create session
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const allowlist = [owner.publicKey, verifier1.publicKey, verifier2.publicKey];
-
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          allowlist,
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss stage
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Create action item with threshold 2
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Implement code reviews",
-          owner.publicKey,
-          [verifier1.publicKey, verifier2.publicKey],
-          2, // both must approve
-          PROGRAM_ID
-        )
-      );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-      await client.processTransaction(createActionItemTx);
-
-      // Close the session (required before verification)
-      const closeTx = new Transaction().add(
-        createCloseSessionInstruction(session, payer.publicKey, PROGRAM_ID)
+    it("completes full verification flow and records the owner's reputation", async () => {
+      const context = await startProgram();
+      const { fixture, owner, verifier1, verifier2 } = await setupBoard(context);
+
+      // The owner opts into cross-board reputation
+      const [identity] = findParticipantIdentityPda(owner.publicKey, PROGRAM_ID);
+      const [reputation] = findReputationPda(owner.publicKey, PROGRAM_ID);
+      await send(
+        context,
+        [owner],
+        createCreateIdentityInstruction(identity, owner.publicKey, "owner_one", PROGRAM_ID),
+        createInitReputationInstruction(reputation, identity, owner.publicKey, PROGRAM_ID)
+      );
+
+      await advanceTo(context, fixture, TO_DISCUSS);
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey, verifier2.publicKey],
+        2
+      );
+      await closeBoard(context, fixture);
+
+      await verify(context, fixture, verifier1, owner.publicKey, true);
+      const [actionItem] = findActionItemPda(fixture.board, 0n, PROGRAM_ID);
+      expect(decodeActionItem(await accountData(context, actionItem)).status).toBe(
+        ActionItemStatus.Pending
+      );
+
+      // The second approval meets the threshold
+      await verify(context, fixture, verifier2, owner.publicKey, true);
+      const item = decodeActionItem(await accountData(context, actionItem));
+      expect(item.status).toBe(ActionItemStatus.Completed);
+      expect(item.approvals).toBe(2);
+
+      const membership = decodeMembership(
+        await accountData(context, membershipOf(fixture.board, owner.publicKey))
+      );
+      expect(membership.totalScore).toBe(1n);
+      expect(decodeReputation(await accountData(context, reputation)).completed).toBe(1n);
+      expect(
+        decodeBoard(await accountData(context, fixture.board)).pendingActionItemCount
+      ).toBe(0n);
+    });
+
+    it("rejects the item once the threshold can no longer be met", async () => {
+      const context = await startProgram();
+      const { fixture, owner, verifier1, verifier2 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey, verifier2.publicKey],
+        2
       );
-      closeTx.recentBlockhash = context.lastBlockhash;
-      closeTx.feePayer = payer.publicKey;
-      closeTx.sign(payer);
-      await client.processTransaction(closeTx);
-
-      // Verifier 1 approves
-      const [vote1] = findVerificationVotePda(actionItem, verifier1.publicKey, PROGRAM_ID);
-      const [ownerMembership] = findParticipantEntryPda(session, owner.publicKey, PROGRAM_ID);
-
-      const vote1Tx = new Transaction().add(
-        createCastVerificationVoteInstruction(
-          session,
-          actionItem,
-          vote1,
-          ownerMembership,
-          verifier1.publicKey,
-          0n,
-          true, // approved
-          PROGRAM_ID
-        )
+      await closeBoard(context, fixture);
+
+      await verify(context, fixture, verifier1, owner.publicKey, false);
+      const [actionItem] = findActionItemPda(fixture.board, 0n, PROGRAM_ID);
+      const item = decodeActionItem(await accountData(context, actionItem));
+      expect(item.status).toBe(ActionItemStatus.Rejected);
+      expect(item.rejections).toBe(1);
+
+      const membership = decodeMembership(
+        await accountData(context, membershipOf(fixture.board, owner.publicKey))
       );
-      vote1Tx.recentBlockhash = context.lastBlockhash;
-      vote1Tx.feePayer = verifier1.publicKey;
-      vote1Tx.sign(verifier1);
-      await client.processTransaction(vote1Tx);
-
-      // Verifier 2 approves (should complete the action item)
-      const [vote2] = findVerificationVotePda(actionItem, verifier2.publicKey, PROGRAM_ID);
-
-      const vote2Tx = new Transaction().add(
-        createCastVerificationVoteInstruction(
-          session,
-          actionItem,
-          vote2,
-          ownerMembership,
-          verifier2.publicKey,
-          0n,
-          true, // approved
-          PROGRAM_ID
-        )
+      expect(membership.rejectedCount).toBe(1n);
+
+      await expectProgramError(
+        verify(context, fixture, verifier2, owner.publicKey, true),
+        RetroError.ActionItemNotPending
       );
-      vote2Tx.recentBlockhash = context.lastBlockhash;
-      vote2Tx.feePayer = verifier2.publicKey;
-      vote2Tx.sign(verifier2);
-      await client.processTransaction(vote2Tx);
-
-      // Verify votes were recorded
-      const vote1Account = await client.getAccount(vote1);
-      expect(vote1Account).not.toBeNull();
-      const vote2Account = await client.getAccount(vote2);
-      expect(vote2Account).not.toBeNull();
     });
   });
 
   describe("Access Control", () => {
     it("rejects non-facilitator creating action item", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-
-      await client.requestAirdrop(participant1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant2.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Initialize and create session
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [participant1.publicKey, participant2.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Try to create action item as participant (not facilitator)
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          participant1.publicKey, // participant trying to act as facilitator
-          "Some action",
-          participant1.publicKey,
-          [participant2.publicKey],
-          1,
-          PROGRAM_ID
-        )
+      const context = await startProgram();
+      const { fixture, owner, verifier1, verifier2 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+
+      await expectProgramError(
+        createActionItem(
+          context,
+          fixture,
+          verifier2,
+          owner.publicKey,
+          [verifier1.publicKey],
+          1
+        ),
+        RetroError.UnauthorizedFacilitator
       );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = participant1.publicKey;
-      createActionItemTx.sign(participant1);
-
-      await expect(client.processTransaction(createActionItemTx)).rejects.toThrow();
     });
 
     it("rejects verification before board is closed", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const owner = Keypair.generate();
-      const verifier = Keypair.generate();
-
-      await client.requestAirdrop(owner.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(verifier.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [owner.publicKey, verifier.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Create action item
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Test action",
-          owner.publicKey,
-          [verifier.publicKey],
-          1,
-          PROGRAM_ID
-        )
-      );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-      await client.processTransaction(createActionItemTx);
-
-      // Try to verify WITHOUT closing the session first
-      const [vote] = findVerificationVotePda(actionItem, verifier.publicKey, PROGRAM_ID);
-      const [ownerMembership] = findParticipantEntryPda(session, owner.publicKey, PROGRAM_ID);
-
-      const voteTx = new Transaction().add(
-        createCastVerificationVoteInstruction(
-          session,
-          actionItem,
-          vote,
-          ownerMembership,
-          verifier.publicKey,
-          0n,
-          true,
-          PROGRAM_ID
-        )
+      const context = await startProgram();
+      const { fixture, owner, verifier1 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey],
+        1
+      );
+
+      await expectProgramError(
+        verify(context, fixture, verifier1, owner.publicKey, true),
+        RetroError.BoardNotClosed
       );
-      voteTx.recentBlockhash = context.lastBlockhash;
-      voteTx.feePayer = verifier.publicKey;
-      voteTx.sign(verifier);
-
-      await expect(client.processTransaction(voteTx)).rejects.toThrow();
     });
 
     it("rejects non-verifier casting verification vote", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+      const context = await startProgram();
+      const { fixture, owner, verifier1, verifier2 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey],
+        1
+      );
+      await closeBoard(context, fixture);
+
+      await expectProgramError(
+        verify(context, fixture, verifier2, owner.publicKey, true),
+        RetroError.NotAVerifier
       );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const owner = Keypair.generate();
-      const verifier = Keypair.generate();
-      const nonVerifier = Keypair.generate();
-
-      await client.requestAirdrop(owner.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(verifier.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(nonVerifier.publicKey, BigInt(LAMPORTS_PER_SOL));
+    });
 
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [owner.publicKey, verifier.publicKey, nonVerifier.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Create action item with only verifier as verifier (not nonVerifier)
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Test action",
-          owner.publicKey,
-          [verifier.publicKey], // only verifier is a verifier
-          1,
-          PROGRAM_ID
-        )
-      );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-      await client.processTransaction(createActionItemTx);
-
-      // Close session
-      const closeTx = new Transaction().add(
-        createCloseSessionInstruction(session, payer.publicKey, PROGRAM_ID)
-      );
-      closeTx.recentBlockhash = context.lastBlockhash;
-      closeTx.feePayer = payer.publicKey;
-      closeTx.sign(payer);
-      await client.processTransaction(closeTx);
-
-      // Non-verifier tries to vote
-      const [vote] = findVerificationVotePda(actionItem, nonVerifier.publicKey, PROGRAM_ID);
-      const [ownerMembership] = findParticipantEntryPda(session, owner.publicKey, PROGRAM_ID);
-
-      const voteTx = new Transaction().add(
-        createCastVerificationVoteInstruction(
-          session,
-          actionItem,
-          vote,
-          ownerMembership,
-          nonVerifier.publicKey,
-          0n,
-          true,
-          PROGRAM_ID
-        )
+    it("rejects a reputation account that is not the owner's", async () => {
+      const context = await startProgram();
+      const { fixture, owner, verifier1 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+      await createActionItem(
+        context,
+        fixture,
+        fixture.facilitator,
+        owner.publicKey,
+        [verifier1.publicKey],
+        1
+      );
+      await closeBoard(context, fixture);
+
+      const [verifierReputation] = findReputationPda(verifier1.publicKey, PROGRAM_ID);
+      await expectProgramError(
+        verify(context, fixture, verifier1, owner.publicKey, true, verifierReputation),
+        RetroError.InvalidPDA
       );
-      voteTx.recentBlockhash = context.lastBlockhash;
-      voteTx.feePayer = nonVerifier.publicKey;
-      voteTx.sign(nonVerifier);
-
-      await expect(client.processTransaction(voteTx)).rejects.toThrow();
     });
   });
 
   describe("Validation", () => {
     it("rejects action item with owner as verifier", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-
-      await client.requestAirdrop(participant1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant2.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [participant1.publicKey, participant2.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Try to create action item with owner in verifiers list
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Test action",
-          participant1.publicKey,
-          [participant1.publicKey, participant2.publicKey], // owner is in verifiers!
-          1,
-          PROGRAM_ID
-        )
+      const context = await startProgram();
+      const { fixture, owner, verifier1 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+
+      await expectProgramError(
+        createActionItem(
+          context,
+          fixture,
+          fixture.facilitator,
+          owner.publicKey,
+          [owner.publicKey, verifier1.publicKey],
+          1
+        ),
+        RetroError.OwnerCannotVerify
       );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-
-      await expect(client.processTransaction(createActionItemTx)).rejects.toThrow();
     });
 
     it("rejects action item with threshold higher than verifier count", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+      const context = await startProgram();
+      const { fixture, owner, verifier1 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS);
+
+      await expectProgramError(
+        createActionItem(
+          context,
+          fixture,
+          fixture.facilitator,
+          owner.publicKey,
+          [verifier1.publicKey],
+          5
+        ),
+        RetroError.ThresholdTooHigh
       );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-
-      await client.requestAirdrop(participant1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant2.publicKey, BigInt(LAMPORTS_PER_SOL));
+    });
 
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [participant1.publicKey, participant2.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance to Discuss
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote, SessionStage.Discuss]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Try to create action item with threshold > verifiers count
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Test action",
-          participant1.publicKey,
-          [participant2.publicKey], // only 1 verifier
-          5, // but threshold is 5!
-          PROGRAM_ID
-        )
+    it("rejects a verifier without a membership", async () => {
+      const context = await startProgram();
+      const { fixture, owner } = await setupBoard(context);
+      const outsider = Keypair.generate();
+      await advanceTo(context, fixture, TO_DISCUSS);
+
+      await expectProgramError(
+        createActionItem(
+          context,
+          fixture,
+          fixture.facilitator,
+          owner.publicKey,
+          [outsider.publicKey],
+          1
+        ),
+        RetroError.NotOnAllowlist
       );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-
-      await expect(client.processTransaction(createActionItemTx)).rejects.toThrow();
     });
 
     it("rejects creating action item in wrong stage", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-
-      await client.requestAirdrop(participant1.publicKey, BigInt(LAMPORTS_PER_SOL));
-      await client.requestAirdrop(participant2.publicKey, BigInt(LAMPORTS_PER_SOL));
-
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category 1"],
-          [participant1.publicKey, participant2.publicKey],
-          5,
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Only advance to Vote stage (not Discuss)
-      for (const stage of [SessionStage.WriteNotes, SessionStage.GroupDuplicates, SessionStage.Vote]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(session, payer.publicKey, stage, PROGRAM_ID)
-        );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Try to create action item in Vote stage (should fail)
-      const [actionItem] = findActionItemPda(session, 0n, PROGRAM_ID);
-      const createActionItemTx = new Transaction().add(
-        createCreateActionItemInstruction(
-          session,
-          actionItem,
-          payer.publicKey,
-          "Test action",
-          participant1.publicKey,
-          [participant2.publicKey],
-          1,
-          PROGRAM_ID
-        )
+      const context = await startProgram();
+      const { fixture, owner, verifier1 } = await setupBoard(context);
+      await advanceTo(context, fixture, TO_DISCUSS.slice(0, 3));
+
+      await expectProgramError(
+        createActionItem(
+          context,
+          fixture,
+          fixture.facilitator,
+          owner.publicKey,
+          [verifier1.publicKey],
+          1
+        ),
+        RetroError.InvalidStage
       );
-      createActionItemTx.recentBlockhash = context.lastBlockhash;
-      createActionItemTx.feePayer = payer.publicKey;
-      createActionItemTx.sign(payer);
-
-      await expect(client.processTransaction(createActionItemTx)).rejects.toThrow();
     });
   });
 });
@@ -1,703 +1,958 @@
-import { describe, it, expect, beforeAll } from "vitest";
-import { start } from "solana-bankrun";
+import { describe, it, expect } from "vitest";
+import { createHash } from "crypto";
+import { PublicKey, Keypair } from "@solana/web3.js";
 import {
-  PublicKey,
-  Transaction,
-  Keypair,
-  LAMPORTS_PER_SOL,
-} from "@solana/web3.js";
-import {
-  findTeamRegistryPda,
-  findSessionPda,
+  findBoardPda,
   findNotePda,
   findGroupPda,
-  findParticipantEntryPda,
   findVoteRecordPda,
+  findTeamPda,
 } from "./helpers/pda";
 import {
-  createInitTeamRegistryInstruction,
-  createCreateSessionInstruction,
+  createCreateBoardInstruction,
   createAdvanceStageInstruction,
-  createCloseSessionInstruction,
+  createCloseBoardInstruction,
   createCreateNoteInstruction,
+  createRevealNoteAuthorInstruction,
+  createDeleteNoteInstruction,
   createCreateGroupInstruction,
   createSetGroupTitleInstruction,
   createAssignNoteToGroupInstruction,
   createUnassignNoteInstruction,
   createCastVoteInstruction,
-  SessionStage,
+  createRetractVoteInstruction,
+  createRevertStageInstruction,
+  createCreateTeamInstruction,
+  createSetTeamMemberInstruction,
+  defaultBoardConfig,
+  BoardStage,
+  VotingMode,
+  TeamRole,
 } from "./helpers/instructions";
-
-// Program ID (must match lib.rs)
-const PROGRAM_ID = new PublicKey(
-  "CZ1xaAyDaXa5GyWPHCytfcJjnmJhuFnVeHJLrYiijVLx"
-);
-
-// Path to compiled program
-const PROGRAM_PATH = "target/deploy/retroquest.so";
+import {
+  decodeBoard,
+  decodeMembership,
+  decodeNote,
+  decodeGroup,
+} from "./helpers/accounts";
+import {
+  PROGRAM_ID,
+  RetroError,
+  startProgram,
+  send,
+  expectProgramError,
+  fundedKeypair,
+  accountData,
+  membershipOf,
+  createBoard,
+  advanceTo,
+} from "./helpers/bankrun";
+
+// sha256(author || board || salt), matching Note::author_commitment in state.rs
+function authorCommitment(
+  author: PublicKey,
+  board: PublicKey,
+  salt: Uint8Array
+): Uint8Array {
+  return createHash("sha256")
+    .update(author.toBuffer())
+    .update(board.toBuffer())
+    .update(salt)
+    .digest();
+}
 
 describe("RetroQuest", () => {
   // Test Suite 1: Happy Path - Full Retro Workflow
   describe("Happy Path - Full Retro Workflow", () => {
-    it("completes a full retrospective session", async () => {
-      // Start Bankrun with the program
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      // Create participants
-      const participant1 = Keypair.generate();
-      const participant2 = Keypair.generate();
-
-      // Fund participants
-      const fundTx1 = await client.requestAirdrop(
-        participant1.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-      const fundTx2 = await client.requestAirdrop(
-        participant2.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // 1. Initialize team registry
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      // Verify team registry was created
-      const teamRegistryAccount = await client.getAccount(teamRegistry);
-      expect(teamRegistryAccount).not.toBeNull();
-
-      // 2. Create session with 2 participants, 3 categories
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const categories = ["What went well", "What didn't go well", "Action items"];
-      const allowlist = [participant1.publicKey, participant2.publicKey];
-
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          categories,
-          allowlist,
-          5, // 5 voting credits
-          PROGRAM_ID
-        )
-      );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Verify session was created
-      const sessionAccount = await client.getAccount(session);
-      expect(sessionAccount).not.toBeNull();
-
-      // 3. Advance to WriteNotes stage
-      const advanceToWriteTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.WriteNotes,
-          PROGRAM_ID
-        )
-      );
-      advanceToWriteTx.recentBlockhash = context.lastBlockhash;
-      advanceToWriteTx.feePayer = payer.publicKey;
-      advanceToWriteTx.sign(payer);
-      await client.processTransaction(advanceToWriteTx);
-
-      // 4. Participants create notes
-      const [note0] = findNotePda(session, 0n, PROGRAM_ID);
-      const createNote0Tx = new Transaction().add(
+    it("completes a full retrospective board", async () => {
+      const context = await startProgram();
+      const participant1 = await fundedKeypair(context);
+      const participant2 = await fundedKeypair(context);
+
+      const fixture = await createBoard(
+        context,
+        [participant1.publicKey, participant2.publicKey],
+        (config) => {
+          config.categories = ["What went well", "What didn't go well", "Action items"];
+        }
+      );
+      const { board } = fixture;
+
+      let boardState = decodeBoard(await accountData(context, board));
+      expect(boardState.stage).toBe(BoardStage.Setup);
+      expect(boardState.stages).toEqual([
+        BoardStage.Setup,
+        BoardStage.WriteNotes,
+        BoardStage.GroupDuplicates,
+        BoardStage.Vote,
+        BoardStage.Discuss,
+      ]);
+      expect(boardState.membershipCount).toBe(2);
+
+      // Participants write notes
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+      const [note0] = findNotePda(board, 0n, PROGRAM_ID);
+      const [note1] = findNotePda(board, 1n, PROGRAM_ID);
+      await send(
+        context,
+        [participant1],
         createCreateNoteInstruction(
-          session,
+          board,
           note0,
+          membershipOf(board, participant1.publicKey),
           participant1.publicKey,
-          0, // category 0
+          0,
           "Good teamwork!",
+          null,
           PROGRAM_ID
         )
       );
-      createNote0Tx.recentBlockhash = context.lastBlockhash;
-      createNote0Tx.feePayer = participant1.publicKey;
-      createNote0Tx.sign(participant1);
-      await client.processTransaction(createNote0Tx);
-
-      const [note1] = findNotePda(session, 1n, PROGRAM_ID);
-      const createNote1Tx = new Transaction().add(
+      await send(
+        context,
+        [participant2],
         createCreateNoteInstruction(
-          session,
+          board,
           note1,
+          membershipOf(board, participant2.publicKey),
           participant2.publicKey,
-          1, // category 1
+          1,
           "Too many meetings",
+          null,
           PROGRAM_ID
         )
       );
-      createNote1Tx.recentBlockhash = context.lastBlockhash;
-      createNote1Tx.feePayer = participant2.publicKey;
-      createNote1Tx.sign(participant2);
-      await client.processTransaction(createNote1Tx);
-
-      // 5. Advance to GroupDuplicates stage
-      const advanceToGroupTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.GroupDuplicates,
-          PROGRAM_ID
-        )
-      );
-      advanceToGroupTx.recentBlockhash = context.lastBlockhash;
-      advanceToGroupTx.feePayer = payer.publicKey;
-      advanceToGroupTx.sign(payer);
-      await client.processTransaction(advanceToGroupTx);
+      const noteState = decodeNote(await accountData(context, note0));
+      expect(noteState.author.equals(participant1.publicKey)).toBe(true);
 
-      // 6. Create group and assign notes
-      const [group0] = findGroupPda(session, 0n, PROGRAM_ID);
-      const createGroupTx = new Transaction().add(
+      // Group the notes
+      await advanceTo(context, fixture, [BoardStage.GroupDuplicates]);
+      const [group0] = findGroupPda(board, 0n, PROGRAM_ID);
+      const membership1 = membershipOf(board, participant1.publicKey);
+      await send(
+        context,
+        [participant1],
         createCreateGroupInstruction(
-          session,
+          board,
+          group0,
+          membership1,
+          participant1.publicKey,
+          "Meetings",
+          PROGRAM_ID
+        ),
+        createSetGroupTitleInstruction(
+          board,
           group0,
+          membership1,
           participant1.publicKey,
+          0n,
           "Meeting overload",
           PROGRAM_ID
-        )
-      );
-      createGroupTx.recentBlockhash = context.lastBlockhash;
-      createGroupTx.feePayer = participant1.publicKey;
-      createGroupTx.sign(participant1);
-      await client.processTransaction(createGroupTx);
-
-      // Assign note to group
-      const assignNoteTx = new Transaction().add(
+        ),
         createAssignNoteToGroupInstruction(
-          session,
+          board,
           note1,
           group0,
+          membership1,
           participant1.publicKey,
           1n,
           0n,
           PROGRAM_ID
         )
       );
-      assignNoteTx.recentBlockhash = context.lastBlockhash;
-      assignNoteTx.feePayer = participant1.publicKey;
-      assignNoteTx.sign(participant1);
-      await client.processTransaction(assignNoteTx);
-
-      // 7. Advance to Vote stage
-      const advanceToVoteTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.Vote,
-          PROGRAM_ID
-        )
-      );
-      advanceToVoteTx.recentBlockhash = context.lastBlockhash;
-      advanceToVoteTx.feePayer = payer.publicKey;
-      advanceToVoteTx.sign(payer);
-      await client.processTransaction(advanceToVoteTx);
+      const groupState = decodeGroup(await accountData(context, group0));
+      expect(groupState.title).toBe("Meeting overload");
+      expect(groupState.noteCount).toBe(1);
 
-      // 8. Cast votes
-      const [participantEntry1] = findParticipantEntryPda(
-        session,
-        participant1.publicKey,
-        PROGRAM_ID
-      );
+      // Vote
+      await advanceTo(context, fixture, [BoardStage.Vote]);
       const [voteRecord1] = findVoteRecordPda(
-        session,
+        board,
         participant1.publicKey,
         0n,
         PROGRAM_ID
       );
-
-      const castVoteTx = new Transaction().add(
+      await send(
+        context,
+        [participant1],
         createCastVoteInstruction(
-          session,
-          participantEntry1,
+          board,
+          membership1,
           group0,
           voteRecord1,
           participant1.publicKey,
           0n,
-          2, // 2 credits
+          2,
           PROGRAM_ID
         )
       );
-      castVoteTx.recentBlockhash = context.lastBlockhash;
-      castVoteTx.feePayer = participant1.publicKey;
-      castVoteTx.sign(participant1);
-      await client.processTransaction(castVoteTx);
-
-      // 9. Advance to Discuss stage
-      const advanceToDiscussTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.Discuss,
+      expect(decodeMembership(await accountData(context, membership1)).creditsSpent).toBe(2);
+      expect(decodeGroup(await accountData(context, group0)).voteTally).toBe(2n);
+
+      // Discuss is the last stage of the default pipeline, so the board can close
+      await advanceTo(context, fixture, [BoardStage.Discuss]);
+      await send(
+        context,
+        [fixture.facilitator],
+        createCloseBoardInstruction(
+          board,
+          fixture.facilitator.publicKey,
+          null,
           PROGRAM_ID
         )
       );
-      advanceToDiscussTx.recentBlockhash = context.lastBlockhash;
-      advanceToDiscussTx.feePayer = payer.publicKey;
-      advanceToDiscussTx.sign(payer);
-      await client.processTransaction(advanceToDiscussTx);
-
-      // 10. Close session
-      const closeSessionTx = new Transaction().add(
-        createCloseSessionInstruction(session, payer.publicKey, PROGRAM_ID)
-      );
-      closeSessionTx.recentBlockhash = context.lastBlockhash;
-      closeSessionTx.feePayer = payer.publicKey;
-      closeSessionTx.sign(payer);
-      await client.processTransaction(closeSessionTx);
 
-      // Verify session is closed
-      const finalSessionAccount = await client.getAccount(session);
-      expect(finalSessionAccount).not.toBeNull();
+      boardState = decodeBoard(await accountData(context, board));
+      expect(boardState.closed).toBe(true);
     });
   });
 
   // Test Suite 2: Access Control
   describe("Access Control", () => {
-    it("rejects non-allowlisted user from creating note", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
-      );
-      const client = context.banksClient;
-      const payer = context.payer;
-
-      const allowedParticipant = Keypair.generate();
-      const notAllowedParticipant = Keypair.generate();
-
-      // Fund non-allowed participant
-      await client.requestAirdrop(
-        notAllowedParticipant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup: Create registry and session
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [allowedParticipant.publicKey], // Only allowedParticipant is in allowlist
-          5,
-          PROGRAM_ID
-        )
+    it("rejects a participant without a membership from creating a note", async () => {
+      const context = await startProgram();
+      const allowedParticipant = await fundedKeypair(context);
+      const notAllowedParticipant = await fundedKeypair(context);
+
+      const fixture = await createBoard(context, [allowedParticipant.publicKey]);
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      const [note] = findNotePda(fixture.board, 0n, PROGRAM_ID);
+      await expectProgramError(
+        send(
+          context,
+          [notAllowedParticipant],
+          createCreateNoteInstruction(
+            fixture.board,
+            note,
+            membershipOf(fixture.board, notAllowedParticipant.publicKey),
+            notAllowedParticipant.publicKey,
+            0,
+            "Should fail",
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.NotOnAllowlist
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
+    });
 
-      // Advance to WriteNotes
-      const advanceTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.WriteNotes,
-          PROGRAM_ID
-        )
+    it("rejects non-facilitator from advancing stage", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+
+      await expectProgramError(
+        send(
+          context,
+          [participant],
+          createAdvanceStageInstruction(
+            fixture.board,
+            participant.publicKey,
+            BoardStage.WriteNotes,
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.UnauthorizedFacilitator
       );
-      advanceTx.recentBlockhash = context.lastBlockhash;
-      advanceTx.feePayer = payer.publicKey;
-      advanceTx.sign(payer);
-      await client.processTransaction(advanceTx);
+    });
+  });
 
-      // Try to create note with non-allowlisted user
-      const [note] = findNotePda(session, 0n, PROGRAM_ID);
-      const createNoteTx = new Transaction().add(
-        createCreateNoteInstruction(
-          session,
-          note,
-          notAllowedParticipant.publicKey,
-          0,
-          "Should fail",
-          PROGRAM_ID
-        )
+  // Test Suite 3: Stage Enforcement
+  describe("Stage Enforcement", () => {
+    it("rejects creating note in wrong stage", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+
+      const [note] = findNotePda(fixture.board, 0n, PROGRAM_ID);
+      await expectProgramError(
+        send(
+          context,
+          [participant],
+          createCreateNoteInstruction(
+            fixture.board,
+            note,
+            membershipOf(fixture.board, participant.publicKey),
+            participant.publicKey,
+            0,
+            "Should fail",
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.InvalidStage
       );
-      createNoteTx.recentBlockhash = context.lastBlockhash;
-      createNoteTx.feePayer = notAllowedParticipant.publicKey;
-      createNoteTx.sign(notAllowedParticipant);
+    });
+
+    it("only advances to the next stage of the pipeline", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
 
-      // Expect this to fail with NotOnAllowlist error
-      await expect(client.processTransaction(createNoteTx)).rejects.toThrow();
+      await expectProgramError(
+        advanceTo(context, fixture, [BoardStage.Vote]),
+        RetroError.InvalidStageTransition
+      );
     });
 
-    it("rejects non-facilitator from advancing stage", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+    it("skips stages left out of the pipeline", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey], (config) => {
+        config.stages = [BoardStage.Setup, BoardStage.WriteNotes, BoardStage.Discuss];
+      });
+
+      await advanceTo(context, fixture, [BoardStage.WriteNotes, BoardStage.Discuss]);
+      const boardState = decodeBoard(await accountData(context, fixture.board));
+      expect(boardState.stage).toBe(BoardStage.Discuss);
+    });
+
+    it("rejects closing the board before its final stage", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      await expectProgramError(
+        send(
+          context,
+          [fixture.facilitator],
+          createCloseBoardInstruction(
+            fixture.board,
+            fixture.facilitator.publicKey,
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.InvalidStage
       );
-      const client = context.banksClient;
-      const payer = context.payer;
+    });
+  });
 
-      const participant = Keypair.generate();
-      await client.requestAirdrop(
-        participant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup: Create registry and session
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [participant.publicKey],
-          5,
+  // Test Suite 4: Grouping
+  describe("Grouping", () => {
+    it("unassigns a note from the group it is in", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+      const { board } = fixture;
+      const membership = membershipOf(board, participant.publicKey);
+
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+      const [note] = findNotePda(board, 0n, PROGRAM_ID);
+      await send(
+        context,
+        [participant],
+        createCreateNoteInstruction(
+          board,
+          note,
+          membership,
+          participant.publicKey,
+          0,
+          "Flaky CI",
+          null,
           PROGRAM_ID
         )
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
 
-      // Try to advance stage with non-facilitator
-      const advanceTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          participant.publicKey, // Not the facilitator
-          SessionStage.WriteNotes,
+      await advanceTo(context, fixture, [BoardStage.GroupDuplicates]);
+      const [group0] = findGroupPda(board, 0n, PROGRAM_ID);
+      const [group1] = findGroupPda(board, 1n, PROGRAM_ID);
+      await send(
+        context,
+        [participant],
+        createCreateGroupInstruction(
+          board,
+          group0,
+          membership,
+          participant.publicKey,
+          "Tooling",
+          PROGRAM_ID
+        ),
+        createCreateGroupInstruction(
+          board,
+          group1,
+          membership,
+          participant.publicKey,
+          "Process",
+          PROGRAM_ID
+        ),
+        createAssignNoteToGroupInstruction(
+          board,
+          note,
+          group0,
+          membership,
+          participant.publicKey,
+          0n,
+          0n,
           PROGRAM_ID
         )
       );
-      advanceTx.recentBlockhash = context.lastBlockhash;
-      advanceTx.feePayer = participant.publicKey;
-      advanceTx.sign(participant);
 
-      // Expect this to fail with UnauthorizedFacilitator error
-      await expect(client.processTransaction(advanceTx)).rejects.toThrow();
-    });
-  });
-
-  // Test Suite 3: Stage Enforcement
-  describe("Stage Enforcement", () => {
-    it("rejects creating note in wrong stage", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+      // The group passed must be the one the note is in
+      await expectProgramError(
+        send(
+          context,
+          [participant],
+          createUnassignNoteInstruction(
+            board,
+            note,
+            group1,
+            membership,
+            participant.publicKey,
+            0n,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.InvalidPDA
       );
-      const client = context.banksClient;
-      const payer = context.payer;
 
-      const participant = Keypair.generate();
-      await client.requestAirdrop(
-        participant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup: Create registry and session (stays in Setup stage)
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [participant.publicKey],
-          5,
+      await send(
+        context,
+        [participant],
+        createUnassignNoteInstruction(
+          board,
+          note,
+          group0,
+          membership,
+          participant.publicKey,
+          0n,
           PROGRAM_ID
         )
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
+      expect(decodeNote(await accountData(context, note)).groupId).toBeNull();
+      expect(decodeGroup(await accountData(context, group0)).noteCount).toBe(0);
+    });
+
+    it("rejects a participant without a membership from creating a group", async () => {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const outsider = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+      await advanceTo(context, fixture, [
+        BoardStage.WriteNotes,
+        BoardStage.GroupDuplicates,
+      ]);
+
+      const [group] = findGroupPda(fixture.board, 0n, PROGRAM_ID);
+      await expectProgramError(
+        send(
+          context,
+          [outsider],
+          createCreateGroupInstruction(
+            fixture.board,
+            group,
+            membershipOf(fixture.board, outsider.publicKey),
+            outsider.publicKey,
+            "Should fail",
+            PROGRAM_ID
+          )
+        ),
+        RetroError.NotOnAllowlist
+      );
+    });
+  });
 
-      // Try to create note in Setup stage (should be WriteNotes)
-      const [note] = findNotePda(session, 0n, PROGRAM_ID);
-      const createNoteTx = new Transaction().add(
+  // Test Suite 5: Voting Credits
+  describe("Voting Credits", () => {
+    // A board in Vote with one grouped note, so group 0 can take votes
+    async function votingBoard(
+      votingMode: number,
+      votingCredits: number
+    ) {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey], (config) => {
+        config.votingMode = votingMode;
+        config.votingCreditsPerParticipant = votingCredits;
+      });
+      const { board } = fixture;
+      const membership = membershipOf(board, participant.publicKey);
+      const [note] = findNotePda(board, 0n, PROGRAM_ID);
+      const [group] = findGroupPda(board, 0n, PROGRAM_ID);
+
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+      await send(
+        context,
+        [participant],
         createCreateNoteInstruction(
-          session,
+          board,
           note,
+          membership,
           participant.publicKey,
           0,
-          "Should fail",
+          "Slow reviews",
+          null,
           PROGRAM_ID
         )
       );
-      createNoteTx.recentBlockhash = context.lastBlockhash;
-      createNoteTx.feePayer = participant.publicKey;
-      createNoteTx.sign(participant);
-
-      // Expect this to fail with InvalidStage error
-      await expect(client.processTransaction(createNoteTx)).rejects.toThrow();
-    });
-  });
-
-  // Test Suite 4: Voting Credits
-  describe("Voting Credits", () => {
-    it("rejects voting over credit limit", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+      await advanceTo(context, fixture, [BoardStage.GroupDuplicates]);
+      await send(
+        context,
+        [participant],
+        createCreateGroupInstruction(
+          board,
+          group,
+          membership,
+          participant.publicKey,
+          "Reviews",
+          PROGRAM_ID
+        ),
+        createAssignNoteToGroupInstruction(
+          board,
+          note,
+          group,
+          membership,
+          participant.publicKey,
+          0n,
+          0n,
+          PROGRAM_ID
+        )
       );
-      const client = context.banksClient;
-      const payer = context.payer;
+      await advanceTo(context, fixture, [BoardStage.Vote]);
 
-      const participant = Keypair.generate();
-      await client.requestAirdrop(
+      const [voteRecord] = findVoteRecordPda(
+        board,
         participant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup full workflow to Vote stage
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [participant.publicKey],
-          3, // Only 3 voting credits
-          PROGRAM_ID
-        )
+        0n,
+        PROGRAM_ID
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
-
-      // Advance through stages to Vote
-      for (const stage of [
-        SessionStage.WriteNotes,
-        SessionStage.GroupDuplicates,
-        SessionStage.Vote,
-      ]) {
-        const advanceTx = new Transaction().add(
-          createAdvanceStageInstruction(
-            session,
-            payer.publicKey,
-            stage,
+      const castVote = (votes: number) =>
+        createCastVoteInstruction(
+          board,
+          membership,
+          group,
+          voteRecord,
+          participant.publicKey,
+          0n,
+          votes,
+          PROGRAM_ID
+        );
+      const vote = (votes: number) =>
+        send(context, [participant], castVote(votes));
+      const retract = (votes: number) =>
+        send(
+          context,
+          [participant],
+          createRetractVoteInstruction(
+            board,
+            membership,
+            group,
+            voteRecord,
+            participant.publicKey,
+            participant.publicKey,
+            0n,
+            votes,
             PROGRAM_ID
           )
         );
-        advanceTx.recentBlockhash = context.lastBlockhash;
-        advanceTx.feePayer = payer.publicKey;
-        advanceTx.sign(payer);
-        await client.processTransaction(advanceTx);
-      }
-
-      // Create a group in GroupDuplicates stage
-      // Need to go back... Actually we need to create group before advancing to Vote
-      // Let's restart this test properly
-
-      // This test needs proper setup - skipping detailed implementation
-      // The structure shows the intent
+      const creditsSpent = async () =>
+        decodeMembership(await accountData(context, membership)).creditsSpent;
+
+      return {
+        context,
+        fixture,
+        participant,
+        group,
+        voteRecord,
+        castVote,
+        vote,
+        retract,
+        creditsSpent,
+      };
+    }
+
+    it("rejects voting over credit limit", async () => {
+      const { vote, creditsSpent } = await votingBoard(VotingMode.Linear, 3);
+
+      await expectProgramError(vote(4), RetroError.InsufficientCredits);
+      await vote(3);
+      expect(await creditsSpent()).toBe(3);
     });
-  });
 
-  // Test Suite 5: Edge Cases
-  describe("Edge Cases", () => {
-    it("accepts note at max length (280 chars)", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+    it("charges n squared credits for n votes on quadratic boards", async () => {
+      const { context, group, vote, creditsSpent } = await votingBoard(
+        VotingMode.Quadratic,
+        5
       );
-      const client = context.banksClient;
-      const payer = context.payer;
 
-      const participant = Keypair.generate();
-      await client.requestAirdrop(
-        participant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [participant.publicKey],
-          5,
+      await vote(2);
+      expect(await creditsSpent()).toBe(4);
+      expect(decodeGroup(await accountData(context, group)).voteTally).toBe(2n);
+
+      // A third vote would bring the group's cost to 9
+      await expectProgramError(vote(1), RetroError.InsufficientCredits);
+    });
+
+    it("refunds retracted votes and closes the emptied vote record", async () => {
+      const { context, group, voteRecord, vote, retract, creditsSpent } =
+        await votingBoard(VotingMode.Linear, 5);
+
+      await vote(3);
+      await expectProgramError(retract(4), RetroError.InvalidRetraction);
+
+      await retract(1);
+      expect(await creditsSpent()).toBe(2);
+      expect(decodeGroup(await accountData(context, group)).voteTally).toBe(2n);
+
+      await retract(2);
+      expect(await creditsSpent()).toBe(0);
+      expect(await context.banksClient.getAccount(voteRecord)).toBeNull();
+    });
+
+    it("discards the vote round when the facilitator reverts before Vote", async () => {
+      const { context, fixture, participant, group, castVote, vote, creditsSpent } =
+        await votingBoard(VotingMode.Linear, 3);
+      const membership = membershipOf(fixture.board, participant.publicKey);
+
+      await vote(3);
+      await send(
+        context,
+        [fixture.facilitator],
+        createRevertStageInstruction(
+          fixture.board,
+          fixture.facilitator.publicKey,
+          BoardStage.GroupDuplicates,
+          null,
           PROGRAM_ID
         )
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
+      expect(decodeBoard(await accountData(context, fixture.board)).voteRound).toBe(1);
+
+      // Untouched accounts keep the old round until an instruction resets them
+      const staleMembership = decodeMembership(await accountData(context, membership));
+      expect(staleMembership.creditsSpent).toBe(3);
+      expect(staleMembership.voteRound).toBe(0);
+      const staleGroup = decodeGroup(await accountData(context, group));
+      expect(staleGroup.voteTally).toBe(3n);
+      expect(staleGroup.voteRound).toBe(0);
 
-      // Advance to WriteNotes
-      const advanceTx = new Transaction().add(
+      // Back in Vote the full budget is available again
+      await send(
+        context,
+        [participant, fixture.facilitator],
         createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.WriteNotes,
+          fixture.board,
+          fixture.facilitator.publicKey,
+          BoardStage.Vote,
+          null,
           PROGRAM_ID
-        )
+        ),
+        castVote(2)
+      );
+      expect(await creditsSpent()).toBe(2);
+      const groupState = decodeGroup(await accountData(context, group));
+      expect(groupState.voteTally).toBe(2n);
+      expect(groupState.voteRound).toBe(1);
+    });
+  });
+
+  // Test Suite 6: Hidden Note Authors
+  describe("Hidden Note Authors", () => {
+    const salt = new Uint8Array(32).fill(7);
+
+    it("stores the commitment instead of the author until they reveal", async () => {
+      const context = await startProgram();
+      const author = await fundedKeypair(context);
+      const other = await fundedKeypair(context);
+      const fixture = await createBoard(
+        context,
+        [author.publicKey, other.publicKey],
+        (config) => {
+          config.hideNoteAuthors = true;
+        }
+      );
+      const { board } = fixture;
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      const [note] = findNotePda(board, 0n, PROGRAM_ID);
+      const createNote = (commitment: Uint8Array | null) =>
+        send(
+          context,
+          [author],
+          createCreateNoteInstruction(
+            board,
+            note,
+            membershipOf(board, author.publicKey),
+            author.publicKey,
+            0,
+            "Hidden",
+            commitment,
+            PROGRAM_ID
+          )
+        );
+
+      await expectProgramError(createNote(null), RetroError.InvalidAuthorCommitment);
+
+      const commitment = authorCommitment(author.publicKey, board, salt);
+      await createNote(commitment);
+      let noteState = decodeNote(await accountData(context, note));
+      expect(noteState.author.equals(PublicKey.default)).toBe(true);
+      expect(noteState.authorCommitment).toEqual(Buffer.from(commitment));
+      expect(noteState.rentPayer.equals(PublicKey.default)).toBe(true);
+
+      // Only the author, with the salt, opens the commitment
+      await expectProgramError(
+        send(
+          context,
+          [other],
+          createRevealNoteAuthorInstruction(board, note, other.publicKey, salt, PROGRAM_ID)
+        ),
+        RetroError.AuthorCommitmentMismatch
+      );
+      await expectProgramError(
+        send(
+          context,
+          [author],
+          createRevealNoteAuthorInstruction(
+            board,
+            note,
+            author.publicKey,
+            new Uint8Array(32).fill(8),
+            PROGRAM_ID
+          )
+        ),
+        RetroError.AuthorCommitmentMismatch
+      );
+
+      await send(
+        context,
+        [author],
+        createRevealNoteAuthorInstruction(board, note, author.publicKey, salt, PROGRAM_ID)
       );
-      advanceTx.recentBlockhash = context.lastBlockhash;
-      advanceTx.feePayer = payer.publicKey;
-      advanceTx.sign(payer);
-      await client.processTransaction(advanceTx);
+      noteState = decodeNote(await accountData(context, note));
+      expect(noteState.author.equals(author.publicKey)).toBe(true);
+      expect(noteState.rentPayer.equals(author.publicKey)).toBe(true);
+    });
 
-      // Create note with exactly 280 characters
-      const maxContent = "a".repeat(280);
-      const [note] = findNotePda(session, 0n, PROGRAM_ID);
-      const createNoteTx = new Transaction().add(
+    it("rejects commitments on boards that show authors", async () => {
+      const context = await startProgram();
+      const author = await fundedKeypair(context);
+      const fixture = await createBoard(context, [author.publicKey]);
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      const [note] = findNotePda(fixture.board, 0n, PROGRAM_ID);
+      await expectProgramError(
+        send(
+          context,
+          [author],
+          createCreateNoteInstruction(
+            fixture.board,
+            note,
+            membershipOf(fixture.board, author.publicKey),
+            author.publicKey,
+            0,
+            "Visible",
+            authorCommitment(author.publicKey, fixture.board, salt),
+            PROGRAM_ID
+          )
+        ),
+        RetroError.InvalidAuthorCommitment
+      );
+    });
+  });
+
+  // Test Suite 7: Note Deletion
+  describe("Note Deletion", () => {
+    it("lets only the author delete a note and refunds its rent payer", async () => {
+      const context = await startProgram();
+      const author = await fundedKeypair(context);
+      const other = await fundedKeypair(context);
+      const fixture = await createBoard(context, [
+        author.publicKey,
+        other.publicKey,
+      ]);
+      const { board } = fixture;
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      const [note] = findNotePda(board, 0n, PROGRAM_ID);
+      await send(
+        context,
+        [author],
         createCreateNoteInstruction(
-          session,
+          board,
           note,
-          participant.publicKey,
+          membershipOf(board, author.publicKey),
+          author.publicKey,
           0,
-          maxContent,
+          "Oops",
+          null,
           PROGRAM_ID
         )
       );
-      createNoteTx.recentBlockhash = context.lastBlockhash;
-      createNoteTx.feePayer = participant.publicKey;
-      createNoteTx.sign(participant);
-
-      // Should succeed
-      await client.processTransaction(createNoteTx);
-
-      const noteAccount = await client.getAccount(note);
-      expect(noteAccount).not.toBeNull();
-    });
-
-    it("rejects note over max length", async () => {
-      const context = await start(
-        [{ name: "retroquest", programId: PROGRAM_ID }],
-        []
+      const noteRent = (await context.banksClient.getAccount(note))!.lamports;
+
+      await expectProgramError(
+        send(
+          context,
+          [other],
+          createDeleteNoteInstruction(
+            board,
+            note,
+            author.publicKey,
+            other.publicKey,
+            0n,
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.NotNoteAuthor
+      );
+      await expectProgramError(
+        send(
+          context,
+          [author],
+          createDeleteNoteInstruction(
+            board,
+            note,
+            other.publicKey,
+            author.publicKey,
+            0n,
+            null,
+            PROGRAM_ID
+          )
+        ),
+        RetroError.InvalidRentRecipient
       );
-      const client = context.banksClient;
-      const payer = context.payer;
 
-      const participant = Keypair.generate();
-      await client.requestAirdrop(
-        participant.publicKey,
-        BigInt(LAMPORTS_PER_SOL)
-      );
-
-      // Setup
-      const [teamRegistry] = findTeamRegistryPda(payer.publicKey, PROGRAM_ID);
-      const initTx = new Transaction().add(
-        createInitTeamRegistryInstruction(payer.publicKey, PROGRAM_ID)
-      );
-      initTx.recentBlockhash = context.lastBlockhash;
-      initTx.feePayer = payer.publicKey;
-      initTx.sign(payer);
-      await client.processTransaction(initTx);
-
-      const [session] = findSessionPda(payer.publicKey, 0n, PROGRAM_ID);
-      const createSessionTx = new Transaction().add(
-        createCreateSessionInstruction(
-          teamRegistry,
-          session,
-          payer.publicKey,
-          ["Category"],
-          [participant.publicKey],
-          5,
+      // The other participant pays the fee so the author's balance only moves by the rent
+      const before = await context.banksClient.getBalance(author.publicKey);
+      await send(
+        context,
+        [other, author],
+        createDeleteNoteInstruction(
+          board,
+          note,
+          author.publicKey,
+          author.publicKey,
+          0n,
+          null,
           PROGRAM_ID
         )
       );
-      createSessionTx.recentBlockhash = context.lastBlockhash;
-      createSessionTx.feePayer = payer.publicKey;
-      createSessionTx.sign(payer);
-      await client.processTransaction(createSessionTx);
+      expect(await context.banksClient.getAccount(note)).toBeNull();
+      expect(await context.banksClient.getBalance(author.publicKey)).toBe(
+        before + BigInt(noteRent)
+      );
+    });
+  });
 
-      // Advance to WriteNotes
-      const advanceTx = new Transaction().add(
-        createAdvanceStageInstruction(
-          session,
-          payer.publicKey,
-          SessionStage.WriteNotes,
+  // Test Suite 8: Team Boards
+  describe("Team Boards", () => {
+    it("lets any team facilitator run a team board", async () => {
+      const context = await startProgram();
+      const creator = context.payer;
+      const coFacilitator = await fundedKeypair(context);
+      const member = await fundedKeypair(context);
+      const participant = await fundedKeypair(context);
+
+      const [team] = findTeamPda(creator.publicKey, "Platform", PROGRAM_ID);
+      const [otherTeam] = findTeamPda(creator.publicKey, "Payments", PROGRAM_ID);
+      await send(
+        context,
+        [creator],
+        createCreateTeamInstruction(team, creator.publicKey, "Platform", PROGRAM_ID),
+        createCreateTeamInstruction(otherTeam, creator.publicKey, "Payments", PROGRAM_ID),
+        createSetTeamMemberInstruction(
+          team,
+          creator.publicKey,
+          coFacilitator.publicKey,
+          TeamRole.Facilitator,
+          PROGRAM_ID
+        ),
+        createSetTeamMemberInstruction(
+          team,
+          creator.publicKey,
+          member.publicKey,
+          TeamRole.Member,
           PROGRAM_ID
         )
       );
-      advanceTx.recentBlockhash = context.lastBlockhash;
-      advanceTx.feePayer = payer.publicKey;
-      advanceTx.sign(payer);
-      await client.processTransaction(advanceTx);
 
-      // Create note with 281 characters (over limit)
-      const overMaxContent = "a".repeat(281);
-      const [note] = findNotePda(session, 0n, PROGRAM_ID);
-      const createNoteTx = new Transaction().add(
-        createCreateNoteInstruction(
-          session,
-          note,
-          participant.publicKey,
-          0,
-          overMaxContent,
+      // Team boards are numbered by the team
+      const [board] = findBoardPda(team, 0n, PROGRAM_ID);
+      await send(
+        context,
+        [creator],
+        createCreateBoardInstruction(
+          team,
+          board,
+          creator.publicKey,
+          defaultBoardConfig([participant.publicKey]),
+          [membershipOf(board, participant.publicKey)],
           PROGRAM_ID
         )
       );
-      createNoteTx.recentBlockhash = context.lastBlockhash;
-      createNoteTx.feePayer = participant.publicKey;
-      createNoteTx.sign(participant);
+      expect(decodeBoard(await accountData(context, board)).team?.equals(team)).toBe(true);
+
+      const advance = (signer: Keypair, teamAccount: PublicKey) =>
+        send(
+          context,
+          [signer],
+          createAdvanceStageInstruction(
+            board,
+            signer.publicKey,
+            BoardStage.WriteNotes,
+            teamAccount,
+            PROGRAM_ID
+          )
+        );
+
+      await expectProgramError(
+        advance(member, team),
+        RetroError.UnauthorizedFacilitator
+      );
+      await expectProgramError(
+        advance(coFacilitator, otherTeam),
+        RetroError.AccountBoardMismatch
+      );
+
+      await advance(coFacilitator, team);
+      expect(decodeBoard(await accountData(context, board)).stage).toBe(
+        BoardStage.WriteNotes
+      );
+    });
+  });
+
+  // Test Suite 9: Edge Cases
+  describe("Edge Cases", () => {
+    async function writingBoard() {
+      const context = await startProgram();
+      const participant = await fundedKeypair(context);
+      const fixture = await createBoard(context, [participant.publicKey]);
+      await advanceTo(context, fixture, [BoardStage.WriteNotes]);
+
+      const [note] = findNotePda(fixture.board, 0n, PROGRAM_ID);
+      const createNote = (content: string) =>
+        send(
+          context,
+          [participant],
+          createCreateNoteInstruction(
+            fixture.board,
+            note,
+            membershipOf(fixture.board, participant.publicKey),
+            participant.publicKey,
+            0,
+            content,
+            null,
+            PROGRAM_ID
+          )
+        );
+      return { context, note, createNote };
+    }
+
+    it("accepts note at max length (280 chars)", async () => {
+      const { context, note, createNote } = await writingBoard();
+
+      await createNote("a".repeat(280));
+      expect(await context.banksClient.getAccount(note)).not.toBeNull();
+    });
+
+    it("rejects note over max length", async () => {
+      const { createNote } = await writingBoard();
 
-      // Should fail with NoteTooLong error
-      await expect(client.processTransaction(createNoteTx)).rejects.toThrow();
+      await expectProgramError(createNote("a".repeat(281)), RetroError.NoteTooLong);
     });
   });
 });
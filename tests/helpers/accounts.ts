@@ -0,0 +1,248 @@
+import { PublicKey } from "@solana/web3.js";
+
+// Sequential reader over Borsh-encoded account data
+class Reader {
+  private offset = 0;
+
+  constructor(private readonly data: Buffer) {}
+
+  u8(): number {
+    const value = this.data.readUInt8(this.offset);
+    this.offset += 1;
+    return value;
+  }
+
+  bool(): boolean {
+    return this.u8() === 1;
+  }
+
+  u32(): number {
+    const value = this.data.readUInt32LE(this.offset);
+    this.offset += 4;
+    return value;
+  }
+
+  u64(): bigint {
+    const value = this.data.readBigUInt64LE(this.offset);
+    this.offset += 8;
+    return value;
+  }
+
+  pubkey(): PublicKey {
+    const value = new PublicKey(this.data.subarray(this.offset, this.offset + 32));
+    this.offset += 32;
+    return value;
+  }
+
+  bytes32(): Buffer {
+    const value = Buffer.from(this.data.subarray(this.offset, this.offset + 32));
+    this.offset += 32;
+    return value;
+  }
+
+  string(): string {
+    const length = this.u32();
+    const value = this.data
+      .subarray(this.offset, this.offset + length)
+      .toString("utf8");
+    this.offset += length;
+    return value;
+  }
+
+  option<T>(read: () => T): T | null {
+    return this.bool() ? read() : null;
+  }
+
+  vec<T>(read: () => T): T[] {
+    const length = this.u32();
+    return Array.from({ length }, read);
+  }
+}
+
+export interface RetroBoard {
+  facilitator: PublicKey;
+  stage: number;
+  stages: number[];
+  closed: boolean;
+  votingMode: number;
+  voteRound: number;
+  hideNoteAuthors: boolean;
+  noteCount: bigint;
+  groupCount: bigint;
+  actionItemCount: bigint;
+  pendingActionItemCount: bigint;
+  membershipCount: number;
+  team: PublicKey | null;
+}
+
+export function decodeBoard(data: Uint8Array): RetroBoard {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  const facilitator = r.pubkey();
+  r.option(() => r.pubkey()); // pending_facilitator
+  r.u64(); // board_index
+  const stage = r.u8();
+  const stages = r.vec(() => r.u8());
+  r.vec(() => r.u64()); // stage_duration_slots
+  const closed = r.bool();
+  r.vec(() => r.string()); // categories
+  r.u8(); // voting_credits_per_participant
+  const votingMode = r.u8();
+  const voteRound = r.u32();
+  r.u32(); // ballot_count
+  r.u64(); // tallied_group_count
+  r.u64(); // candidate_count
+  r.u32(); // tallied_ballot_count
+  r.option(() => r.pubkey()); // last_tallied_participant
+  const hideNoteAuthors = r.bool();
+  const noteCount = r.u64();
+  const groupCount = r.u64();
+  const actionItemCount = r.u64();
+  const pendingActionItemCount = r.u64();
+  r.u64(); // live_action_item_count
+  r.u64(); // legacy_note_count
+  const membershipCount = r.u32();
+  const team = r.option(() => r.pubkey());
+
+  return {
+    facilitator,
+    stage,
+    stages,
+    closed,
+    votingMode,
+    voteRound,
+    hideNoteAuthors,
+    noteCount,
+    groupCount,
+    actionItemCount,
+    pendingActionItemCount,
+    membershipCount,
+    team,
+  };
+}
+
+export interface BoardMembership {
+  participant: PublicKey;
+  votingCredits: number;
+  creditsSpent: number;
+  totalScore: bigint;
+  rejectedCount: bigint;
+  voteRound: number;
+}
+
+export function decodeMembership(data: Uint8Array): BoardMembership {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  r.pubkey(); // board
+  return {
+    participant: r.pubkey(),
+    votingCredits: r.u8(),
+    creditsSpent: r.u8(),
+    totalScore: r.u64(),
+    rejectedCount: r.u64(),
+    voteRound: r.u32(),
+  };
+}
+
+export interface Note {
+  author: PublicKey;
+  authorCommitment: Buffer | null;
+  content: string;
+  groupId: bigint | null;
+  rentPayer: PublicKey;
+}
+
+export function decodeNote(data: Uint8Array): Note {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  r.pubkey(); // board
+  r.u64(); // note_id
+  const author = r.pubkey();
+  const authorCommitment = r.option(() => r.bytes32());
+  r.u8(); // category_id
+  const content = r.string();
+  r.u64(); // created_at_slot
+  const groupId = r.option(() => r.u64());
+  const rentPayer = r.pubkey();
+
+  return { author, authorCommitment, content, groupId, rentPayer };
+}
+
+export interface Group {
+  title: string;
+  voteTally: bigint;
+  voteRound: number;
+  noteCount: number;
+}
+
+export function decodeGroup(data: Uint8Array): Group {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  r.pubkey(); // board
+  r.u64(); // group_id
+  const title = r.string();
+  r.pubkey(); // created_by
+  return {
+    title,
+    voteTally: r.u64(),
+    voteRound: r.u32(),
+    noteCount: r.u32(),
+  };
+}
+
+// Must match ActionItemStatus in state.rs
+export const ActionItemStatus = {
+  Pending: 0,
+  Completed: 1,
+  Expired: 2,
+  Rejected: 3,
+} as const;
+
+export interface ActionItem {
+  owner: PublicKey;
+  verifiers: PublicKey[];
+  threshold: number;
+  approvals: number;
+  rejections: number;
+  status: number;
+}
+
+export function decodeActionItem(data: Uint8Array): ActionItem {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  r.pubkey(); // board
+  r.u64(); // action_item_id
+  r.string(); // description
+  return {
+    owner: r.pubkey(),
+    verifiers: r.vec(() => r.pubkey()),
+    threshold: r.u8(),
+    approvals: r.u8(),
+    rejections: r.u8(),
+    status: r.u8(),
+  };
+}
+
+export interface Reputation {
+  completed: bigint;
+  rejected: bigint;
+  expired: bigint;
+}
+
+export function decodeReputation(data: Uint8Array): Reputation {
+  const r = new Reader(Buffer.from(data));
+  r.u8(); // discriminator
+  r.bool(); // is_initialized
+  r.pubkey(); // authority
+  r.pubkey(); // identity
+  return {
+    completed: r.u64(),
+    rejected: r.u64(),
+    expired: r.u64(),
+  };
+}
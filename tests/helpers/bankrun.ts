@@ -0,0 +1,165 @@
+import { expect } from "vitest";
+import { start, ProgramTestContext } from "solana-bankrun";
+import {
+  PublicKey,
+  Transaction,
+  TransactionInstruction,
+  Keypair,
+  SystemProgram,
+  LAMPORTS_PER_SOL,
+} from "@solana/web3.js";
+import {
+  findFacilitatorRegistryPda,
+  findBoardPda,
+  findBoardMembershipPda,
+} from "./pda";
+import {
+  createInitFacilitatorRegistryInstruction,
+  createCreateBoardInstruction,
+  createAdvanceStageInstruction,
+  defaultBoardConfig,
+  BoardConfig,
+} from "./instructions";
+
+// Program ID (must match lib.rs)
+export const PROGRAM_ID = new PublicKey(
+  "CZ1xaAyDaXa5GyWPHCytfcJjnmJhuFnVeHJLrYiijVLx"
+);
+
+// Custom error codes used by the tests (must match error.rs)
+export const RetroError = {
+  UnauthorizedFacilitator: 0,
+  InvalidStage: 2,
+  InvalidStageTransition: 3,
+  NotOnAllowlist: 4,
+  NoteTooLong: 7,
+  NoteNotGrouped: 10,
+  InsufficientCredits: 11,
+  InvalidPDA: 16,
+  OwnerCannotVerify: 22,
+  ThresholdTooHigh: 24,
+  NotAVerifier: 25,
+  ActionItemNotPending: 26,
+  BoardNotClosed: 28,
+  AccountBoardMismatch: 39,
+  InvalidRentRecipient: 40,
+  InvalidAuthorCommitment: 42,
+  AuthorCommitmentMismatch: 43,
+  NotNoteAuthor: 45,
+  ActionItemsExist: 62,
+  InvalidRetraction: 63,
+} as const;
+
+export async function startProgram(): Promise<ProgramTestContext> {
+  return start([{ name: "retroquest", programId: PROGRAM_ID }], []);
+}
+
+// The first signer pays the fee
+export async function send(
+  context: ProgramTestContext,
+  signers: Keypair[],
+  ...instructions: TransactionInstruction[]
+): Promise<void> {
+  const tx = new Transaction().add(...instructions);
+  tx.recentBlockhash = context.lastBlockhash;
+  tx.feePayer = signers[0].publicKey;
+  tx.sign(...signers);
+  await context.banksClient.processTransaction(tx);
+}
+
+export async function expectProgramError(
+  result: Promise<unknown>,
+  code: number
+): Promise<void> {
+  await expect(result).rejects.toThrow(
+    `custom program error: 0x${code.toString(16)}`
+  );
+}
+
+export async function fundedKeypair(
+  context: ProgramTestContext
+): Promise<Keypair> {
+  const keypair = Keypair.generate();
+  await send(
+    context,
+    [context.payer],
+    SystemProgram.transfer({
+      fromPubkey: context.payer.publicKey,
+      toPubkey: keypair.publicKey,
+      lamports: LAMPORTS_PER_SOL,
+    })
+  );
+  return keypair;
+}
+
+export async function accountData(
+  context: ProgramTestContext,
+  address: PublicKey
+): Promise<Uint8Array> {
+  const account = await context.banksClient.getAccount(address);
+  expect(account).not.toBeNull();
+  return account!.data;
+}
+
+export function membershipOf(board: PublicKey, participant: PublicKey): PublicKey {
+  return findBoardMembershipPda(board, participant, PROGRAM_ID)[0];
+}
+
+// The payer's first board, sitting in Setup
+export interface BoardFixture {
+  board: PublicKey;
+  facilitator: Keypair;
+}
+
+export async function createBoard(
+  context: ProgramTestContext,
+  participants: PublicKey[],
+  configure: (config: BoardConfig) => void = () => {}
+): Promise<BoardFixture> {
+  const facilitator = context.payer;
+  const [registry] = findFacilitatorRegistryPda(facilitator.publicKey, PROGRAM_ID);
+  const [board] = findBoardPda(facilitator.publicKey, 0n, PROGRAM_ID);
+
+  const config = defaultBoardConfig(participants);
+  configure(config);
+
+  await send(
+    context,
+    [facilitator],
+    createInitFacilitatorRegistryInstruction(
+      registry,
+      facilitator.publicKey,
+      PROGRAM_ID
+    ),
+    createCreateBoardInstruction(
+      registry,
+      board,
+      facilitator.publicKey,
+      config,
+      participants.map((participant) => membershipOf(board, participant)),
+      PROGRAM_ID
+    )
+  );
+
+  return { board, facilitator };
+}
+
+export async function advanceTo(
+  context: ProgramTestContext,
+  fixture: BoardFixture,
+  stages: number[]
+): Promise<void> {
+  for (const stage of stages) {
+    await send(
+      context,
+      [fixture.facilitator],
+      createAdvanceStageInstruction(
+        fixture.board,
+        fixture.facilitator.publicKey,
+        stage,
+        null,
+        PROGRAM_ID
+      )
+    );
+  }
+}
@@ -2,25 +2,15 @@ import {
   PublicKey,
   TransactionInstruction,
   SystemProgram,
+  AccountMeta,
 } from "@solana/web3.js";
 import * as borsh from "borsh";
-import {
-  findTeamRegistryPda,
-  findSessionPda,
-  findNotePda,
-  findGroupPda,
-  findParticipantEntryPda,
-  findVoteRecordPda,
-  findActionItemPda,
-  findVerificationVotePda,
-  findParticipantIdentityPda,
-} from "./pda";
 
 // Instruction discriminators (must match instructions.rs)
-const INIT_TEAM_REGISTRY = 0;
-const CREATE_SESSION = 1;
+const INIT_FACILITATOR_REGISTRY = 0;
+const CREATE_BOARD = 1;
 const ADVANCE_STAGE = 2;
-const CLOSE_SESSION = 3;
+const CLOSE_BOARD = 3;
 const CREATE_NOTE = 4;
 const CREATE_GROUP = 5;
 const SET_GROUP_TITLE = 6;
@@ -31,19 +21,85 @@ const CREATE_ACTION_ITEM = 10;
 const CAST_VERIFICATION_VOTE = 11;
 const CREATE_IDENTITY = 14;
 const UPDATE_IDENTITY = 15;
+const REVEAL_NOTE_AUTHOR = 23;
+const DELETE_NOTE = 25;
+const INIT_REPUTATION = 32;
+const CREATE_TEAM = 33;
+const SET_TEAM_MEMBER = 34;
+const REVERT_STAGE = 39;
+const RETRACT_VOTE = 40;
+
+// Board stages (must match BoardStage in state.rs)
+export const BoardStage = {
+  Setup: 0,
+  WriteNotes: 1,
+  GroupDuplicates: 2,
+  Vote: 3,
+  Discuss: 4,
+  ReviewPreviousActions: 5,
+  RevealVotes: 6,
+} as const;
+
+export const VotingMode = {
+  Linear: 0,
+  Quadratic: 1,
+  Ranked: 2,
+  Sealed: 3,
+} as const;
+
+export const TeamRole = {
+  Member: 0,
+  Facilitator: 1,
+} as const;
+
+// CreateBoard payload (must match BoardConfig in instructions.rs)
+export interface BoardConfig {
+  categories: string[];
+  allowlist: PublicKey[];
+  votingCreditsPerParticipant: number | null;
+  hideNoteAuthors: boolean;
+  predecessor: PublicKey | null;
+  stages: number[] | null;
+  stageDurationSlots: bigint[] | null;
+  votingMode: number;
+  creditBudgets: number[] | null;
+}
+
+export function defaultBoardConfig(allowlist: PublicKey[]): BoardConfig {
+  return {
+    categories: ["What went well", "What to improve"],
+    allowlist,
+    votingCreditsPerParticipant: 5,
+    hideNoteAuthors: false,
+    predecessor: null,
+    stages: null,
+    stageDurationSlots: null,
+    votingMode: VotingMode.Linear,
+    creditBudgets: null,
+  };
+}
+
+const pubkeySchema = { array: { type: "u8", len: 32 } };
+const bytes32Schema = { array: { type: "u8", len: 32 } };
 
 // Borsh schema definitions
-const createSessionSchema = {
+const createBoardSchema = {
   struct: {
     categories: { array: { type: "string" } },
-    allowlist: { array: { type: { array: { type: "u8", len: 32 } } } },
+    allowlist: { array: { type: pubkeySchema } },
     voting_credits_per_participant: { option: "u8" },
+    hide_note_authors: "bool",
+    predecessor: { option: pubkeySchema },
+    stages: { option: { array: { type: "u8" } } },
+    stage_duration_slots: { option: { array: { type: "u64" } } },
+    voting_mode: "u8",
+    credit_budgets: { option: { array: { type: "u8" } } },
   },
 };
 
-const advanceStageSchema = {
+const stageSchema = {
   struct: {
-    new_stage: "u8",
+    stage: "u8",
   },
 };
 
@@ -51,6 +107,20 @@ const createNoteSchema = {
   struct: {
     category_id: "u8",
     content: "string",
+    author_commitment: { option: bytes32Schema },
+  },
+};
+
+const revealNoteAuthorSchema = {
+  struct: {
+    salt: bytes32Schema,
+  },
+};
+
+const deleteNoteSchema = {
+  struct: {
+    note_id: "u64",
+    salt: { option: bytes32Schema },
   },
 };
 
@@ -87,12 +157,20 @@ const castVoteSchema = {
   },
 };
 
+const retractVoteSchema = {
+  struct: {
+    group_id: "u64",
+    votes: "u8",
+  },
+};
+
 const createActionItemSchema = {
   struct: {
     description: "string",
-    owner: { array: { type: "u8", len: 32 } },
-    verifiers: { array: { type: { array: { type: "u8", len: 32 } } } },
+    owner: pubkeySchema,
+    verifiers: { array: { type: pubkeySchema } },
     threshold: "u8",
+    due_at: { option: "i64" },
   },
 };
 
@@ -109,6 +187,19 @@ const identitySchema = {
   },
 };
 
+const createTeamSchema = {
+  struct: {
+    name: "string",
+  },
+};
+
+const setTeamMemberSchema = {
+  struct: {
+    member: pubkeySchema,
+    role: "u8",
+  },
+};
+
 function serializeInstruction(discriminator: number, payload?: Buffer): Buffer {
   if (payload) {
     return Buffer.concat([Buffer.from([discriminator]), payload]);
@@ -116,101 +207,152 @@ function serializeInstruction(discriminator: number, payload?: Buffer): Buffer {
   return Buffer.from([discriminator]);
 }
 
-export function createInitTeamRegistryInstruction(
-  teamAuthority: PublicKey,
+// Team boards pass the team account right after the fixed accounts
+function teamAccounts(team: PublicKey | null): AccountMeta[] {
+  return team ? [{ pubkey: team, isSigner: false, isWritable: false }] : [];
+}
+
+export function createInitFacilitatorRegistryInstruction(
+  facilitatorRegistry: PublicKey,
+  facilitator: PublicKey,
   programId: PublicKey
 ): TransactionInstruction {
-  const [teamRegistry] = findTeamRegistryPda(teamAuthority, programId);
-
   return new TransactionInstruction({
     keys: [
-      { pubkey: teamRegistry, isSigner: false, isWritable: true },
-      { pubkey: teamAuthority, isSigner: true, isWritable: true },
+      { pubkey: facilitatorRegistry, isSigner: false, isWritable: true },
+      { pubkey: facilitator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
     programId,
-    data: serializeInstruction(INIT_TEAM_REGISTRY),
+    data: serializeInstruction(INIT_FACILITATOR_REGISTRY),
   });
 }
 
-export function createCreateSessionInstruction(
-  teamRegistry: PublicKey,
-  session: PublicKey,
-  teamAuthority: PublicKey,
-  categories: string[],
-  allowlist: PublicKey[],
-  votingCreditsPerParticipant: number | null,
+// registryOrTeam is the facilitator registry, or the team for a team board
+export function createCreateBoardInstruction(
+  registryOrTeam: PublicKey,
+  board: PublicKey,
+  facilitator: PublicKey,
+  config: BoardConfig,
+  membershipAccounts: PublicKey[],
   programId: PublicKey
 ): TransactionInstruction {
   const payload = {
-    categories,
-    allowlist: allowlist.map((pk) => Array.from(pk.toBytes())),
-    voting_credits_per_participant: votingCreditsPerParticipant,
+    categories: config.categories,
+    allowlist: config.allowlist.map((pk) => Array.from(pk.toBytes())),
+    voting_credits_per_participant: config.votingCreditsPerParticipant,
+    hide_note_authors: config.hideNoteAuthors,
+    predecessor: config.predecessor
+      ? Array.from(config.predecessor.toBytes())
+      : null,
+    stages: config.stages,
+    stage_duration_slots: config.stageDurationSlots,
+    voting_mode: config.votingMode,
+    credit_budgets: config.creditBudgets,
   };
-
-  const serialized = borsh.serialize(createSessionSchema, payload);
+  const serialized = borsh.serialize(createBoardSchema, payload);
+
+  const keys: AccountMeta[] = [
+    { pubkey: registryOrTeam, isSigner: false, isWritable: true },
+    { pubkey: board, isSigner: false, isWritable: true },
+    { pubkey: facilitator, isSigner: true, isWritable: true },
+    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+  ];
+  // BoardMembership accounts for each allowlist member
+  for (const membership of membershipAccounts) {
+    keys.push({ pubkey: membership, isSigner: false, isWritable: true });
+  }
+  if (config.predecessor) {
+    keys.push({ pubkey: config.predecessor, isSigner: false, isWritable: false });
+  }
 
   return new TransactionInstruction({
-    keys: [
-      { pubkey: teamRegistry, isSigner: false, isWritable: true },
-      { pubkey: session, isSigner: false, isWritable: true },
-      { pubkey: teamAuthority, isSigner: true, isWritable: true },
-      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
-    ],
+    keys,
     programId,
-    data: serializeInstruction(CREATE_SESSION, Buffer.from(serialized)),
+    data: serializeInstruction(CREATE_BOARD, Buffer.from(serialized)),
   });
 }
 
 export function createAdvanceStageInstruction(
-  session: PublicKey,
+  board: PublicKey,
   facilitator: PublicKey,
   newStage: number,
+  team: PublicKey | null,
   programId: PublicKey
 ): TransactionInstruction {
-  const payload = { new_stage: newStage };
-  const serialized = borsh.serialize(advanceStageSchema, payload);
+  const serialized = borsh.serialize(stageSchema, { stage: newStage });
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: facilitator, isSigner: true, isWritable: false },
+      ...teamAccounts(team),
     ],
     programId,
     data: serializeInstruction(ADVANCE_STAGE, Buffer.from(serialized)),
   });
 }
 
-export function createCloseSessionInstruction(
-  session: PublicKey,
+export function createRevertStageInstruction(
+  board: PublicKey,
   facilitator: PublicKey,
+  targetStage: number,
+  team: PublicKey | null,
   programId: PublicKey
 ): TransactionInstruction {
+  const serialized = borsh.serialize(stageSchema, { stage: targetStage });
+
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: facilitator, isSigner: true, isWritable: false },
+      ...teamAccounts(team),
     ],
     programId,
-    data: serializeInstruction(CLOSE_SESSION),
+    data: serializeInstruction(REVERT_STAGE, Buffer.from(serialized)),
   });
 }
 
+export function createCloseBoardInstruction(
+  board: PublicKey,
+  facilitator: PublicKey,
+  team: PublicKey | null,
+  programId: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: board, isSigner: false, isWritable: true },
+      { pubkey: facilitator, isSigner: true, isWritable: false },
+      ...teamAccounts(team),
+    ],
+    programId,
+    data: serializeInstruction(CLOSE_BOARD),
+  });
+}
+
+// authorCommitment is sha256(author || board || salt), required on boards that hide note authors
 export function createCreateNoteInstruction(
-  session: PublicKey,
+  board: PublicKey,
   note: PublicKey,
+  membership: PublicKey,
   author: PublicKey,
   categoryId: number,
   content: string,
+  authorCommitment: Uint8Array | null,
   programId: PublicKey
 ): TransactionInstruction {
-  const payload = { category_id: categoryId, content };
+  const payload = {
+    category_id: categoryId,
+    content,
+    author_commitment: authorCommitment ? Array.from(authorCommitment) : null,
+  };
   const serialized = borsh.serialize(createNoteSchema, payload);
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: note, isSigner: false, isWritable: true },
+      { pubkey: membership, isSigner: false, isWritable: false },
       { pubkey: author, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
@@ -219,20 +361,67 @@ export function createCreateNoteInstruction(
   });
 }
 
+export function createRevealNoteAuthorInstruction(
+  board: PublicKey,
+  note: PublicKey,
+  author: PublicKey,
+  salt: Uint8Array,
+  programId: PublicKey
+): TransactionInstruction {
+  const serialized = borsh.serialize(revealNoteAuthorSchema, {
+    salt: Array.from(salt),
+  });
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: board, isSigner: false, isWritable: false },
+      { pubkey: note, isSigner: false, isWritable: true },
+      { pubkey: author, isSigner: true, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(REVEAL_NOTE_AUTHOR, Buffer.from(serialized)),
+  });
+}
+
+export function createDeleteNoteInstruction(
+  board: PublicKey,
+  note: PublicKey,
+  rentRecipient: PublicKey,
+  author: PublicKey,
+  noteId: bigint,
+  salt: Uint8Array | null,
+  programId: PublicKey
+): TransactionInstruction {
+  const payload = { note_id: noteId, salt: salt ? Array.from(salt) : null };
+  const serialized = borsh.serialize(deleteNoteSchema, payload);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: board, isSigner: false, isWritable: false },
+      { pubkey: note, isSigner: false, isWritable: true },
+      { pubkey: rentRecipient, isSigner: false, isWritable: true },
+      { pubkey: author, isSigner: true, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(DELETE_NOTE, Buffer.from(serialized)),
+  });
+}
+
 export function createCreateGroupInstruction(
-  session: PublicKey,
+  board: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   creator: PublicKey,
   title: string,
   programId: PublicKey
 ): TransactionInstruction {
-  const payload = { title };
-  const serialized = borsh.serialize(createGroupSchema, payload);
+  const serialized = borsh.serialize(createGroupSchema, { title });
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: group, isSigner: false, isWritable: true },
+      { pubkey: membership, isSigner: false, isWritable: false },
       { pubkey: creator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
@@ -242,8 +431,9 @@ export function createCreateGroupInstruction(
 }
 
 export function createSetGroupTitleInstruction(
-  session: PublicKey,
+  board: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   participant: PublicKey,
   groupId: bigint,
   title: string,
@@ -254,8 +444,9 @@ export function createSetGroupTitleInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: false },
+      { pubkey: board, isSigner: false, isWritable: false },
       { pubkey: group, isSigner: false, isWritable: true },
+      { pubkey: membership, isSigner: false, isWritable: false },
       { pubkey: participant, isSigner: true, isWritable: false },
     ],
     programId,
@@ -264,9 +455,10 @@ export function createSetGroupTitleInstruction(
 }
 
 export function createAssignNoteToGroupInstruction(
-  session: PublicKey,
+  board: PublicKey,
   note: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   participant: PublicKey,
   noteId: bigint,
   groupId: bigint,
@@ -277,9 +469,10 @@ export function createAssignNoteToGroupInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: false },
+      { pubkey: board, isSigner: false, isWritable: false },
       { pubkey: note, isSigner: false, isWritable: true },
-      { pubkey: group, isSigner: false, isWritable: false },
+      { pubkey: group, isSigner: false, isWritable: true },
+      { pubkey: membership, isSigner: false, isWritable: false },
       { pubkey: participant, isSigner: true, isWritable: false },
     ],
     programId,
@@ -287,20 +480,24 @@ export function createAssignNoteToGroupInstruction(
   });
 }
 
+// group is the note's current group, whose note count drops by one
 export function createUnassignNoteInstruction(
-  session: PublicKey,
+  board: PublicKey,
   note: PublicKey,
+  group: PublicKey,
+  membership: PublicKey,
   participant: PublicKey,
   noteId: bigint,
   programId: PublicKey
 ): TransactionInstruction {
-  const payload = { note_id: noteId };
-  const serialized = borsh.serialize(unassignNoteSchema, payload);
+  const serialized = borsh.serialize(unassignNoteSchema, { note_id: noteId });
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: false },
+      { pubkey: board, isSigner: false, isWritable: false },
       { pubkey: note, isSigner: false, isWritable: true },
+      { pubkey: group, isSigner: false, isWritable: true },
+      { pubkey: membership, isSigner: false, isWritable: false },
       { pubkey: participant, isSigner: true, isWritable: false },
     ],
     programId,
@@ -309,8 +506,8 @@ export function createUnassignNoteInstruction(
 }
 
 export function createCastVoteInstruction(
-  session: PublicKey,
-  participantEntry: PublicKey,
+  board: PublicKey,
+  membership: PublicKey,
   group: PublicKey,
   voteRecord: PublicKey,
   voter: PublicKey,
@@ -323,8 +520,8 @@ export function createCastVoteInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: false },
-      { pubkey: participantEntry, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: false },
+      { pubkey: membership, isSigner: false, isWritable: true },
       { pubkey: group, isSigner: false, isWritable: true },
       { pubkey: voteRecord, isSigner: false, isWritable: true },
       { pubkey: voter, isSigner: true, isWritable: true },
@@ -335,23 +532,47 @@ export function createCastVoteInstruction(
   });
 }
 
-// Session stages
-export const SessionStage = {
-  Setup: 0,
-  WriteNotes: 1,
-  GroupDuplicates: 2,
-  Vote: 3,
-  Discuss: 4,
-} as const;
+export function createRetractVoteInstruction(
+  board: PublicKey,
+  membership: PublicKey,
+  group: PublicKey,
+  voteRecord: PublicKey,
+  rentRecipient: PublicKey,
+  voter: PublicKey,
+  groupId: bigint,
+  votes: number,
+  programId: PublicKey
+): TransactionInstruction {
+  const payload = { group_id: groupId, votes };
+  const serialized = borsh.serialize(retractVoteSchema, payload);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: board, isSigner: false, isWritable: false },
+      { pubkey: membership, isSigner: false, isWritable: true },
+      { pubkey: group, isSigner: false, isWritable: true },
+      { pubkey: voteRecord, isSigner: false, isWritable: true },
+      { pubkey: rentRecipient, isSigner: false, isWritable: true },
+      { pubkey: voter, isSigner: true, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(RETRACT_VOTE, Buffer.from(serialized)),
+  });
+}
 
+// ownerMembership and verifierMemberships prove everyone named is on the allowlist
 export function createCreateActionItemInstruction(
-  session: PublicKey,
+  board: PublicKey,
   actionItem: PublicKey,
   facilitator: PublicKey,
+  team: PublicKey | null,
   description: string,
   owner: PublicKey,
   verifiers: PublicKey[],
   threshold: number,
+  dueAt: bigint | null,
+  ownerMembership: PublicKey,
+  verifierMemberships: PublicKey[],
   programId: PublicKey
 ): TransactionInstruction {
   const payload = {
@@ -359,26 +580,36 @@ export function createCreateActionItemInstruction(
     owner: Array.from(owner.toBytes()),
     verifiers: verifiers.map((pk) => Array.from(pk.toBytes())),
     threshold,
+    due_at: dueAt,
   };
   const serialized = borsh.serialize(createActionItemSchema, payload);
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: true },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: actionItem, isSigner: false, isWritable: true },
       { pubkey: facilitator, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      ...teamAccounts(team),
+      { pubkey: ownerMembership, isSigner: false, isWritable: false },
+      ...verifierMemberships.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+      })),
     ],
     programId,
     data: serializeInstruction(CREATE_ACTION_ITEM, Buffer.from(serialized)),
   });
 }
 
+// ownerReputation is only updated if the owner has created one, but must always be passed
 export function createCastVerificationVoteInstruction(
-  session: PublicKey,
+  board: PublicKey,
   actionItem: PublicKey,
   verificationVote: PublicKey,
   ownerMembership: PublicKey,
+  ownerReputation: PublicKey,
   verifier: PublicKey,
   actionItemId: bigint,
   approved: boolean,
@@ -392,10 +623,11 @@ export function createCastVerificationVoteInstruction(
 
   return new TransactionInstruction({
     keys: [
-      { pubkey: session, isSigner: false, isWritable: false },
+      { pubkey: board, isSigner: false, isWritable: true },
       { pubkey: actionItem, isSigner: false, isWritable: true },
       { pubkey: verificationVote, isSigner: false, isWritable: true },
       { pubkey: ownerMembership, isSigner: false, isWritable: true },
+      { pubkey: ownerReputation, isSigner: false, isWritable: true },
       { pubkey: verifier, isSigner: true, isWritable: true },
       { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
     ],
@@ -442,3 +674,60 @@ export function createUpdateIdentityInstruction(
     data: serializeInstruction(UPDATE_IDENTITY, Buffer.from(serialized)),
   });
 }
+
+export function createInitReputationInstruction(
+  reputation: PublicKey,
+  identity: PublicKey,
+  authority: PublicKey,
+  programId: PublicKey
+): TransactionInstruction {
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: reputation, isSigner: false, isWritable: true },
+      { pubkey: identity, isSigner: false, isWritable: false },
+      { pubkey: authority, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(INIT_REPUTATION),
+  });
+}
+
+export function createCreateTeamInstruction(
+  team: PublicKey,
+  creator: PublicKey,
+  name: string,
+  programId: PublicKey
+): TransactionInstruction {
+  const serialized = borsh.serialize(createTeamSchema, { name });
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: team, isSigner: false, isWritable: true },
+      { pubkey: creator, isSigner: true, isWritable: true },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(CREATE_TEAM, Buffer.from(serialized)),
+  });
+}
+
+export function createSetTeamMemberInstruction(
+  team: PublicKey,
+  facilitator: PublicKey,
+  member: PublicKey,
+  role: number,
+  programId: PublicKey
+): TransactionInstruction {
+  const payload = { member: Array.from(member.toBytes()), role };
+  const serialized = borsh.serialize(setTeamMemberSchema, payload);
+
+  return new TransactionInstruction({
+    keys: [
+      { pubkey: team, isSigner: false, isWritable: true },
+      { pubkey: facilitator, isSigner: true, isWritable: false },
+    ],
+    programId,
+    data: serializeInstruction(SET_TEAM_MEMBER, Buffer.from(serialized)),
+  });
+}
@@ -1,98 +1,98 @@
 import { PublicKey } from "@solana/web3.js";
 
 // PDA Seeds (must match state.rs)
-const TEAM_REGISTRY_SEED = Buffer.from("team_registry");
-const SESSION_SEED = Buffer.from("session");
-const PARTICIPANT_SEED = Buffer.from("participant");
+const FACILITATOR_REGISTRY_SEED = Buffer.from("facilitator_registry");
+const BOARD_SEED = Buffer.from("board");
+const MEMBERSHIP_SEED = Buffer.from("membership");
 const NOTE_SEED = Buffer.from("note");
 const GROUP_SEED = Buffer.from("group");
 const VOTE_SEED = Buffer.from("vote");
 const ACTION_ITEM_SEED = Buffer.from("action_item");
 const VERIFICATION_VOTE_SEED = Buffer.from("verification_vote");
+const PARTICIPANT_IDENTITY_SEED = Buffer.from("participant");
+const REPUTATION_SEED = Buffer.from("reputation");
+const TEAM_SEED = Buffer.from("team");
+
+function u64Bytes(value: bigint): Buffer {
+  const buffer = Buffer.alloc(8);
+  buffer.writeBigUInt64LE(value);
+  return buffer;
+}
 
-export function findTeamRegistryPda(
-  teamAuthority: PublicKey,
+export function findFacilitatorRegistryPda(
+  facilitator: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [TEAM_REGISTRY_SEED, teamAuthority.toBuffer()],
+    [FACILITATOR_REGISTRY_SEED, facilitator.toBuffer()],
     programId
   );
 }
 
-export function findSessionPda(
-  teamAuthority: PublicKey,
-  sessionIndex: bigint,
+// Boards are indexed per facilitator registry, or per team for team boards
+export function findBoardPda(
+  authority: PublicKey,
+  boardIndex: bigint,
   programId: PublicKey
 ): [PublicKey, number] {
-  const sessionIndexBuffer = Buffer.alloc(8);
-  sessionIndexBuffer.writeBigUInt64LE(sessionIndex);
   return PublicKey.findProgramAddressSync(
-    [SESSION_SEED, teamAuthority.toBuffer(), sessionIndexBuffer],
+    [BOARD_SEED, authority.toBuffer(), u64Bytes(boardIndex)],
     programId
   );
 }
 
-export function findParticipantEntryPda(
-  session: PublicKey,
+export function findBoardMembershipPda(
+  board: PublicKey,
   participant: PublicKey,
   programId: PublicKey
 ): [PublicKey, number] {
   return PublicKey.findProgramAddressSync(
-    [PARTICIPANT_SEED, session.toBuffer(), participant.toBuffer()],
+    [MEMBERSHIP_SEED, board.toBuffer(), participant.toBuffer()],
     programId
   );
 }
 
 export function findNotePda(
-  session: PublicKey,
+  board: PublicKey,
   noteId: bigint,
   programId: PublicKey
 ): [PublicKey, number] {
-  const noteIdBuffer = Buffer.alloc(8);
-  noteIdBuffer.writeBigUInt64LE(noteId);
   return PublicKey.findProgramAddressSync(
-    [NOTE_SEED, session.toBuffer(), noteIdBuffer],
+    [NOTE_SEED, board.toBuffer(), u64Bytes(noteId)],
     programId
   );
 }
 
 export function findGroupPda(
-  session: PublicKey,
+  board: PublicKey,
   groupId: bigint,
   programId: PublicKey
 ): [PublicKey, number] {
-  const groupIdBuffer = Buffer.alloc(8);
-  groupIdBuffer.writeBigUInt64LE(groupId);
   return PublicKey.findProgramAddressSync(
-    [GROUP_SEED, session.toBuffer(), groupIdBuffer],
+    [GROUP_SEED, board.toBuffer(), u64Bytes(groupId)],
     programId
   );
 }
 
 export function findVoteRecordPda(
-  session: PublicKey,
+  board: PublicKey,
   participant: PublicKey,
   groupId: bigint,
   programId: PublicKey
 ): [PublicKey, number] {
-  const groupIdBuffer = Buffer.alloc(8);
-  groupIdBuffer.writeBigUInt64LE(groupId);
   return PublicKey.findProgramAddressSync(
-    [VOTE_SEED, session.toBuffer(), participant.toBuffer(), groupIdBuffer],
+    [VOTE_SEED, board.toBuffer(), participant.toBuffer(), u64Bytes(groupId)],
     programId
   );
 }
 
 export function findActionItemPda(
-  session: PublicKey,
+  board: PublicKey,
   actionItemId: bigint,
   programId: PublicKey
 ): [PublicKey, number] {
-  const actionItemIdBuffer = Buffer.alloc(8);
-  actionItemIdBuffer.writeBigUInt64LE(actionItemId);
   return PublicKey.findProgramAddressSync(
-    [ACTION_ITEM_SEED, session.toBuffer(), actionItemIdBuffer],
+    [ACTION_ITEM_SEED, board.toBuffer(), u64Bytes(actionItemId)],
     programId
   );
 }
@@ -109,9 +109,6 @@ export function findVerificationVotePda(
 }
 
 // Participant identity PDA (seeds: ["participant", authority])
-// Note: This reuses PARTICIPANT_SEED but with different PDA structure
-const PARTICIPANT_IDENTITY_SEED = Buffer.from("participant");
-
 export function findParticipantIdentityPda(
   authority: PublicKey,
   programId: PublicKey
@@ -121,3 +118,26 @@ export function findParticipantIdentityPda(
     programId
   );
 }
+
+// Reputation hangs off the identity (seeds: ["reputation", identity])
+export function findReputationPda(
+  authority: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  const [identity] = findParticipantIdentityPda(authority, programId);
+  return PublicKey.findProgramAddressSync(
+    [REPUTATION_SEED, identity.toBuffer()],
+    programId
+  );
+}
+
+export function findTeamPda(
+  creator: PublicKey,
+  name: string,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [TEAM_SEED, creator.toBuffer(), Buffer.from(name)],
+    programId
+  );
+}
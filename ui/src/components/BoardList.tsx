@@ -228,7 +228,7 @@ export const BoardList: React.FC = () => {
 
       <div className="text-gray-400 text-sm space-y-1">
         <p>{board.data.categories.length} categories</p>
-        <p>{board.data.allowlist.length} participants</p>
+        <p>{board.data.membershipCount} participants</p>
         <p>{board.data.noteCount.toString()} notes</p>
         <p>{board.data.groupCount.toString()} groups</p>
       </div>
@@ -23,24 +23,38 @@ export const BoardView: React.FC = () => {
   const { canSign, getSessionSigner, getSessionTokenAddress } = useSession();
 
   const boardAddress = id ? new PublicKey(id) : null;
-  const { board, notes, groups, actionItems, membership, identities, loading, error, refresh } =
-    useBoard(boardAddress);
+  const {
+    board,
+    notes,
+    groups,
+    actionItems,
+    membership,
+    memberships,
+    identities,
+    loading,
+    error,
+    refresh,
+  } = useBoard(boardAddress);
 
   const [advancing, setAdvancing] = React.useState(false);
 
   const isFacilitator =
     board && publicKey && board.facilitator.equals(publicKey);
-  const isOnAllowlist =
-    board &&
-    publicKey &&
-    board.allowlist.some((pk) => pk.equals(publicKey));
+  // Holding a BoardMembership is what puts a wallet on the allowlist
+  const isOnAllowlist = membership !== null;
+
+  // The board moves through its own stage pipeline rather than every stage in order
+  const stagePosition = board ? board.stages.indexOf(board.stage) : -1;
+  const nextStage =
+    board && stagePosition + 1 < board.stages.length
+      ? board.stages[stagePosition + 1]
+      : null;
 
   const handleAdvanceStage = async () => {
-    if (!board || !publicKey || !boardAddress) return;
+    if (!board || !publicKey || !boardAddress || nextStage === null) return;
 
     try {
       setAdvancing(true);
-      const nextStage = board.stage + 1;
 
       console.log("Advancing stage:", {
         boardAddress: boardAddress.toString(),
@@ -59,6 +73,7 @@ export const BoardView: React.FC = () => {
           boardAddress,
           sessionSigner.publicKey,
           nextStage,
+          board.team,
           PROGRAM_ID,
           sessionToken
         );
@@ -70,6 +85,7 @@ export const BoardView: React.FC = () => {
           boardAddress,
           publicKey,
           nextStage,
+          board.team,
           PROGRAM_ID
         );
         await sendInstructions([instruction]);
@@ -102,6 +118,7 @@ export const BoardView: React.FC = () => {
         const instruction = createCloseBoardInstruction(
           boardAddress,
           sessionSigner.publicKey,
+          board.team,
           PROGRAM_ID,
           sessionToken
         );
@@ -112,6 +129,7 @@ export const BoardView: React.FC = () => {
         const instruction = createCloseBoardInstruction(
           boardAddress,
           publicKey,
+          board.team,
           PROGRAM_ID
         );
         await sendInstructions([instruction]);
@@ -156,9 +174,10 @@ export const BoardView: React.FC = () => {
       groups,
       actionItems,
       membership,
+      memberships,
       boardAddress: boardAddress!,
       refresh,
-      isOnAllowlist: isOnAllowlist || false,
+      isOnAllowlist,
       identities,
     };
 
@@ -174,7 +193,12 @@ export const BoardView: React.FC = () => {
       case BoardStage.Discuss:
         return <DiscussStage {...props} />;
       default:
-        return null;
+        return (
+          <p className="text-gray-400">
+            The {STAGE_NAMES[board.stage]} stage is not available in this app
+            yet.
+          </p>
+        );
     }
   };
 
@@ -207,16 +231,16 @@ export const BoardView: React.FC = () => {
           {/* Facilitator Controls */}
           {isFacilitator && !board.closed && (
             <div className="flex gap-2">
-              {board.stage < BoardStage.Discuss && (
+              {nextStage !== null && (
                 <button
                   onClick={handleAdvanceStage}
                   disabled={advancing}
                   className="px-4 py-2 bg-green-600 hover:bg-green-700 disabled:bg-gray-600 rounded-lg font-medium"
                 >
-                  {advancing ? "..." : `Advance to ${STAGE_NAMES[(board.stage + 1) as BoardStage]}`}
+                  {advancing ? "..." : `Advance to ${STAGE_NAMES[nextStage]}`}
                 </button>
               )}
-              {board.stage === BoardStage.Discuss && (
+              {nextStage === null && (
                 <button
                   onClick={handleCloseBoard}
                   disabled={advancing}
@@ -233,34 +257,34 @@ export const BoardView: React.FC = () => {
       {/* Stage Progress */}
       <div className="mb-8">
         <div className="flex items-center justify-between max-w-2xl">
-          {Object.values(STAGE_NAMES).map((name, index) => (
-            <React.Fragment key={name}>
+          {board.stages.map((stage, index) => (
+            <React.Fragment key={stage}>
               <div className="flex flex-col items-center">
                 <div
                   className={`w-8 h-8 rounded-full flex items-center justify-center text-sm font-medium ${
-                    index < board.stage
+                    index < stagePosition
                       ? "bg-green-500 text-white"
-                      : index === board.stage
+                      : index === stagePosition
                       ? "bg-purple-500 text-white"
                       : "bg-gray-700 text-gray-400"
                   }`}
                 >
-                  {index < board.stage ? "✓" : index + 1}
+                  {index < stagePosition ? "✓" : index + 1}
                 </div>
                 <span
                   className={`text-xs mt-1 ${
-                    index === board.stage
+                    index === stagePosition
                       ? "text-purple-400"
                       : "text-gray-500"
                   }`}
                 >
-                  {name}
+                  {STAGE_NAMES[stage]}
                 </span>
               </div>
-              {index < 4 && (
+              {index < board.stages.length - 1 && (
                 <div
                   className={`flex-1 h-0.5 mx-2 ${
-                    index < board.stage ? "bg-green-500" : "bg-gray-700"
+                    index < stagePosition ? "bg-green-500" : "bg-gray-700"
                   }`}
                 />
               )}
@@ -11,8 +11,10 @@ import {
   findFacilitatorRegistryPda,
   findBoardPda,
   findBoardMembershipPda,
+  BoardConfig,
 } from "../utils/instructions";
 import { deserializeFacilitatorRegistry } from "../utils/deserialize";
+import { MAX_INITIAL_PARTICIPANTS, VotingMode } from "../types";
 
 export const CreateBoard: React.FC = () => {
   const navigate = useNavigate();
@@ -29,6 +31,8 @@ export const CreateBoard: React.FC = () => {
   const [allowlistInput, setAllowlistInput] = useState("");
   const [allowlist, setAllowlist] = useState<string[]>([]);
   const [votingCredits, setVotingCredits] = useState(5);
+  const [votingMode, setVotingMode] = useState<VotingMode>(VotingMode.Linear);
+  const [hideNoteAuthors, setHideNoteAuthors] = useState(false);
   const [loading, setLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
@@ -102,6 +106,19 @@ export const CreateBoard: React.FC = () => {
         return pda;
       });
 
+      // Default stage pipeline, no timeboxes and an equal budget for everyone
+      const config: BoardConfig = {
+        categories,
+        allowlist: allowlistPubkeys,
+        votingCreditsPerParticipant: votingCredits,
+        hideNoteAuthors,
+        predecessor: null,
+        stages: null,
+        stageDurationSlots: null,
+        votingMode,
+        creditBudgets: null,
+      };
+
       // Use session signing only if registry exists (to avoid mixing session and wallet signing)
       if (canSign() && !needsRegistryInit) {
         const sessionSigner = getSessionSigner()!;
@@ -112,9 +129,7 @@ export const CreateBoard: React.FC = () => {
             facilitatorRegistryPda,
             boardPda,
             sessionSigner.publicKey,
-            categories,
-            allowlistPubkeys,
-            votingCredits,
+            config,
             membershipPdas,
             programId,
             sessionToken
@@ -130,9 +145,7 @@ export const CreateBoard: React.FC = () => {
             facilitatorRegistryPda,
             boardPda,
             publicKey,
-            categories,
-            allowlistPubkeys,
-            votingCredits,
+            config,
             membershipPdas,
             programId
           )
@@ -222,7 +235,7 @@ export const CreateBoard: React.FC = () => {
         <div className="bg-gray-800 rounded-xl p-6 border border-gray-700">
           <h2 className="text-lg font-medium mb-4">Participants (Allowlist)</h2>
           <p className="text-gray-400 text-sm mb-4">
-            Add wallet addresses of participants (max 8)
+            Add wallet addresses of participants (max {MAX_INITIAL_PARTICIPANTS})
           </p>
 
           <div className="space-y-2 mb-4">
@@ -245,7 +258,7 @@ export const CreateBoard: React.FC = () => {
             ))}
           </div>
 
-          {allowlist.length < 8 && (
+          {allowlist.length < MAX_INITIAL_PARTICIPANTS && (
             <div className="flex gap-2">
               <input
                 type="text"
@@ -288,6 +301,44 @@ export const CreateBoard: React.FC = () => {
             </span>
             <span>20</span>
           </div>
+
+          <div className="flex gap-2 mt-6">
+            {[VotingMode.Linear, VotingMode.Quadratic].map((mode) => (
+              <button
+                key={mode}
+                type="button"
+                onClick={() => setVotingMode(mode)}
+                className={`px-3 py-1 rounded-full text-sm font-medium transition-colors ${
+                  votingMode === mode
+                    ? "bg-purple-600 text-white"
+                    : "bg-gray-600 text-gray-300 hover:bg-gray-500"
+                }`}
+              >
+                {mode === VotingMode.Linear ? "Linear" : "Quadratic"}
+              </button>
+            ))}
+          </div>
+          <p className="text-gray-400 text-sm mt-2">
+            {votingMode === VotingMode.Linear
+              ? "Each vote costs one credit."
+              : "n votes on one group cost n² credits."}
+          </p>
+        </div>
+
+        {/* Note Authors */}
+        <div className="bg-gray-800 rounded-xl p-6 border border-gray-700">
+          <label className="flex items-center gap-3">
+            <input
+              type="checkbox"
+              checked={hideNoteAuthors}
+              onChange={(e) => setHideNoteAuthors(e.target.checked)}
+            />
+            <span className="text-lg font-medium">Hide note authors</span>
+          </label>
+          <p className="text-gray-400 text-sm mt-2">
+            Notes are stored without their author until the author chooses to
+            reveal themselves.
+          </p>
         </div>
 
         {/* Error */}
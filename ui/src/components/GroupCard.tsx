@@ -1,5 +1,6 @@
 import React from "react";
 import { GroupWithAddress, RetroBoard } from "../types";
+import { currentVoteTally } from "../utils/voteRound";
 
 interface Props {
   group: GroupWithAddress;
@@ -30,7 +31,7 @@ export const GroupCard: React.FC<Props> = ({
       <div className="flex justify-between items-start mb-3">
         <h4 className="font-medium text-lg">{group.data.title}</h4>
         <span className="px-2 py-1 bg-purple-500/20 text-purple-300 rounded text-sm">
-          {group.data.voteTally.toString()} votes
+          {currentVoteTally(group.data, board).toString()} votes
         </span>
       </div>
 
@@ -1,12 +1,14 @@
 import React from "react";
 import { NoteWithAddress } from "../types";
 import { UsernameDisplay } from "./UsernameDisplay";
+import { isAuthorHidden } from "../utils/noteAuthor";
 
 interface Props {
   note: NoteWithAddress;
   categoryName?: string;
   showCategory?: boolean;
   identities?: Map<string, string>;
+  onRevealAuthor?: () => void;
 }
 
 export const NoteCard: React.FC<Props> = ({
@@ -14,6 +16,7 @@ export const NoteCard: React.FC<Props> = ({
   categoryName,
   showCategory = false,
   identities = new Map(),
+  onRevealAuthor,
 }) => {
   return (
     <div className="bg-gray-800 rounded-lg p-3 border border-gray-700 hover:border-gray-600 transition-colors">
@@ -22,7 +25,21 @@ export const NoteCard: React.FC<Props> = ({
       )}
       <p className="text-gray-200 text-sm">{note.data.content}</p>
       <div className="flex justify-between items-center mt-2 text-xs text-gray-500">
-        <UsernameDisplay address={note.data.author} identities={identities} />
+        {isAuthorHidden(note.data.author) ? (
+          <span className="italic">
+            Hidden author
+            {onRevealAuthor && (
+              <button
+                onClick={onRevealAuthor}
+                className="ml-2 text-purple-400 hover:text-purple-300 not-italic"
+              >
+                Reveal me
+              </button>
+            )}
+          </span>
+        ) : (
+          <UsernameDisplay address={note.data.author} identities={identities} />
+        )}
         {note.data.groupId !== null && (
           <span className="text-purple-400">Grouped</span>
         )}
@@ -9,6 +9,7 @@ import {
   findActionItemPda,
   findVerificationVotePda,
   findBoardMembershipPda,
+  findReputationPda,
 } from "../../utils/instructions";
 import {
   RetroBoard,
@@ -16,10 +17,13 @@ import {
   GroupWithAddress,
   ActionItemWithAddress,
   BoardMembership,
+  BoardMembershipWithAddress,
   ActionItemStatus,
+  ACTION_ITEM_STATUS_NAMES,
   PROGRAM_ID,
 } from "../../types";
 import { UsernameDisplay, getDisplayName } from "../UsernameDisplay";
+import { currentVoteTally } from "../../utils/voteRound";
 
 interface Props {
   board: RetroBoard;
@@ -27,6 +31,7 @@ interface Props {
   groups: GroupWithAddress[];
   actionItems: ActionItemWithAddress[];
   membership: BoardMembership | null;
+  memberships: BoardMembershipWithAddress[];
   boardAddress: PublicKey;
   refresh: () => Promise<void>;
   isOnAllowlist: boolean;
@@ -38,6 +43,7 @@ export const DiscussStage: React.FC<Props> = ({
   notes,
   groups,
   actionItems,
+  memberships,
   boardAddress,
   refresh,
   isOnAllowlist,
@@ -80,7 +86,8 @@ export const DiscussStage: React.FC<Props> = ({
 
   // Sort by vote count (descending)
   const sortedGroups = [...groupsWithNotes].sort(
-    (a, b) => Number(b.data.voteTally - a.data.voteTally)
+    (a, b) =>
+      Number(currentVoteTally(b.data, board) - currentVoteTally(a.data, board))
   );
 
   // Get ungrouped notes
@@ -88,12 +95,12 @@ export const DiscussStage: React.FC<Props> = ({
 
   // Calculate total votes
   const totalVotes = groups.reduce(
-    (sum, g) => sum + Number(g.data.voteTally),
+    (sum, g) => sum + Number(currentVoteTally(g.data, board)),
     0
   );
 
   // Get available participants for owner/verifier selection (excludes facilitator)
-  const participantsForSelection = board.allowlist;
+  const participantsForSelection = memberships.map((m) => m.data.participant);
 
   const handleCreateActionItem = async () => {
     if (!publicKey || !isFacilitator || !description.trim() || !selectedOwner) return;
@@ -107,6 +114,17 @@ export const DiscussStage: React.FC<Props> = ({
         board.actionItemCount,
         PROGRAM_ID
       );
+      const owner = new PublicKey(selectedOwner);
+      const verifiers = selectedVerifiers.map((v) => new PublicKey(v));
+      // The owner and every verifier must hold a membership on this board
+      const [ownerMembershipPda] = findBoardMembershipPda(
+        boardAddress,
+        owner,
+        PROGRAM_ID
+      );
+      const verifierMembershipPdas = verifiers.map(
+        (v) => findBoardMembershipPda(boardAddress, v, PROGRAM_ID)[0]
+      );
 
       if (canSign()) {
         const sessionSigner = getSessionSigner()!;
@@ -116,10 +134,14 @@ export const DiscussStage: React.FC<Props> = ({
           boardAddress,
           actionItemPda,
           sessionSigner.publicKey,
+          board.team,
           description.trim(),
-          new PublicKey(selectedOwner),
-          selectedVerifiers.map((v) => new PublicKey(v)),
+          owner,
+          verifiers,
           threshold,
+          null,
+          ownerMembershipPda,
+          verifierMembershipPdas,
           PROGRAM_ID,
           sessionToken
         );
@@ -132,10 +154,14 @@ export const DiscussStage: React.FC<Props> = ({
           boardAddress,
           actionItemPda,
           publicKey,
+          board.team,
           description.trim(),
-          new PublicKey(selectedOwner),
-          selectedVerifiers.map((v) => new PublicKey(v)),
+          owner,
+          verifiers,
           threshold,
+          null,
+          ownerMembershipPda,
+          verifierMembershipPdas,
           PROGRAM_ID
         );
 
@@ -178,6 +204,10 @@ export const DiscussStage: React.FC<Props> = ({
         actionItem.data.owner,
         PROGRAM_ID
       );
+      const [ownerReputationPda] = findReputationPda(
+        actionItem.data.owner,
+        PROGRAM_ID
+      );
 
       if (canSign()) {
         const sessionSigner = getSessionSigner()!;
@@ -188,6 +218,7 @@ export const DiscussStage: React.FC<Props> = ({
           actionItem.address,
           verificationVotePda,
           ownerMembershipPda,
+          ownerReputationPda,
           sessionSigner.publicKey,
           actionItem.data.actionItemId,
           approved,
@@ -204,6 +235,7 @@ export const DiscussStage: React.FC<Props> = ({
           actionItem.address,
           verificationVotePda,
           ownerMembershipPda,
+          ownerReputationPda,
           publicKey,
           actionItem.data.actionItemId,
           approved,
@@ -230,7 +262,7 @@ export const DiscussStage: React.FC<Props> = ({
 
   const canVerify = (actionItem: ActionItemWithAddress) => {
     if (!publicKey || !board.closed) return false;
-    if (actionItem.data.status === ActionItemStatus.Completed) return false;
+    if (actionItem.data.status !== ActionItemStatus.Pending) return false;
     return actionItem.data.verifiers.some((v) => v.equals(publicKey));
   };
 
@@ -402,7 +434,9 @@ export const DiscussStage: React.FC<Props> = ({
                 className={`rounded-lg p-4 ${
                   item.data.status === ActionItemStatus.Completed
                     ? "bg-green-900/20 border border-green-600/30"
-                    : "bg-gray-700/50"
+                    : item.data.status === ActionItemStatus.Pending
+                    ? "bg-gray-700/50"
+                    : "bg-red-900/20 border border-red-600/30"
                 }`}
               >
                 <div className="flex items-start justify-between gap-4">
@@ -412,12 +446,12 @@ export const DiscussStage: React.FC<Props> = ({
                         className={`px-2 py-0.5 rounded text-xs font-medium ${
                           item.data.status === ActionItemStatus.Completed
                             ? "bg-green-600 text-white"
-                            : "bg-yellow-600 text-white"
+                            : item.data.status === ActionItemStatus.Pending
+                            ? "bg-yellow-600 text-white"
+                            : "bg-red-600 text-white"
                         }`}
                       >
-                        {item.data.status === ActionItemStatus.Completed
-                          ? "Completed"
-                          : "Pending"}
+                        {ACTION_ITEM_STATUS_NAMES[item.data.status]}
                       </span>
                       <span className="text-gray-400 text-sm">
                         Owner: <UsernameDisplay address={item.data.owner} identities={identities} />
@@ -428,6 +462,8 @@ export const DiscussStage: React.FC<Props> = ({
                       Approvals: {item.data.approvals} / {item.data.threshold}{" "}
                       needed
                       <span className="mx-2">|</span>
+                      Rejections: {item.data.rejections}
+                      <span className="mx-2">|</span>
                       Verifiers: {item.data.verifiers.length}
                     </div>
                   </div>
@@ -501,7 +537,7 @@ export const DiscussStage: React.FC<Props> = ({
                     <div className="flex items-center gap-3 mb-2">
                       <h4 className="text-lg font-medium">{group.data.title}</h4>
                       <span className="px-2 py-1 bg-purple-500/20 text-purple-300 rounded text-sm">
-                        {group.data.voteTally.toString()} votes
+                        {currentVoteTally(group.data, board).toString()} votes
                       </span>
                     </div>
 
@@ -552,7 +588,7 @@ export const DiscussStage: React.FC<Props> = ({
       {/* Board info */}
       <div className="border-t border-gray-700 pt-6 text-sm text-gray-500">
         <p>Board #{board.boardIndex.toString()}</p>
-        <p>{board.allowlist.length} participants</p>
+        <p>{board.membershipCount} participants</p>
         <p>{board.votingCreditsPerParticipant} credits per participant</p>
       </div>
     </div>
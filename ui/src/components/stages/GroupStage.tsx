@@ -9,6 +9,7 @@ import {
   createUnassignNoteInstruction,
   findGroupPda,
   findNotePda,
+  findBoardMembershipPda,
 } from "../../utils/instructions";
 import {
   RetroBoard,
@@ -53,6 +54,11 @@ export const GroupStage: React.FC<Props> = ({
     try {
       setCreating(true);
       const [groupPda] = findGroupPda(boardAddress, board.groupCount, PROGRAM_ID);
+      const [membershipPda] = findBoardMembershipPda(
+        boardAddress,
+        publicKey,
+        PROGRAM_ID
+      );
 
       if (canSign()) {
         const sessionSigner = getSessionSigner()!;
@@ -61,6 +67,7 @@ export const GroupStage: React.FC<Props> = ({
         const instruction = createCreateGroupInstruction(
           boardAddress,
           groupPda,
+          membershipPda,
           sessionSigner.publicKey,
           newGroupTitle.trim(),
           PROGRAM_ID,
@@ -73,6 +80,7 @@ export const GroupStage: React.FC<Props> = ({
         const instruction = createCreateGroupInstruction(
           boardAddress,
           groupPda,
+          membershipPda,
           publicKey,
           newGroupTitle.trim(),
           PROGRAM_ID
@@ -95,6 +103,11 @@ export const GroupStage: React.FC<Props> = ({
     try {
       const [notePda] = findNotePda(boardAddress, noteId, PROGRAM_ID);
       const [groupPda] = findGroupPda(boardAddress, groupId, PROGRAM_ID);
+      const [membershipPda] = findBoardMembershipPda(
+        boardAddress,
+        publicKey,
+        PROGRAM_ID
+      );
 
       if (canSign()) {
         const sessionSigner = getSessionSigner()!;
@@ -104,6 +117,7 @@ export const GroupStage: React.FC<Props> = ({
           boardAddress,
           notePda,
           groupPda,
+          membershipPda,
           sessionSigner.publicKey,
           noteId,
           groupId,
@@ -118,6 +132,7 @@ export const GroupStage: React.FC<Props> = ({
           boardAddress,
           notePda,
           groupPda,
+          membershipPda,
           publicKey,
           noteId,
           groupId,
@@ -136,8 +151,18 @@ export const GroupStage: React.FC<Props> = ({
   const handleUnassignNote = async (noteId: bigint) => {
     if (!publicKey || !isOnAllowlist) return;
 
+    // The note's current group is passed so its note count can be updated
+    const groupId = notes.find((n) => n.data.noteId === noteId)?.data.groupId;
+    if (groupId === null || groupId === undefined) return;
+
     try {
       const [notePda] = findNotePda(boardAddress, noteId, PROGRAM_ID);
+      const [groupPda] = findGroupPda(boardAddress, groupId, PROGRAM_ID);
+      const [membershipPda] = findBoardMembershipPda(
+        boardAddress,
+        publicKey,
+        PROGRAM_ID
+      );
 
       if (canSign()) {
         const sessionSigner = getSessionSigner()!;
@@ -146,6 +171,8 @@ export const GroupStage: React.FC<Props> = ({
         const instruction = createUnassignNoteInstruction(
           boardAddress,
           notePda,
+          groupPda,
+          membershipPda,
           sessionSigner.publicKey,
           noteId,
           PROGRAM_ID,
@@ -158,6 +185,8 @@ export const GroupStage: React.FC<Props> = ({
         const instruction = createUnassignNoteInstruction(
           boardAddress,
           notePda,
+          groupPda,
+          membershipPda,
           publicKey,
           noteId,
           PROGRAM_ID
@@ -5,6 +5,8 @@ import {
   NoteWithAddress,
   GroupWithAddress,
   BoardMembership,
+  BoardMembershipWithAddress,
+  VotingMode,
 } from "../../types";
 
 interface Props {
@@ -12,12 +14,13 @@ interface Props {
   notes: NoteWithAddress[];
   groups: GroupWithAddress[];
   membership: BoardMembership | null;
+  memberships: BoardMembershipWithAddress[];
   boardAddress: PublicKey;
   refresh: () => Promise<void>;
   isOnAllowlist: boolean;
 }
 
-export const SetupStage: React.FC<Props> = ({ board }) => {
+export const SetupStage: React.FC<Props> = ({ board, memberships }) => {
   return (
     <div className="space-y-6">
       <div>
@@ -46,10 +49,10 @@ export const SetupStage: React.FC<Props> = ({ board }) => {
       {/* Participants */}
       <div>
         <h3 className="text-lg font-medium mb-3">
-          Participants ({board.allowlist.length})
+          Participants ({board.membershipCount})
         </h3>
         <div className="space-y-2">
-          {board.allowlist.map((participant) => (
+          {memberships.map(({ data: { participant } }) => (
             <div
               key={participant.toString()}
               className="flex items-center gap-2 text-gray-300"
@@ -72,8 +75,16 @@ export const SetupStage: React.FC<Props> = ({ board }) => {
           <span className="text-purple-400 font-medium">
             {board.votingCreditsPerParticipant} voting credits
           </span>{" "}
-          to allocate during the voting stage.
+          to allocate during the voting stage
+          {board.votingMode === VotingMode.Quadratic &&
+            ", where n votes on one group cost n² credits"}
+          .
         </p>
+        {board.hideNoteAuthors && (
+          <p className="text-gray-400 mt-2">
+            Note authors are hidden until they choose to reveal themselves.
+          </p>
+        )}
       </div>
 
       {/* Facilitator Info */}
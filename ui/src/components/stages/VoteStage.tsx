@@ -14,9 +14,11 @@ import {
   NoteWithAddress,
   GroupWithAddress,
   BoardMembership,
+  VotingMode,
   PROGRAM_ID,
 } from "../../types";
 import { GroupCard } from "../GroupCard";
+import { currentCreditsSpent, currentVoteTally } from "../../utils/voteRound";
 
 interface Props {
   board: RetroBoard;
@@ -42,11 +44,17 @@ export const VoteStage: React.FC<Props> = ({
   const { canSign, getSessionSigner, getSessionTokenAddress } = useSession();
   const [voting, setVoting] = useState<bigint | null>(null);
 
-  const creditsSpent = membership?.creditsSpent || 0;
-  const creditsRemaining = board.votingCreditsPerParticipant - creditsSpent;
+  // Each membership carries its own budget, which the facilitator may have changed
+  const creditsSpent = membership ? currentCreditsSpent(membership, board) : 0;
+  const creditsRemaining = (membership?.votingCredits || 0) - creditsSpent;
+  // Ranked and sealed boards vote through ballots and commitments instead of CastVote
+  const hasLiveTally =
+    board.votingMode === VotingMode.Linear ||
+    board.votingMode === VotingMode.Quadratic;
 
   const handleVote = async (groupId: bigint, credits: number) => {
-    if (!publicKey || !isOnAllowlist || credits > creditsRemaining) return;
+    if (!publicKey || !isOnAllowlist || !hasLiveTally || credits > creditsRemaining)
+      return;
 
     try {
       setVoting(groupId);
@@ -114,7 +122,8 @@ export const VoteStage: React.FC<Props> = ({
 
   // Sort by vote count
   const sortedGroups = [...groupsWithNotes].sort(
-    (a, b) => Number(b.data.voteTally - a.data.voteTally)
+    (a, b) =>
+      Number(currentVoteTally(b.data, board) - currentVoteTally(a.data, board))
   );
 
   return (
@@ -140,10 +149,20 @@ export const VoteStage: React.FC<Props> = ({
       </div>
 
       {/* Vote buttons legend */}
-      {isOnAllowlist && creditsRemaining > 0 && (
+      {isOnAllowlist && hasLiveTally && creditsRemaining > 0 && (
         <div className="bg-gray-700/50 rounded-lg p-4 text-sm text-gray-400">
           Click the vote buttons on each group to allocate your credits.
-          Each click adds 1 credit.
+          {board.votingMode === VotingMode.Quadratic
+            ? " Each click adds 1 vote; n votes on one group cost n² credits."
+            : " Each click adds 1 credit."}
+        </div>
+      )}
+
+      {!hasLiveTally && (
+        <div className="bg-gray-700/50 rounded-lg p-4 text-sm text-gray-400">
+          This board uses {VotingMode[board.votingMode].toLowerCase()} voting,
+          which is not available in this app yet. Tallies appear once voting
+          ends.
         </div>
       )}
 
@@ -182,11 +201,11 @@ export const VoteStage: React.FC<Props> = ({
                 {/* Vote controls */}
                 <div className="flex flex-col items-center gap-2">
                   <div className="text-2xl font-bold text-purple-400">
-                    {group.data.voteTally.toString()}
+                    {currentVoteTally(group.data, board).toString()}
                   </div>
                   <div className="text-xs text-gray-500">votes</div>
 
-                  {isOnAllowlist && creditsRemaining > 0 && (
+                  {isOnAllowlist && hasLiveTally && creditsRemaining > 0 && (
                     <button
                       onClick={() => handleVote(group.data.groupId, 1)}
                       disabled={voting === group.data.groupId}
@@ -5,8 +5,17 @@ import { useProgram } from "../../hooks/useProgram";
 import { useSession } from "../../contexts/SessionContext";
 import {
   createCreateNoteInstruction,
+  createRevealNoteAuthorInstruction,
   findNotePda,
+  findBoardMembershipPda,
 } from "../../utils/instructions";
+import {
+  computeAuthorCommitment,
+  generateNoteSalt,
+  isAuthorHidden,
+  loadNoteSalt,
+  saveNoteSalt,
+} from "../../utils/noteAuthor";
 import {
   RetroBoard,
   NoteWithAddress,
@@ -48,6 +57,23 @@ export const WriteNotesStage: React.FC<Props> = ({
     try {
       setSubmitting(true);
       const [notePda] = findNotePda(boardAddress, board.noteCount, PROGRAM_ID);
+      const [membershipPda] = findBoardMembershipPda(
+        boardAddress,
+        publicKey,
+        PROGRAM_ID
+      );
+
+      // Boards that hide authors store a commitment instead of the author's key
+      let authorCommitment: Uint8Array | null = null;
+      if (board.hideNoteAuthors) {
+        const salt = generateNoteSalt();
+        authorCommitment = await computeAuthorCommitment(
+          publicKey,
+          boardAddress,
+          salt
+        );
+        saveNoteSalt(notePda, salt);
+      }
 
       // Check if we can use session signing
       if (canSign()) {
@@ -57,9 +83,11 @@ export const WriteNotesStage: React.FC<Props> = ({
         const instruction = createCreateNoteInstruction(
           boardAddress,
           notePda,
+          membershipPda,
           sessionSigner.publicKey,
           selectedCategory,
           noteContent.trim(),
+          authorCommitment,
           PROGRAM_ID,
           sessionToken
         );
@@ -71,9 +99,11 @@ export const WriteNotesStage: React.FC<Props> = ({
         const instruction = createCreateNoteInstruction(
           boardAddress,
           notePda,
+          membershipPda,
           publicKey,
           selectedCategory,
           noteContent.trim(),
+          authorCommitment,
           PROGRAM_ID
         );
         await sendInstructions([instruction]);
@@ -88,6 +118,47 @@ export const WriteNotesStage: React.FC<Props> = ({
     }
   };
 
+  const handleRevealAuthor = async (note: NoteWithAddress) => {
+    const salt = loadNoteSalt(note.address);
+    if (!publicKey || !salt) return;
+
+    try {
+      if (canSign()) {
+        const sessionSigner = getSessionSigner()!;
+        const sessionToken = getSessionTokenAddress()!;
+
+        const instruction = createRevealNoteAuthorInstruction(
+          boardAddress,
+          note.address,
+          sessionSigner.publicKey,
+          salt,
+          PROGRAM_ID,
+          sessionToken
+        );
+        await sendInstructionsWithSession([instruction], sessionSigner, {
+          fallbackToWallet: true,
+        });
+      } else {
+        const instruction = createRevealNoteAuthorInstruction(
+          boardAddress,
+          note.address,
+          publicKey,
+          salt,
+          PROGRAM_ID
+        );
+        await sendInstructions([instruction]);
+      }
+
+      await refresh();
+    } catch (err) {
+      console.error("Error revealing note author:", err);
+    }
+  };
+
+  // Only the wallet that wrote a hidden note holds its salt
+  const canReveal = (note: NoteWithAddress) =>
+    isAuthorHidden(note.data.author) && loadNoteSalt(note.address) !== null;
+
   // Group notes by category
   const notesByCategory = board.categories.map((category, index) => ({
     category,
@@ -101,7 +172,10 @@ export const WriteNotesStage: React.FC<Props> = ({
         <h2 className="text-xl font-semibold mb-2">Write Notes</h2>
         <p className="text-gray-400">
           Add notes to the categories below. Notes are visible to all
-          participants.
+          participants
+          {board.hideNoteAuthors
+            ? ", but their authors stay hidden unless they reveal themselves."
+            : "."}
         </p>
       </div>
 
@@ -172,7 +246,16 @@ export const WriteNotesStage: React.FC<Props> = ({
                 <p className="text-gray-500 text-sm italic">No notes yet</p>
               ) : (
                 categoryNotes.map((note) => (
-                    <NoteCard key={note.address.toString()} note={note} identities={identities} />
+                    <NoteCard
+                      key={note.address.toString()}
+                      note={note}
+                      identities={identities}
+                      onRevealAuthor={
+                        canReveal(note)
+                          ? () => handleRevealAuthor(note)
+                          : undefined
+                      }
+                    />
                 ))
               )}
             </div>
@@ -22,6 +22,8 @@ import {
   GroupWithAddress,
   BoardMembership,
   ActionItemWithAddress,
+  BoardMembershipWithAddress,
+  BOARD_MEMBERSHIP_SIZE,
   PROGRAM_ID,
 } from "../types";
 
@@ -31,6 +33,8 @@ interface BoardData {
   groups: GroupWithAddress[];
   actionItems: ActionItemWithAddress[];
   membership: BoardMembership | null;
+  // Every BoardMembership of the board; a membership is what puts a participant on the allowlist
+  memberships: BoardMembershipWithAddress[];
   identities: Map<string, string>; // wallet address -> username
   loading: boolean;
   error: string | null;
@@ -44,6 +48,9 @@ export function useBoard(boardAddress: PublicKey | null): BoardData {
   const [groups, setGroups] = useState<GroupWithAddress[]>([]);
   const [actionItems, setActionItems] = useState<ActionItemWithAddress[]>([]);
   const [membership, setMembership] = useState<BoardMembership | null>(null);
+  const [memberships, setMemberships] = useState<
+    BoardMembershipWithAddress[]
+  >([]);
   const [identities, setIdentities] = useState<Map<string, string>>(new Map());
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
@@ -173,9 +180,34 @@ export function useBoard(boardAddress: PublicKey | null): BoardData {
         }
       }
 
+      // Fetch every membership of the board (board pubkey sits at offset 2)
+      const membershipAccounts = await connection.getProgramAccounts(
+        PROGRAM_ID,
+        {
+          filters: [
+            { dataSize: BOARD_MEMBERSHIP_SIZE },
+            { memcmp: { offset: 2, bytes: boardAddress.toBase58() } },
+          ],
+        }
+      );
+      const fetchedMemberships: BoardMembershipWithAddress[] = [];
+      for (const { pubkey, account } of membershipAccounts) {
+        try {
+          fetchedMemberships.push({
+            address: pubkey,
+            data: deserializeBoardMembership(Buffer.from(account.data)),
+          });
+        } catch (e) {
+          // Skip accounts that are not memberships
+          console.warn("Failed to deserialize membership:", e);
+        }
+      }
+      setMemberships(fetchedMemberships);
+      const participants = fetchedMemberships.map((m) => m.data.participant);
+
       // Batch fetch participant identities for all allowlisted participants
-      if (boardData.allowlist.length > 0) {
-        const identityAddresses: PublicKey[] = boardData.allowlist.map(
+      if (participants.length > 0) {
+        const identityAddresses: PublicKey[] = participants.map(
           (participant) =>
             findParticipantIdentityPda(participant, PROGRAM_ID)[0]
         );
@@ -193,7 +225,7 @@ export function useBoard(boardAddress: PublicKey | null): BoardData {
               );
               if (identity.isInitialized) {
                 fetchedIdentities.set(
-                  boardData.allowlist[index].toBase58(),
+                  participants[index].toBase58(),
                   identity.username
                 );
               }
@@ -267,6 +299,7 @@ export function useBoard(boardAddress: PublicKey | null): BoardData {
     groups,
     actionItems,
     membership,
+    memberships,
     identities,
     loading,
     error,
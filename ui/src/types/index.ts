@@ -7,12 +7,24 @@ export enum BoardStage {
   GroupDuplicates = 2,
   Vote = 3,
   Discuss = 4,
+  ReviewPreviousActions = 5,
+  RevealVotes = 6,
 }
 
 // Action item status matching Rust enum
 export enum ActionItemStatus {
   Pending = 0,
   Completed = 1,
+  Expired = 2,
+  Rejected = 3,
+}
+
+// Voting mode matching Rust enum
+export enum VotingMode {
+  Linear = 0,
+  Quadratic = 1,
+  Ranked = 2,
+  Sealed = 3,
 }
 
 export const STAGE_NAMES: Record<BoardStage, string> = {
@@ -21,6 +33,15 @@ export const STAGE_NAMES: Record<BoardStage, string> = {
   [BoardStage.GroupDuplicates]: "Group Duplicates",
   [BoardStage.Vote]: "Vote",
   [BoardStage.Discuss]: "Discuss",
+  [BoardStage.ReviewPreviousActions]: "Review Previous Actions",
+  [BoardStage.RevealVotes]: "Reveal Votes",
+};
+
+export const ACTION_ITEM_STATUS_NAMES: Record<ActionItemStatus, string> = {
+  [ActionItemStatus.Pending]: "Pending",
+  [ActionItemStatus.Completed]: "Completed",
+  [ActionItemStatus.Expired]: "Expired",
+  [ActionItemStatus.Rejected]: "Rejected",
 };
 
 // Account types matching Rust structs
@@ -34,17 +55,37 @@ export interface FacilitatorRegistry {
 export interface RetroBoard {
   isInitialized: boolean;
   facilitator: PublicKey;
+  pendingFacilitator: PublicKey | null;
   boardIndex: bigint;
   stage: BoardStage;
+  // Ordered stage pipeline the board moves through
+  stages: BoardStage[];
+  // Timebox in slots per pipeline stage (0 = untimed)
+  stageDurationSlots: bigint[];
   closed: boolean;
   categories: string[];
-  allowlist: PublicKey[];
   votingCreditsPerParticipant: number;
+  votingMode: VotingMode;
+  voteRound: number;
+  ballotCount: number;
+  // TallyBallots progress: groups scanned, how many could be ranked, ballots tallied
+  talliedGroupCount: bigint;
+  candidateCount: bigint;
+  talliedBallotCount: number;
+  lastTalliedParticipant: PublicKey | null;
+  hideNoteAuthors: boolean;
   noteCount: bigint;
   groupCount: bigint;
   actionItemCount: bigint;
+  pendingActionItemCount: bigint;
+  liveActionItemCount: bigint;
+  legacyNoteCount: bigint;
+  membershipCount: number;
+  team: PublicKey | null;
+  predecessor: PublicKey | null;
   createdAtSlot: bigint;
   stageChangedAtSlot: bigint;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -52,11 +93,14 @@ export interface Note {
   isInitialized: boolean;
   board: PublicKey;
   noteId: bigint;
+  // PublicKey.default while the author of a note on a hiding board is unrevealed
   author: PublicKey;
+  authorCommitment: Uint8Array | null;
   categoryId: number;
   content: string;
   createdAtSlot: bigint;
   groupId: bigint | null;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -67,6 +111,9 @@ export interface Group {
   title: string;
   createdBy: PublicKey;
   voteTally: bigint;
+  voteRound: number;
+  noteCount: number;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -74,8 +121,12 @@ export interface BoardMembership {
   isInitialized: boolean;
   board: PublicKey;
   participant: PublicKey;
+  votingCredits: number;
   creditsSpent: number;
   totalScore: bigint;
+  rejectedCount: bigint;
+  voteRound: number;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -84,7 +135,10 @@ export interface VoteRecord {
   board: PublicKey;
   participant: PublicKey;
   groupId: bigint;
+  votes: number;
   creditsSpent: number;
+  voteRound: number;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -97,9 +151,13 @@ export interface ActionItem {
   verifiers: PublicKey[];
   threshold: number;
   approvals: number;
+  rejections: number;
   status: ActionItemStatus;
   createdAtSlot: bigint;
   verifiedAtSlot: bigint | null;
+  // Unix timestamp after which a pending item can be expired
+  dueAt: bigint | null;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -109,6 +167,7 @@ export interface VerificationVote {
   verifier: PublicKey;
   approved: boolean;
   votedAtSlot: bigint;
+  rentPayer: PublicKey;
   bump: number;
 }
 
@@ -138,6 +197,11 @@ export const MIN_USERNAME_CHARS = 3;
 export const MAX_USERNAME_CHARS = 32;
 
 // UI-specific types
+export interface BoardMembershipWithAddress {
+  address: PublicKey;
+  data: BoardMembership;
+}
+
 export interface BoardWithAddress {
   address: PublicKey;
   data: RetroBoard;
@@ -186,13 +250,22 @@ export const DISCRIMINATOR_ACTION_ITEM = 7;
 export const DISCRIMINATOR_VERIFICATION_VOTE = 8;
 export const DISCRIMINATOR_PARTICIPANT_IDENTITY = 9;
 export const DISCRIMINATOR_SESSION_TOKEN = 10;
+export const DISCRIMINATOR_REPUTATION = 11;
+export const DISCRIMINATOR_TEAM = 12;
+export const DISCRIMINATOR_BALLOT = 13;
+export const DISCRIMINATOR_VOTE_COMMITMENT = 14;
+
+// Fixed account sizes (must match the LEN constants in state.rs)
+export const FACILITATOR_REGISTRY_SIZE = 43;
+export const BOARD_MEMBERSHIP_SIZE = 121;
+export const VOTE_RECORD_SIZE = 113;
+export const VERIFICATION_VOTE_SIZE = 108;
+export const SESSION_TOKEN_SIZE = 105;
 
-// Account sizes (updated with discriminator byte at offset 0)
-export const FACILITATOR_REGISTRY_SIZE = 43; // was 42
-export const BOARD_MEMBERSHIP_SIZE = 76; // was 75
-export const VOTE_RECORD_SIZE = 76; // was 75
-export const VERIFICATION_VOTE_SIZE = 76; // was 75
-export const SESSION_TOKEN_SIZE = 105; // was 104
+// Board limits (must match state.rs)
+export const MAX_INITIAL_PARTICIPANTS = 8;
+export const MAX_PARTICIPANTS = 64;
+export const MAX_VERIFIERS = 7;
 
 // Program ID (deployed on devnet)
 export const PROGRAM_ID = new PublicKey(
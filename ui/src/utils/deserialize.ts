@@ -13,6 +13,7 @@ import {
   ParticipantIdentity,
   BoardStage,
   ActionItemStatus,
+  VotingMode,
   DISCRIMINATOR_FACILITATOR_REGISTRY,
   DISCRIMINATOR_RETRO_BOARD,
   DISCRIMINATOR_BOARD_MEMBERSHIP,
@@ -83,6 +84,58 @@ function readOptionU64(
   return [readU64(buffer, offset + 1), 9];
 }
 
+// Helper to read Option<i64>
+function readOptionI64(
+  buffer: Buffer,
+  offset: number
+): [bigint | null, number] {
+  const hasValue = buffer.readUInt8(offset);
+  if (hasValue === 0) {
+    return [null, 1];
+  }
+  return [buffer.readBigInt64LE(offset + 1), 9];
+}
+
+// Helper to read Option<Pubkey>
+function readOptionPublicKey(
+  buffer: Buffer,
+  offset: number
+): [PublicKey | null, number] {
+  const hasValue = buffer.readUInt8(offset);
+  if (hasValue === 0) {
+    return [null, 1];
+  }
+  return [readPublicKey(buffer, offset + 1), 33];
+}
+
+// Helper to read Option<[u8; 32]>
+function readOptionBytes32(
+  buffer: Buffer,
+  offset: number
+): [Uint8Array | null, number] {
+  const hasValue = buffer.readUInt8(offset);
+  if (hasValue === 0) {
+    return [null, 1];
+  }
+  return [new Uint8Array(buffer.slice(offset + 1, offset + 33)), 33];
+}
+
+// Helper to read Vec<u8>
+function readU8Vec(buffer: Buffer, offset: number): [number[], number] {
+  const count = buffer.readUInt32LE(offset);
+  return [Array.from(buffer.slice(offset + 4, offset + 4 + count)), 4 + count];
+}
+
+// Helper to read Vec<u64>
+function readU64Vec(buffer: Buffer, offset: number): [bigint[], number] {
+  const count = buffer.readUInt32LE(offset);
+  const values: bigint[] = [];
+  for (let i = 0; i < count; i++) {
+    values.push(readU64(buffer, offset + 4 + i * 8));
+  }
+  return [values, 4 + count * 8];
+}
+
 export function deserializeFacilitatorRegistry(
   data: Buffer
 ): FacilitatorRegistry {
@@ -117,24 +170,58 @@ export function deserializeBoard(data: Buffer): RetroBoard {
   const facilitator = readPublicKey(data, offset);
   offset += 32;
 
+  const [pendingFacilitator, pendingFacilitatorLen] = readOptionPublicKey(
+    data,
+    offset
+  );
+  offset += pendingFacilitatorLen;
+
   const boardIndex = readU64(data, offset);
   offset += 8;
 
   const stage = data.readUInt8(offset) as BoardStage;
   offset += 1;
 
+  const [stages, stagesLen] = readU8Vec(data, offset);
+  offset += stagesLen;
+
+  const [stageDurationSlots, stageDurationSlotsLen] = readU64Vec(data, offset);
+  offset += stageDurationSlotsLen;
+
   const closed = data.readUInt8(offset) === 1;
   offset += 1;
 
   const [categories, categoriesLen] = readStringVec(data, offset);
   offset += categoriesLen;
 
-  const [allowlist, allowlistLen] = readPubkeyVec(data, offset);
-  offset += allowlistLen;
-
   const votingCreditsPerParticipant = data.readUInt8(offset);
   offset += 1;
 
+  const votingMode = data.readUInt8(offset) as VotingMode;
+  offset += 1;
+
+  const voteRound = data.readUInt32LE(offset);
+  offset += 4;
+
+  const ballotCount = data.readUInt32LE(offset);
+  offset += 4;
+
+  const talliedGroupCount = readU64(data, offset);
+  offset += 8;
+
+  const candidateCount = readU64(data, offset);
+  offset += 8;
+
+  const talliedBallotCount = data.readUInt32LE(offset);
+  offset += 4;
+
+  const [lastTalliedParticipant, lastTalliedParticipantLen] =
+    readOptionPublicKey(data, offset);
+  offset += lastTalliedParticipantLen;
+
+  const hideNoteAuthors = data.readUInt8(offset) === 1;
+  offset += 1;
+
   const noteCount = readU64(data, offset);
   offset += 8;
 
@@ -144,28 +231,66 @@ export function deserializeBoard(data: Buffer): RetroBoard {
   const actionItemCount = readU64(data, offset);
   offset += 8;
 
+  const pendingActionItemCount = readU64(data, offset);
+  offset += 8;
+
+  const liveActionItemCount = readU64(data, offset);
+  offset += 8;
+
+  const legacyNoteCount = readU64(data, offset);
+  offset += 8;
+
+  const membershipCount = data.readUInt32LE(offset);
+  offset += 4;
+
+  const [team, teamLen] = readOptionPublicKey(data, offset);
+  offset += teamLen;
+
+  const [predecessor, predecessorLen] = readOptionPublicKey(data, offset);
+  offset += predecessorLen;
+
   const createdAtSlot = readU64(data, offset);
   offset += 8;
 
   const stageChangedAtSlot = readU64(data, offset);
   offset += 8;
 
+  const rentPayer = readPublicKey(data, offset);
+  offset += 32;
+
   const bump = data.readUInt8(offset);
 
   return {
     isInitialized,
     facilitator,
+    pendingFacilitator,
     boardIndex,
     stage,
+    stages: stages as BoardStage[],
+    stageDurationSlots,
     closed,
     categories,
-    allowlist,
     votingCreditsPerParticipant,
+    votingMode,
+    voteRound,
+    ballotCount,
+    talliedGroupCount,
+    candidateCount,
+    talliedBallotCount,
+    lastTalliedParticipant,
+    hideNoteAuthors,
     noteCount,
     groupCount,
     actionItemCount,
+    pendingActionItemCount,
+    liveActionItemCount,
+    legacyNoteCount,
+    membershipCount,
+    team,
+    predecessor,
     createdAtSlot,
     stageChangedAtSlot,
+    rentPayer,
     bump,
   };
 }
@@ -193,6 +318,12 @@ export function deserializeNote(data: Buffer): Note {
   const author = readPublicKey(data, offset);
   offset += 32;
 
+  const [authorCommitment, authorCommitmentLen] = readOptionBytes32(
+    data,
+    offset
+  );
+  offset += authorCommitmentLen;
+
   const categoryId = data.readUInt8(offset);
   offset += 1;
 
@@ -205,6 +336,9 @@ export function deserializeNote(data: Buffer): Note {
   const [groupId, groupIdLen] = readOptionU64(data, offset);
   offset += groupIdLen;
 
+  const rentPayer = readPublicKey(data, offset);
+  offset += 32;
+
   const bump = data.readUInt8(offset);
 
   return {
@@ -212,10 +346,12 @@ export function deserializeNote(data: Buffer): Note {
     board,
     noteId,
     author,
+    authorCommitment,
     categoryId,
     content,
     createdAtSlot,
     groupId,
+    rentPayer,
     bump,
   };
 }
@@ -249,6 +385,15 @@ export function deserializeGroup(data: Buffer): Group {
   const voteTally = readU64(data, offset);
   offset += 8;
 
+  const voteRound = data.readUInt32LE(offset);
+  offset += 4;
+
+  const noteCount = data.readUInt32LE(offset);
+  offset += 4;
+
+  const rentPayer = readPublicKey(data, offset);
+  offset += 32;
+
   const bump = data.readUInt8(offset);
 
   return {
@@ -258,6 +403,9 @@ export function deserializeGroup(data: Buffer): Group {
     title,
     createdBy,
     voteTally,
+    voteRound,
+    noteCount,
+    rentPayer,
     bump,
   };
 }
@@ -273,9 +421,13 @@ export function deserializeBoardMembership(data: Buffer): BoardMembership {
     isInitialized: data.readUInt8(1) === 1,
     board: readPublicKey(data, 2),
     participant: readPublicKey(data, 34),
-    creditsSpent: data.readUInt8(66),
-    totalScore: readU64(data, 67),
-    bump: data.readUInt8(75),
+    votingCredits: data.readUInt8(66),
+    creditsSpent: data.readUInt8(67),
+    totalScore: readU64(data, 68),
+    rejectedCount: readU64(data, 76),
+    voteRound: data.readUInt32LE(84),
+    rentPayer: readPublicKey(data, 88),
+    bump: data.readUInt8(120),
   };
 }
 
@@ -291,8 +443,11 @@ export function deserializeVoteRecord(data: Buffer): VoteRecord {
     board: readPublicKey(data, 2),
     participant: readPublicKey(data, 34),
     groupId: readU64(data, 66),
-    creditsSpent: data.readUInt8(74),
-    bump: data.readUInt8(75),
+    votes: data.readUInt8(74),
+    creditsSpent: data.readUInt8(75),
+    voteRound: data.readUInt32LE(76),
+    rentPayer: readPublicKey(data, 80),
+    bump: data.readUInt8(112),
   };
 }
 
@@ -331,6 +486,9 @@ export function deserializeActionItem(data: Buffer): ActionItem {
   const approvals = data.readUInt8(offset);
   offset += 1;
 
+  const rejections = data.readUInt8(offset);
+  offset += 1;
+
   const status = data.readUInt8(offset) as ActionItemStatus;
   offset += 1;
 
@@ -340,6 +498,12 @@ export function deserializeActionItem(data: Buffer): ActionItem {
   const [verifiedAtSlot, verifiedAtSlotLen] = readOptionU64(data, offset);
   offset += verifiedAtSlotLen;
 
+  const [dueAt, dueAtLen] = readOptionI64(data, offset);
+  offset += dueAtLen;
+
+  const rentPayer = readPublicKey(data, offset);
+  offset += 32;
+
   const bump = data.readUInt8(offset);
 
   return {
@@ -351,9 +515,12 @@ export function deserializeActionItem(data: Buffer): ActionItem {
     verifiers,
     threshold,
     approvals,
+    rejections,
     status,
     createdAtSlot,
     verifiedAtSlot,
+    dueAt,
+    rentPayer,
     bump,
   };
 }
@@ -371,7 +538,8 @@ export function deserializeVerificationVote(data: Buffer): VerificationVote {
     verifier: readPublicKey(data, 34),
     approved: data.readUInt8(66) === 1,
     votedAtSlot: readU64(data, 67),
-    bump: data.readUInt8(75),
+    rentPayer: readPublicKey(data, 75),
+    bump: data.readUInt8(107),
   };
 }
 
@@ -16,6 +16,7 @@ import {
   findVerificationVotePda,
   findSessionTokenPda,
 } from "./pda";
+import { BoardStage, VotingMode } from "../types";
 
 // Instruction discriminators (must match instructions.rs)
 const INIT_FACILITATOR_REGISTRY = 0;
@@ -34,13 +35,42 @@ const CREATE_SESSION = 12;
 const REVOKE_SESSION = 13;
 const CREATE_IDENTITY = 14;
 const UPDATE_IDENTITY = 15;
+const REVEAL_NOTE_AUTHOR = 23;
+const DELETE_NOTE = 25;
+const REVERT_STAGE = 39;
+const RETRACT_VOTE = 40;
+
+// Settings chosen when a board is created (CreateBoard payload)
+export interface BoardConfig {
+  categories: string[];
+  allowlist: PublicKey[];
+  votingCreditsPerParticipant: number | null;
+  hideNoteAuthors: boolean;
+  predecessor: PublicKey | null;
+  // Ordered stage pipeline; null enables every stage that applies
+  stages: BoardStage[] | null;
+  // Timebox in slots for each pipeline stage (0 = untimed); null leaves every stage untimed
+  stageDurationSlots: bigint[] | null;
+  votingMode: VotingMode;
+  // Voting budget for each allowlist entry; null gives everyone votingCreditsPerParticipant
+  creditBudgets: number[] | null;
+}
 
 // Borsh schema definitions
+const pubkeySchema = { array: { type: "u8", len: 32 } };
+const bytes32Schema = { array: { type: "u8", len: 32 } };
+
 const createBoardSchema = {
   struct: {
     categories: { array: { type: "string" } },
-    allowlist: { array: { type: { array: { type: "u8", len: 32 } } } },
+    allowlist: { array: { type: pubkeySchema } },
     voting_credits_per_participant: { option: "u8" },
+    hide_note_authors: "bool",
+    predecessor: { option: pubkeySchema },
+    stages: { option: { array: { type: "u8" } } },
+    stage_duration_slots: { option: { array: { type: "u64" } } },
+    voting_mode: "u8",
+    credit_budgets: { option: { array: { type: "u8" } } },
   },
 };
 
@@ -54,6 +84,7 @@ const createNoteSchema = {
   struct: {
     category_id: "u8",
     content: "string",
+    author_commitment: { option: bytes32Schema },
   },
 };
 
@@ -93,9 +124,10 @@ const castVoteSchema = {
 const createActionItemSchema = {
   struct: {
     description: "string",
-    owner: { array: { type: "u8", len: 32 } },
-    verifiers: { array: { type: { array: { type: "u8", len: 32 } } } },
+    owner: pubkeySchema,
+    verifiers: { array: { type: pubkeySchema } },
     threshold: "u8",
+    due_at: { option: "i64" },
   },
 };
 
@@ -119,6 +151,26 @@ const identitySchema = {
   },
 };
 
+const revealNoteAuthorSchema = {
+  struct: {
+    salt: bytes32Schema,
+  },
+};
+
+const deleteNoteSchema = {
+  struct: {
+    note_id: "u64",
+    salt: { option: bytes32Schema },
+  },
+};
+
+const retractVoteSchema = {
+  struct: {
+    group_id: "u64",
+    votes: "u8",
+  },
+};
+
 function serializeInstruction(discriminator: number, payload?: Buffer): Buffer {
   if (payload) {
     return Buffer.concat([Buffer.from([discriminator]), payload]);
@@ -150,21 +202,28 @@ export function createCreateBoardInstruction(
   facilitatorRegistry: PublicKey,
   board: PublicKey,
   signer: PublicKey,
-  categories: string[],
-  allowlist: PublicKey[],
-  votingCreditsPerParticipant: number | null,
+  config: BoardConfig,
   membershipAccounts: PublicKey[],
   programId: PublicKey,
   sessionToken?: PublicKey
 ): TransactionInstruction {
   const payload = {
-    categories,
-    allowlist: allowlist.map((pk) => Array.from(pk.toBytes())),
-    voting_credits_per_participant: votingCreditsPerParticipant,
+    categories: config.categories,
+    allowlist: config.allowlist.map((pk) => Array.from(pk.toBytes())),
+    voting_credits_per_participant: config.votingCreditsPerParticipant,
+    hide_note_authors: config.hideNoteAuthors,
+    predecessor: config.predecessor
+      ? Array.from(config.predecessor.toBytes())
+      : null,
+    stages: config.stages,
+    stage_duration_slots: config.stageDurationSlots,
+    voting_mode: config.votingMode,
+    credit_budgets: config.creditBudgets,
   };
 
   const serialized = borsh.serialize(createBoardSchema as any, payload);
 
+  // Team boards pass the team account in place of the facilitator registry
   const keys = [
     { pubkey: facilitatorRegistry, isSigner: false, isWritable: true },
     { pubkey: board, isSigner: false, isWritable: true },
@@ -182,6 +241,15 @@ export function createCreateBoardInstruction(
     keys.push({ pubkey: ma, isSigner: false, isWritable: true });
   }
 
+  // Follow-up boards pass their predecessor last
+  if (config.predecessor) {
+    keys.push({
+      pubkey: config.predecessor,
+      isSigner: false,
+      isWritable: false,
+    });
+  }
+
   return new TransactionInstruction({
     keys,
     programId,
@@ -193,6 +261,7 @@ export function createAdvanceStageInstruction(
   board: PublicKey,
   signer: PublicKey,
   newStage: number,
+  team: PublicKey | null,
   programId: PublicKey,
   sessionToken?: PublicKey
 ): TransactionInstruction {
@@ -204,6 +273,10 @@ export function createAdvanceStageInstruction(
     { pubkey: signer, isSigner: true, isWritable: false },
   ];
 
+  if (team) {
+    keys.push({ pubkey: team, isSigner: false, isWritable: false });
+  }
+
   if (sessionToken) {
     keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
   }
@@ -215,9 +288,41 @@ export function createAdvanceStageInstruction(
   });
 }
 
+export function createRevertStageInstruction(
+  board: PublicKey,
+  signer: PublicKey,
+  targetStage: number,
+  team: PublicKey | null,
+  programId: PublicKey,
+  sessionToken?: PublicKey
+): TransactionInstruction {
+  const payload = { new_stage: targetStage };
+  const serialized = borsh.serialize(advanceStageSchema as any, payload);
+
+  const keys = [
+    { pubkey: board, isSigner: false, isWritable: true },
+    { pubkey: signer, isSigner: true, isWritable: false },
+  ];
+
+  if (team) {
+    keys.push({ pubkey: team, isSigner: false, isWritable: false });
+  }
+
+  if (sessionToken) {
+    keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
+  }
+
+  return new TransactionInstruction({
+    keys,
+    programId,
+    data: serializeInstruction(REVERT_STAGE, Buffer.from(serialized)),
+  });
+}
+
 export function createCloseBoardInstruction(
   board: PublicKey,
   signer: PublicKey,
+  team: PublicKey | null,
   programId: PublicKey,
   sessionToken?: PublicKey
 ): TransactionInstruction {
@@ -226,6 +331,10 @@ export function createCloseBoardInstruction(
     { pubkey: signer, isSigner: true, isWritable: false },
   ];
 
+  if (team) {
+    keys.push({ pubkey: team, isSigner: false, isWritable: false });
+  }
+
   if (sessionToken) {
     keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
   }
@@ -237,21 +346,29 @@ export function createCloseBoardInstruction(
   });
 }
 
+// authorCommitment is required on boards that hide note authors and must be null otherwise
 export function createCreateNoteInstruction(
   board: PublicKey,
   note: PublicKey,
+  membership: PublicKey,
   signer: PublicKey,
   categoryId: number,
   content: string,
+  authorCommitment: Uint8Array | null,
   programId: PublicKey,
   sessionToken?: PublicKey
 ): TransactionInstruction {
-  const payload = { category_id: categoryId, content };
+  const payload = {
+    category_id: categoryId,
+    content,
+    author_commitment: authorCommitment ? Array.from(authorCommitment) : null,
+  };
   const serialized = borsh.serialize(createNoteSchema as any, payload);
 
   const keys = [
     { pubkey: board, isSigner: false, isWritable: true },
     { pubkey: note, isSigner: false, isWritable: true },
+    { pubkey: membership, isSigner: false, isWritable: false },
     { pubkey: signer, isSigner: true, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -267,9 +384,70 @@ export function createCreateNoteInstruction(
   });
 }
 
+export function createRevealNoteAuthorInstruction(
+  board: PublicKey,
+  note: PublicKey,
+  signer: PublicKey,
+  salt: Uint8Array,
+  programId: PublicKey,
+  sessionToken?: PublicKey
+): TransactionInstruction {
+  const payload = { salt: Array.from(salt) };
+  const serialized = borsh.serialize(revealNoteAuthorSchema as any, payload);
+
+  const keys = [
+    { pubkey: board, isSigner: false, isWritable: false },
+    { pubkey: note, isSigner: false, isWritable: true },
+    { pubkey: signer, isSigner: true, isWritable: false },
+  ];
+
+  if (sessionToken) {
+    keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
+  }
+
+  return new TransactionInstruction({
+    keys,
+    programId,
+    data: serializeInstruction(REVEAL_NOTE_AUTHOR, Buffer.from(serialized)),
+  });
+}
+
+// salt opens the author commitment on notes that hide their author
+export function createDeleteNoteInstruction(
+  board: PublicKey,
+  note: PublicKey,
+  rentRecipient: PublicKey,
+  signer: PublicKey,
+  noteId: bigint,
+  salt: Uint8Array | null,
+  programId: PublicKey,
+  sessionToken?: PublicKey
+): TransactionInstruction {
+  const payload = { note_id: noteId, salt: salt ? Array.from(salt) : null };
+  const serialized = borsh.serialize(deleteNoteSchema as any, payload);
+
+  const keys = [
+    { pubkey: board, isSigner: false, isWritable: false },
+    { pubkey: note, isSigner: false, isWritable: true },
+    { pubkey: rentRecipient, isSigner: false, isWritable: true },
+    { pubkey: signer, isSigner: true, isWritable: false },
+  ];
+
+  if (sessionToken) {
+    keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
+  }
+
+  return new TransactionInstruction({
+    keys,
+    programId,
+    data: serializeInstruction(DELETE_NOTE, Buffer.from(serialized)),
+  });
+}
+
 export function createCreateGroupInstruction(
   board: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   signer: PublicKey,
   title: string,
   programId: PublicKey,
@@ -281,6 +459,7 @@ export function createCreateGroupInstruction(
   const keys = [
     { pubkey: board, isSigner: false, isWritable: true },
     { pubkey: group, isSigner: false, isWritable: true },
+    { pubkey: membership, isSigner: false, isWritable: false },
     { pubkey: signer, isSigner: true, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -299,6 +478,7 @@ export function createCreateGroupInstruction(
 export function createSetGroupTitleInstruction(
   board: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   signer: PublicKey,
   groupId: bigint,
   title: string,
@@ -311,6 +491,7 @@ export function createSetGroupTitleInstruction(
   const keys = [
     { pubkey: board, isSigner: false, isWritable: false },
     { pubkey: group, isSigner: false, isWritable: true },
+    { pubkey: membership, isSigner: false, isWritable: false },
     { pubkey: signer, isSigner: true, isWritable: false },
   ];
 
@@ -329,6 +510,7 @@ export function createAssignNoteToGroupInstruction(
   board: PublicKey,
   note: PublicKey,
   group: PublicKey,
+  membership: PublicKey,
   signer: PublicKey,
   noteId: bigint,
   groupId: bigint,
@@ -341,7 +523,8 @@ export function createAssignNoteToGroupInstruction(
   const keys = [
     { pubkey: board, isSigner: false, isWritable: false },
     { pubkey: note, isSigner: false, isWritable: true },
-    { pubkey: group, isSigner: false, isWritable: false },
+    { pubkey: group, isSigner: false, isWritable: true },
+    { pubkey: membership, isSigner: false, isWritable: false },
     { pubkey: signer, isSigner: true, isWritable: false },
   ];
 
@@ -356,9 +539,12 @@ export function createAssignNoteToGroupInstruction(
   });
 }
 
+// group is the note's current group, whose note count drops
 export function createUnassignNoteInstruction(
   board: PublicKey,
   note: PublicKey,
+  group: PublicKey,
+  membership: PublicKey,
   signer: PublicKey,
   noteId: bigint,
   programId: PublicKey,
@@ -370,6 +556,8 @@ export function createUnassignNoteInstruction(
   const keys = [
     { pubkey: board, isSigner: false, isWritable: false },
     { pubkey: note, isSigner: false, isWritable: true },
+    { pubkey: group, isSigner: false, isWritable: true },
+    { pubkey: membership, isSigner: false, isWritable: false },
     { pubkey: signer, isSigner: true, isWritable: false },
   ];
 
@@ -418,14 +606,55 @@ export function createCastVoteInstruction(
   });
 }
 
+// Takes votes back during Vote; the vote record closes and refunds its rent once empty
+export function createRetractVoteInstruction(
+  board: PublicKey,
+  boardMembership: PublicKey,
+  group: PublicKey,
+  voteRecord: PublicKey,
+  rentRecipient: PublicKey,
+  signer: PublicKey,
+  groupId: bigint,
+  votes: number,
+  programId: PublicKey,
+  sessionToken?: PublicKey
+): TransactionInstruction {
+  const payload = { group_id: groupId, votes };
+  const serialized = borsh.serialize(retractVoteSchema as any, payload);
+
+  const keys = [
+    { pubkey: board, isSigner: false, isWritable: false },
+    { pubkey: boardMembership, isSigner: false, isWritable: true },
+    { pubkey: group, isSigner: false, isWritable: true },
+    { pubkey: voteRecord, isSigner: false, isWritable: true },
+    { pubkey: rentRecipient, isSigner: false, isWritable: true },
+    { pubkey: signer, isSigner: true, isWritable: false },
+  ];
+
+  if (sessionToken) {
+    keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
+  }
+
+  return new TransactionInstruction({
+    keys,
+    programId,
+    data: serializeInstruction(RETRACT_VOTE, Buffer.from(serialized)),
+  });
+}
+
+// The owner and every verifier must be on the board, so their memberships are passed
 export function createCreateActionItemInstruction(
   board: PublicKey,
   actionItem: PublicKey,
   signer: PublicKey,
+  team: PublicKey | null,
   description: string,
   owner: PublicKey,
   verifiers: PublicKey[],
   threshold: number,
+  dueAt: bigint | null,
+  ownerMembership: PublicKey,
+  verifierMemberships: PublicKey[],
   programId: PublicKey,
   sessionToken?: PublicKey
 ): TransactionInstruction {
@@ -434,6 +663,7 @@ export function createCreateActionItemInstruction(
     owner: Array.from(owner.toBytes()),
     verifiers: verifiers.map((pk) => Array.from(pk.toBytes())),
     threshold,
+    due_at: dueAt,
   };
   const serialized = borsh.serialize(createActionItemSchema as any, payload);
 
@@ -444,10 +674,19 @@ export function createCreateActionItemInstruction(
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
 
+  if (team) {
+    keys.push({ pubkey: team, isSigner: false, isWritable: false });
+  }
+
   if (sessionToken) {
     keys.push({ pubkey: sessionToken, isSigner: false, isWritable: false });
   }
 
+  keys.push({ pubkey: ownerMembership, isSigner: false, isWritable: false });
+  for (const membership of verifierMemberships) {
+    keys.push({ pubkey: membership, isSigner: false, isWritable: false });
+  }
+
   return new TransactionInstruction({
     keys,
     programId,
@@ -455,11 +694,13 @@ export function createCreateActionItemInstruction(
   });
 }
 
+// ownerReputation is the owner's Reputation PDA; it is only updated if it exists
 export function createCastVerificationVoteInstruction(
   board: PublicKey,
   actionItem: PublicKey,
   verificationVote: PublicKey,
   ownerMembership: PublicKey,
+  ownerReputation: PublicKey,
   signer: PublicKey,
   actionItemId: bigint,
   approved: boolean,
@@ -473,10 +714,11 @@ export function createCastVerificationVoteInstruction(
   );
 
   const keys = [
-    { pubkey: board, isSigner: false, isWritable: false },
+    { pubkey: board, isSigner: false, isWritable: true },
     { pubkey: actionItem, isSigner: false, isWritable: true },
     { pubkey: verificationVote, isSigner: false, isWritable: true },
     { pubkey: ownerMembership, isSigner: false, isWritable: true },
+    { pubkey: ownerReputation, isSigner: false, isWritable: true },
     { pubkey: signer, isSigner: true, isWritable: true },
     { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
   ];
@@ -589,4 +831,4 @@ export {
   findSessionTokenPda,
 } from "./pda";
 
-export { findParticipantIdentityPda } from "./pda";
+export { findParticipantIdentityPda, findReputationPda } from "./pda";
@@ -0,0 +1,59 @@
+import { PublicKey } from "@solana/web3.js";
+import { Buffer } from "buffer";
+
+// localStorage key for the salts of notes written with a hidden author
+const STORAGE_KEY = "retroquest_note_salts";
+
+// Map of note address -> salt serialized as number[]
+type StoredSaltMap = Record<string, number[]>;
+
+function loadStoredSalts(): StoredSaltMap {
+  try {
+    const stored = localStorage.getItem(STORAGE_KEY);
+    if (!stored) return {};
+    return JSON.parse(stored) as StoredSaltMap;
+  } catch (error) {
+    console.warn("Failed to load stored note salts:", error);
+    return {};
+  }
+}
+
+// The salt is the only way to later reveal, edit or delete the note, so it is kept per note
+export function saveNoteSalt(note: PublicKey, salt: Uint8Array): void {
+  try {
+    const salts = loadStoredSalts();
+    salts[note.toBase58()] = Array.from(salt);
+    localStorage.setItem(STORAGE_KEY, JSON.stringify(salts));
+  } catch (error) {
+    console.warn("Failed to save note salt to localStorage:", error);
+  }
+}
+
+export function loadNoteSalt(note: PublicKey): Uint8Array | null {
+  const salt = loadStoredSalts()[note.toBase58()];
+  return salt ? new Uint8Array(salt) : null;
+}
+
+export function generateNoteSalt(): Uint8Array {
+  return crypto.getRandomValues(new Uint8Array(32));
+}
+
+// sha256(author || board || salt), matching Note::author_commitment in state.rs
+export async function computeAuthorCommitment(
+  author: PublicKey,
+  board: PublicKey,
+  salt: Uint8Array
+): Promise<Uint8Array> {
+  const preimage = Buffer.concat([
+    author.toBuffer(),
+    board.toBuffer(),
+    Buffer.from(salt),
+  ]);
+  const digest = await crypto.subtle.digest("SHA-256", preimage);
+  return new Uint8Array(digest);
+}
+
+// Unrevealed notes on boards that hide authors store the default key as author
+export function isAuthorHidden(author: PublicKey): boolean {
+  return author.equals(PublicKey.default);
+}
@@ -12,6 +12,10 @@ const ACTION_ITEM_SEED = Buffer.from("action_item");
 const VERIFICATION_VOTE_SEED = Buffer.from("verification_vote");
 const SESSION_TOKEN_SEED = Buffer.from("session_token");
 const PARTICIPANT_IDENTITY_SEED = Buffer.from("participant");
+const REPUTATION_SEED = Buffer.from("reputation");
+const TEAM_SEED = Buffer.from("team");
+const BALLOT_SEED = Buffer.from("ballot");
+const VOTE_COMMITMENT_SEED = Buffer.from("vote_commitment");
 
 export function findFacilitatorRegistryPda(
   facilitator: PublicKey,
@@ -141,3 +145,55 @@ export function findParticipantIdentityPda(
     programId
   );
 }
+
+// Reputation PDA, derived from the participant identity rather than the wallet
+// Seeds: ["reputation", identity]
+export function findReputationPda(
+  authority: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  const [identity] = findParticipantIdentityPda(authority, programId);
+  return PublicKey.findProgramAddressSync(
+    [REPUTATION_SEED, identity.toBuffer()],
+    programId
+  );
+}
+
+// Team PDA
+// Seeds: ["team", creator, name]
+export function findTeamPda(
+  creator: PublicKey,
+  name: string,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [TEAM_SEED, creator.toBuffer(), Buffer.from(name)],
+    programId
+  );
+}
+
+// Ballot PDA (ranked boards)
+// Seeds: ["ballot", board, participant]
+export function findBallotPda(
+  board: PublicKey,
+  participant: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BALLOT_SEED, board.toBuffer(), participant.toBuffer()],
+    programId
+  );
+}
+
+// Vote commitment PDA (sealed boards)
+// Seeds: ["vote_commitment", board, participant]
+export function findVoteCommitmentPda(
+  board: PublicKey,
+  participant: PublicKey,
+  programId: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [VOTE_COMMITMENT_SEED, board.toBuffer(), participant.toBuffer()],
+    programId
+  );
+}
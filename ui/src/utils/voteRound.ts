@@ -0,0 +1,15 @@
+import { BoardMembership, Group, RetroBoard } from "../types";
+
+// RevertStage only bumps board.voteRound; the program resets older groups and
+// memberships when they are next touched, so readers must treat them as zero
+
+export function currentVoteTally(group: Group, board: RetroBoard): bigint {
+  return group.voteRound === board.voteRound ? group.voteTally : 0n;
+}
+
+export function currentCreditsSpent(
+  membership: BoardMembership,
+  board: RetroBoard
+): number {
+  return membership.voteRound === board.voteRound ? membership.creditsSpent : 0;
+}